@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use crate::GameplaySet;
+use crate::collision;
+use crate::ui::ShowToast;
+use crate::asteroid::{self, AsteroidDestroyed, AsteroidWaveCleared};
+
+use crate::hero_ship::{
+    HeroShip,
+    HeroShipInput
+};
+
+use crate::statistics::{
+    self,
+    RunStatistics,
+    LifetimeStatistics
+};
+
+use crate::constants::asteroid::SMALL_ASTEROID_ARCHETYPE;
+use crate::constants::statistics::LIFETIME_STATISTICS_FILE_PATH;
+
+use crate::constants::achievements::{
+    SMALL_ASTEROID_STREAK_COUNT,
+    SMALL_ASTEROID_STREAK_SECONDS
+};
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.add_event::<AchievementUnlocked>();
+        app.add_systems(FixedUpdate, (
+            track_hero_ship_thrusting,
+            check_for_wave_cleared_without_thrusting.after(asteroid::maintain_game_loop),
+            check_for_small_asteroid_streak.after(collision::apply_asteroid_hits)
+        ).in_set(GameplaySet));
+        app.add_systems(Update, (
+            unlock_achievements
+        ).run_if(statistics::is_player_run));
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Achievement {
+    WaveClearedWithoutThrusting,
+    SmallAsteroidStreak
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 2] = [
+        Achievement::WaveClearedWithoutThrusting,
+        Achievement::SmallAsteroidStreak
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Achievement::WaveClearedWithoutThrusting => return "Drifter",
+            Achievement::SmallAsteroidStreak => return "Pebble Crusher"
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            Achievement::WaveClearedWithoutThrusting => return "Clear a wave without thrusting".to_string(),
+            Achievement::SmallAsteroidStreak => return format!(
+                "Destroy {} small asteroids in {} seconds",
+                SMALL_ASTEROID_STREAK_COUNT,
+                SMALL_ASTEROID_STREAK_SECONDS
+            )
+        }
+    }
+}
+
+#[derive(Reflect, Default, Debug, Clone)]
+pub struct AchievementProgress {
+    pub has_thrusted_this_wave: bool,
+    pub small_asteroid_destroyed_seconds: VecDeque<f32>
+}
+
+impl AchievementProgress {
+    pub fn record_small_asteroid_destroyed(
+        &mut self,
+        elapsed_seconds: f32
+    ) -> bool {
+        self.small_asteroid_destroyed_seconds.push_back(elapsed_seconds);
+
+        while let Some(oldest_destroyed_seconds) = self.small_asteroid_destroyed_seconds.front() {
+            if elapsed_seconds - oldest_destroyed_seconds <= SMALL_ASTEROID_STREAK_SECONDS {
+                break;
+            }
+            self.small_asteroid_destroyed_seconds.pop_front();
+        }
+        return self.small_asteroid_destroyed_seconds.len() >= SMALL_ASTEROID_STREAK_COUNT;
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct AchievementUnlocked(pub Achievement);
+
+pub fn track_hero_ship_thrusting(
+    mut run_statistics: ResMut<RunStatistics>,
+    hero_ship_query: Query<(&HeroShipInput, &Visibility), With<HeroShip>>
+) {
+    for (hero_ship_input, hero_ship_visibility) in &hero_ship_query {
+        if hero_ship_input.accelerate && *hero_ship_visibility == Visibility::Visible {
+            run_statistics.achievement_progress.has_thrusted_this_wave = true;
+        }
+    }
+}
+
+pub fn check_for_wave_cleared_without_thrusting(
+    mut asteroid_wave_cleared_events: EventReader<AsteroidWaveCleared>,
+    mut achievement_unlocked_events: EventWriter<AchievementUnlocked>,
+    mut run_statistics: ResMut<RunStatistics>
+) {
+    for _ in asteroid_wave_cleared_events.read() {
+        if !run_statistics.achievement_progress.has_thrusted_this_wave {
+            achievement_unlocked_events.send(AchievementUnlocked(Achievement::WaveClearedWithoutThrusting));
+        }
+        run_statistics.achievement_progress.has_thrusted_this_wave = false;
+    }
+}
+
+pub fn check_for_small_asteroid_streak(
+    time: Res<Time>,
+    mut asteroid_destroyed_events: EventReader<AsteroidDestroyed>,
+    mut achievement_unlocked_events: EventWriter<AchievementUnlocked>,
+    mut run_statistics: ResMut<RunStatistics>
+) {
+    for asteroid_destroyed_event in asteroid_destroyed_events.read() {
+        if asteroid_destroyed_event.archetype != SMALL_ASTEROID_ARCHETYPE {
+            continue;
+        }
+
+        if run_statistics.achievement_progress.record_small_asteroid_destroyed(time.elapsed_seconds()) {
+            achievement_unlocked_events.send(AchievementUnlocked(Achievement::SmallAsteroidStreak));
+        }
+    }
+}
+
+pub fn unlock_achievements(
+    mut achievement_unlocked_events: EventReader<AchievementUnlocked>,
+    mut show_toast_events: EventWriter<ShowToast>,
+    mut lifetime_statistics: ResMut<LifetimeStatistics>
+) {
+    let mut is_any_achievement_unlocked: bool = false;
+
+    for AchievementUnlocked(achievement) in achievement_unlocked_events.read() {
+        if !lifetime_statistics.unlock(*achievement) {
+            continue;
+        }
+        is_any_achievement_unlocked = true;
+        show_toast_events.send(ShowToast {
+            message: format!("Achievement unlocked: {}!\n{}", achievement.name(), achievement.description()),
+            color: Color::GOLD
+        });
+    }
+
+    if is_any_achievement_unlocked {
+        statistics::save_ron_file(&*lifetime_statistics, LIFETIME_STATISTICS_FILE_PATH);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_asteroid_streak_is_reached_inside_the_time_window() {
+        let mut achievement_progress: AchievementProgress = AchievementProgress::default();
+
+        for destroyed_index in 0..SMALL_ASTEROID_STREAK_COUNT - 1 {
+            assert!(!achievement_progress.record_small_asteroid_destroyed(destroyed_index as f32 * 0.1));
+        }
+        assert!(achievement_progress.record_small_asteroid_destroyed(SMALL_ASTEROID_STREAK_SECONDS));
+    }
+
+    #[test]
+    fn small_asteroids_destroyed_too_slowly_never_reach_the_streak() {
+        let mut achievement_progress: AchievementProgress = AchievementProgress::default();
+        let destroyed_interval_seconds: f32 = SMALL_ASTEROID_STREAK_SECONDS / (SMALL_ASTEROID_STREAK_COUNT as f32 - 1.5);
+
+        for destroyed_index in 0..SMALL_ASTEROID_STREAK_COUNT * 3 {
+            assert!(!achievement_progress.record_small_asteroid_destroyed(destroyed_index as f32 * destroyed_interval_seconds));
+        }
+    }
+
+    #[test]
+    fn small_asteroids_outside_the_time_window_are_forgotten() {
+        let mut achievement_progress: AchievementProgress = AchievementProgress::default();
+
+        for destroyed_index in 0..SMALL_ASTEROID_STREAK_COUNT - 1 {
+            achievement_progress.record_small_asteroid_destroyed(destroyed_index as f32 * 0.1);
+        }
+
+        assert!(!achievement_progress.record_small_asteroid_destroyed(SMALL_ASTEROID_STREAK_SECONDS + 1.));
+        assert_eq!(achievement_progress.small_asteroid_destroyed_seconds.len(), 1);
+    }
+
+    #[test]
+    fn achievements_are_unlocked_only_once() {
+        let mut lifetime_statistics: LifetimeStatistics = LifetimeStatistics::default();
+
+        assert!(lifetime_statistics.unlock(Achievement::SmallAsteroidStreak));
+        assert!(!lifetime_statistics.unlock(Achievement::SmallAsteroidStreak));
+        assert!(lifetime_statistics.is_unlocked(Achievement::SmallAsteroidStreak));
+        assert!(!lifetime_statistics.is_unlocked(Achievement::WaveClearedWithoutThrusting));
+    }
+}
@@ -3,25 +3,26 @@ use bevy::{
     math::vec3
 };
 
-use rand::{
-    rngs::ThreadRng,
-    Rng
-};
+use rand::Rng;
 
 use bevy_rapier2d::{
     prelude::*,
     geometry::Collider
 };
 
-use crate::PausingState;
-use crate::GameState;
-
-use crate::constants::image_handles::{
-    SMALL_ASTEROID_HANDLE_IMAGE,
-    MEDIUM_ASTEROID_HANDLE_IMAGE,
-    BIG_ASTEROID_HANDLE_IMAGE
+use crate::GameRng;
+use crate::RunScoped;
+use crate::ResetRun;
+use crate::GameplaySet;
+use crate::shop;
+use crate::config::{
+    GameConfig,
+    AsteroidsConfig,
+    AsteroidArchetypeConfig
 };
 
+use crate::constants::timers::ASTEROID_CHECK_FOR_TYPES_SECONDS;
+
 use crate::constants::borders::{
     RIGHT_BORDER_POSITION,
     LEFT_BORDER_POSITION,
@@ -33,21 +34,8 @@ use crate::constants::borders::{
     BOTTOM_BORDER_OFFSCREEN_POSITION
 };
 
-use crate::constants::asteroid_movement_values::{
-    SMALL_ASTEROID_MOVEMENT_SPEED,
-    SMALL_ASTEROID_ROTATION_SPEED,
-    MEDIUM_ASTEROID_MOVEMENT_SPEED,
-    MEDIUM_ASTEROID_ROTATION_SPEED,
-    BIG_ASTEROID_MOVEMENT_SPEED,
-    BIG_ASTEROID_ROTATION_SPEED
-};
-
-use crate::constants::asteroid::{
-    INITIAL_BIG_ASTEROIDS_ONSCREEN,
-    SMALL_ASTEROID_COLLIDER_BALL_SIZE,
-    MEDIUM_ASTEROID_COLLIDER_BALL_SIZE,
-    BIG_ASTEROID_COLLIDER_BALL_SIZE
-};
+use crate::constants::asteroid::BIG_ASTEROID_COLLIDER_BALL_SIZE;
+use crate::constants::asteroid_hit_values::ASTEROID_HIT_FLASH_SECONDS;
 
 pub struct AsteroidPlugin;
 
@@ -57,13 +45,16 @@ impl Plugin for AsteroidPlugin {
         app: &mut App
     ) {
         app.init_resource::<AsteroidCheckForTypesTimer>();
-        app.add_systems(Update, (
-            maintain_game_loop
-        ).run_if(in_state(PausingState::Running).and_then(in_state(GameState::InGame))));
+        app.add_systems(ResetRun, reset_asteroid_check_for_types_timer);
+        app.add_event::<AsteroidDestroyed>();
+        app.add_event::<AsteroidWaveCleared>();
         app.add_systems(FixedUpdate, (
+            maintain_game_loop,
+            spawn_next_asteroid_wave.after(maintain_game_loop).run_if(not(shop::is_shop_available)),
             set_asteroid_movement_and_rotation,
-            set_asteroid_position_after_border_outbounds
-        ).run_if(in_state(PausingState::Running).and_then(in_state(GameState::InGame))));
+            set_asteroid_position_after_border_outbounds,
+            fade_asteroid_hit_flash
+        ).in_set(GameplaySet));
     }
 }
 
@@ -77,9 +68,9 @@ enum BorderSide {
 
 impl BorderSide {
     fn get_randomic_border_side(
-        mut thread_rng: ThreadRng
+        game_rng: &mut GameRng
     ) -> Self {
-        let randomic_border_side: u32 = thread_rng.gen_range(1..=4);
+        let randomic_border_side: u32 = game_rng.gen_range(1..=4);
 
         match randomic_border_side {
             1 => return Self::Right,
@@ -91,17 +82,12 @@ impl BorderSide {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
-pub enum AsteroidType {
-    Small,
-    Medium,
-    Big
-}
-
-#[derive(Component, Clone, Debug)]
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component, Default)]
 pub struct Asteroid {
-    pub asteroid_type: AsteroidType,
-    collider_ball_size: f32,
+    pub archetype: String,
+    pub collider_ball_size: f32,
+    #[reflect(ignore)]
     pub handle_image: Option<Handle<Image>>,
     pub movement_speed: Option<f32>,
     pub movement_direction: Vec3,
@@ -111,70 +97,122 @@ pub struct Asteroid {
 
 impl Default for Asteroid {
     fn default() -> Self {
-        let mut thread_rng: ThreadRng = rand::thread_rng();
-
         return Self {
-            asteroid_type: AsteroidType::Big,
+            archetype: String::new(),
             collider_ball_size: BIG_ASTEROID_COLLIDER_BALL_SIZE,
             handle_image: None,
             movement_speed: None,
-            movement_direction: vec3(thread_rng.gen_range(-1.0..=1.0), thread_rng.gen_range(-1.0..=1.0), 0.),
+            movement_direction: vec3(0., 0., 0.),
             rotation_speed: None,
-            rotation_direction: thread_rng.gen_range(-1.0..=1.0)
+            rotation_direction: 0.
         };
     }
 }
 
 impl Asteroid {
-    fn initialize_asteroid_based_on_type(
+    fn initialize_asteroid_based_on_archetype(
         asset_server: &Res<AssetServer>,
-        asteroid_type: AsteroidType
+        asteroid_archetype: &AsteroidArchetypeConfig,
+        game_rng: &mut GameRng
     ) -> Self {
-        let mut asteroid_struct: Asteroid = Asteroid::default();
-
-        match asteroid_type {
-            AsteroidType::Small => {
-                asteroid_struct.asteroid_type = asteroid_type;
-                asteroid_struct.collider_ball_size = SMALL_ASTEROID_COLLIDER_BALL_SIZE;
-                asteroid_struct.handle_image = Some(asset_server.load(SMALL_ASTEROID_HANDLE_IMAGE));
-                asteroid_struct.movement_speed = Some(SMALL_ASTEROID_MOVEMENT_SPEED);
-                asteroid_struct.rotation_speed = Some(f32::to_radians(SMALL_ASTEROID_ROTATION_SPEED));
-            },
-            AsteroidType::Medium => {
-                asteroid_struct.asteroid_type = asteroid_type;
-                asteroid_struct.collider_ball_size = MEDIUM_ASTEROID_COLLIDER_BALL_SIZE;
-                asteroid_struct.handle_image = Some(asset_server.load(MEDIUM_ASTEROID_HANDLE_IMAGE));
-                asteroid_struct.movement_speed = Some(MEDIUM_ASTEROID_MOVEMENT_SPEED);
-                asteroid_struct.rotation_speed = Some(f32::to_radians(MEDIUM_ASTEROID_ROTATION_SPEED));
-            },
-            AsteroidType::Big => {
-                asteroid_struct.asteroid_type = asteroid_type;
-                asteroid_struct.collider_ball_size = BIG_ASTEROID_COLLIDER_BALL_SIZE;
-                asteroid_struct.handle_image = Some(asset_server.load(BIG_ASTEROID_HANDLE_IMAGE));
-                asteroid_struct.movement_speed = Some(BIG_ASTEROID_MOVEMENT_SPEED);
-                asteroid_struct.rotation_speed = Some(f32::to_radians(BIG_ASTEROID_ROTATION_SPEED));
-            }
-        }
-        return asteroid_struct;
+        return Asteroid {
+            archetype: asteroid_archetype.name.clone(),
+            collider_ball_size: asteroid_archetype.collider_ball_size,
+            handle_image: Some(asset_server.load(asteroid_archetype.sprite.clone())),
+            movement_speed: Some(game_rng.gen_range(asteroid_archetype.movement_speed.0..=asteroid_archetype.movement_speed.1)),
+            movement_direction: vec3(game_rng.gen_range(-1.0..=1.0), game_rng.gen_range(-1.0..=1.0), 0.),
+            rotation_speed: Some(f32::to_radians(
+                game_rng.gen_range(asteroid_archetype.rotation_speed.0..=asteroid_archetype.rotation_speed.1)
+            )),
+            rotation_direction: game_rng.gen_range(-1.0..=1.0)
+        };
     }
+
+    pub fn apply_config(
+        &mut self,
+        asset_server: &Res<AssetServer>,
+        asteroids_config: &AsteroidsConfig
+    ) {
+        let Some(asteroid_archetype) = asteroids_config.get_archetype(&self.archetype) else {
+            return;
+        };
+
+        self.collider_ball_size = asteroid_archetype.collider_ball_size;
+        self.handle_image = Some(asset_server.load(asteroid_archetype.sprite.clone()));
+        self.movement_speed = self.movement_speed
+            .map(|movement_speed| movement_speed.clamp(asteroid_archetype.movement_speed.0, asteroid_archetype.movement_speed.1));
+        self.rotation_speed = self.rotation_speed.map(|rotation_speed| rotation_speed.clamp(
+            f32::to_radians(asteroid_archetype.rotation_speed.0),
+            f32::to_radians(asteroid_archetype.rotation_speed.1)
+        ));
+    }
+}
+
+#[derive(Component, Reflect, Default, Clone, Copy, Debug)]
+#[reflect(Component)]
+pub struct Health {
+    pub hit_points: u32,
+    pub maximum_hit_points: u32
 }
 
+impl Health {
+    pub fn new(maximum_hit_points: u32) -> Self {
+        return Self {
+            hit_points: maximum_hit_points,
+            maximum_hit_points
+        };
+    }
+
+    pub fn take_hit(&mut self) -> bool {
+        self.hit_points = self.hit_points.saturating_sub(1);
+        return self.hit_points == 0;
+    }
+}
+
+#[derive(Component, Deref, DerefMut)]
+pub struct AsteroidHitFlash(pub Timer);
+
+impl Default for AsteroidHitFlash {
+    fn default() -> Self {
+        return Self(Timer::from_seconds(ASTEROID_HIT_FLASH_SECONDS, TimerMode::Once));
+    }
+}
+
+#[derive(Event, Clone, Debug)]
+pub struct AsteroidDestroyed {
+    pub position: Vec3,
+    pub archetype: String
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct AsteroidWaveCleared;
+
 #[derive(Resource, Default, Deref, DerefMut)]
 pub struct AsteroidDestroyedSound(pub Handle<AudioSource>);
 
-#[derive(Resource, Deref, DerefMut)]
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct AsteroidHitSound(pub Handle<AudioSource>);
+
+#[derive(Resource, Reflect, Deref, DerefMut)]
+#[reflect(Resource)]
 pub struct AsteroidCheckForTypesTimer(pub Timer);
 
 impl Default for AsteroidCheckForTypesTimer {
     fn default() -> Self {
-        return Self(Timer::from_seconds(4.5, TimerMode::Repeating));
+        return Self(Timer::from_seconds(ASTEROID_CHECK_FOR_TYPES_SECONDS, TimerMode::Repeating));
     }
 }
 
+pub fn reset_asteroid_check_for_types_timer(
+    mut asteroid_check_for_types_timer: ResMut<AsteroidCheckForTypesTimer>
+) {
+    asteroid_check_for_types_timer.reset();
+}
+
 pub fn maintain_game_loop(
-    commands: Commands,
-    asset_server: Res<AssetServer>,
     time: Res<Time>,
+    game_config: Res<GameConfig>,
+    mut asteroid_wave_cleared_events: EventWriter<AsteroidWaveCleared>,
     mut asteroid_check_for_types_timer: ResMut<AsteroidCheckForTypesTimer>,
     asteroid_query: Query<&Asteroid>
 ) {
@@ -182,11 +220,11 @@ pub fn maintain_game_loop(
     let mut asteroid_counter_onscreen: usize = 0;
 
     for asteroid_component in &asteroid_query {
-        if asteroid_component.asteroid_type != AsteroidType::Big {
+        if asteroid_component.archetype != game_config.asteroids.initial_archetype {
             is_none_big_asteroid_onscreen = true;
         }
 
-        if asteroid_component.asteroid_type == AsteroidType::Big {
+        if asteroid_component.archetype == game_config.asteroids.initial_archetype {
             is_none_big_asteroid_onscreen = false;
         }
         asteroid_counter_onscreen += 1;
@@ -196,22 +234,41 @@ pub fn maintain_game_loop(
         asteroid_check_for_types_timer.0.tick(time.delta());
 
         if asteroid_check_for_types_timer.0.just_finished() {
-            spawn_initial_asteroids(commands, &asset_server);
+            asteroid_wave_cleared_events.send(AsteroidWaveCleared);
         }
     }
 }
 
+pub fn spawn_next_asteroid_wave(
+    commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>,
+    mut asteroid_wave_cleared_events: EventReader<AsteroidWaveCleared>
+) {
+    if asteroid_wave_cleared_events.read().count() > 0 {
+        spawn_initial_asteroids(commands, &asset_server, &game_config, &mut game_rng);
+    }
+}
+
 pub fn spawn_initial_asteroids(
     mut commands: Commands,
-    asset_server: &Res<AssetServer>
+    asset_server: &Res<AssetServer>,
+    game_config: &GameConfig,
+    game_rng: &mut GameRng
 ) {
-    for _ in 1..=INITIAL_BIG_ASTEROIDS_ONSCREEN {
+    for _ in 1..=game_config.asteroids.initial_asteroids_onscreen {
+        let position_x: f32 = get_randomic_asteroid_onscreen_position(game_rng, LEFT_BORDER_POSITION, RIGHT_BORDER_POSITION);
+        let position_y: f32 = get_randomic_asteroid_onscreen_position(game_rng, BOTTOM_BORDER_POSITION, TOP_BORDER_POSITION);
+
         spawn_asteroids(
             commands.reborrow(),
             asset_server,
-            AsteroidType::Big,
-            get_randomic_asteroid_onscreen_position(LEFT_BORDER_POSITION, RIGHT_BORDER_POSITION),
-            get_randomic_asteroid_onscreen_position(BOTTOM_BORDER_POSITION, TOP_BORDER_POSITION)
+            game_config,
+            game_rng,
+            &game_config.asteroids.initial_archetype,
+            position_x,
+            position_y
         );
     }
 }
@@ -219,67 +276,93 @@ pub fn spawn_initial_asteroids(
 pub fn spawn_asteroids_after_collision(
     mut commands: Commands,
     asset_server: &Res<AssetServer>,
+    game_config: &GameConfig,
+    game_rng: &mut GameRng,
     asteroid: &Asteroid,
     asteroid_transform: &Transform
 ) {
-    match asteroid.asteroid_type {
-        AsteroidType::Big => {
-            for _ in 1..=3 {
-                spawn_asteroids(
-                    commands.reborrow(),
-                    asset_server,
-                    AsteroidType::Medium,
-                    asteroid_transform.translation.x,
-                    asteroid_transform.translation.y
-                );
-            }
-        },
-        AsteroidType::Medium => { 
-            for _ in 1..=2 {
-                spawn_asteroids(
-                    commands.reborrow(),
-                    asset_server,
-                    AsteroidType::Small,
-                    asteroid_transform.translation.x,
-                    asteroid_transform.translation.y
-                );
-            }
-        },
-        AsteroidType::Small => {}
+    let Some(asteroid_archetype) = game_config.asteroids.get_archetype(&asteroid.archetype) else {
+        return;
+    };
+
+    for asteroid_split in &asteroid_archetype.split_into {
+        for _ in 1..=asteroid_split.count {
+            spawn_asteroids(
+                commands.reborrow(),
+                asset_server,
+                game_config,
+                game_rng,
+                &asteroid_split.archetype,
+                asteroid_transform.translation.x,
+                asteroid_transform.translation.y
+            );
+        }
     }
 }
 
 pub fn spawn_asteroids(
     mut commands: Commands,
     asset_server: &Res<AssetServer>,
-    asteroid_type: AsteroidType,
+    game_config: &GameConfig,
+    game_rng: &mut GameRng,
+    archetype_name: &str,
     position_x: f32,
     position_y: f32
 ) {
-    let asteroid_struct: Asteroid = Asteroid::initialize_asteroid_based_on_type(asset_server, asteroid_type);
+    let Some(asteroid_archetype) = game_config.asteroids.get_archetype(archetype_name) else {
+        warn!("Unknown asteroid archetype \"{}\".", archetype_name);
+        return;
+    };
+    let asteroid_struct: Asteroid = Asteroid::initialize_asteroid_based_on_archetype(asset_server, asteroid_archetype, game_rng);
+
+    let asteroid_entity: Entity = spawn_asteroid(
+        commands.reborrow(),
+        asteroid_struct,
+        Transform::from_xyz(position_x, position_y, 0.)
+    );
+
+    if asteroid_archetype.hit_points > 1 {
+        commands.entity(asteroid_entity).insert(Health::new(asteroid_archetype.hit_points));
+    }
+}
 
-    commands.spawn((
+pub fn spawn_asteroid(
+    mut commands: Commands,
+    asteroid_struct: Asteroid,
+    asteroid_transform: Transform
+) -> Entity {
+    return commands.spawn((
         SpriteBundle {
-            texture: asteroid_struct.clone().handle_image.unwrap(),
-            transform: Transform {
-                translation: vec3(
-                    position_x,
-                    position_y,
-                    0.
-                ),
-                ..default()
-            },
+            texture: asteroid_struct.clone().handle_image.unwrap_or_default(),
+            transform: asteroid_transform,
             ..default()
         },
         asteroid_struct.clone(),
     ))
     .insert(Name::new("Asteroid"))
+    .insert(RunScoped)
     .insert(RigidBody::Dynamic)
     .insert(Collider::ball(asteroid_struct.collider_ball_size))
     .insert(GravityScale(0.))
     .insert(ActiveEvents::COLLISION_EVENTS)
     .insert(CollisionGroups::new(Group::GROUP_1, Group::GROUP_10))
-    .insert(Dominance::group(10));
+    .insert(Dominance::group(10))
+    .id();
+}
+
+pub fn fade_asteroid_hit_flash(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut asteroid_query: Query<(Entity, &mut AsteroidHitFlash, &mut Sprite), With<Asteroid>>
+) {
+    for (asteroid_entity, mut asteroid_hit_flash, mut asteroid_sprite) in &mut asteroid_query {
+        asteroid_hit_flash.tick(time.delta());
+
+        if asteroid_hit_flash.finished() {
+            asteroid_sprite.color = Color::WHITE;
+            commands.entity(asteroid_entity).remove::<AsteroidHitFlash>();
+        }
+    }
 }
 
 pub fn set_asteroid_movement_and_rotation(
@@ -299,10 +382,9 @@ pub fn set_asteroid_movement_and_rotation(
 
 pub fn set_asteroid_position_after_border_outbounds(
     time: Res<Time>,
+    mut game_rng: ResMut<GameRng>,
     mut asteroid_query: Query<(&mut Asteroid, &mut Transform)>
 ) {
-    let thread_rng: ThreadRng = rand::thread_rng();
-
     for (asteroid_entity, mut asteroid_transform) in &mut asteroid_query {
         let randomic_asteroid_offscreen_position: Vec3;
         let asteroid_position_x: f32 = asteroid_transform.translation.x;
@@ -314,7 +396,7 @@ pub fn set_asteroid_position_after_border_outbounds(
             asteroid_position_y >= TOP_BORDER_OFFSCREEN_POSITION ||
             asteroid_position_y <= BOTTOM_BORDER_OFFSCREEN_POSITION
         {
-            randomic_asteroid_offscreen_position = get_randomic_asteroid_offscreen_position(thread_rng.clone());
+            randomic_asteroid_offscreen_position = get_randomic_asteroid_offscreen_position(&mut game_rng);
             asteroid_transform.translation.x = randomic_asteroid_offscreen_position.x;
             asteroid_transform.translation.y = randomic_asteroid_offscreen_position.y;
 
@@ -322,22 +404,22 @@ pub fn set_asteroid_position_after_border_outbounds(
                 &time,
                 asteroid_entity,
                 asteroid_transform,
-                thread_rng.clone()
+                &mut game_rng
             );
         }
     }
 }
 
 fn get_randomic_asteroid_onscreen_position(
+    game_rng: &mut GameRng,
     position_initial_range: f32,
     position_final_range: f32
 ) -> f32 {
-    let mut thread_rng: ThreadRng = rand::thread_rng();
     let mut is_randomic_position_ok: bool = false;
     let mut randomic_asteroid_position: f32 = 0.;
 
     while !is_randomic_position_ok {
-        randomic_asteroid_position = thread_rng.gen_range(position_initial_range..=position_final_range);
+        randomic_asteroid_position = game_rng.gen_range(position_initial_range..=position_final_range);
 
         if
             randomic_asteroid_position >= 50. ||
@@ -350,35 +432,35 @@ fn get_randomic_asteroid_onscreen_position(
 }
 
 fn get_randomic_asteroid_offscreen_position(
-    thread_rng: ThreadRng
+    game_rng: &mut GameRng
 ) -> Vec3 {
-    let randomic_offscreen_border_side: BorderSide = BorderSide::get_randomic_border_side(thread_rng.clone());
+    let randomic_offscreen_border_side: BorderSide = BorderSide::get_randomic_border_side(game_rng);
 
     match randomic_offscreen_border_side {
         BorderSide::Right => {
             return vec3(
                 RIGHT_BORDER_OFFSCREEN_POSITION - 10.,
-                get_randomic_asteroid_offscreen_y_position(thread_rng.clone()),
+                get_randomic_asteroid_offscreen_y_position(game_rng),
                 0.
             );
         },
         BorderSide::Left => {
             return vec3(
                 LEFT_BORDER_OFFSCREEN_POSITION + 10.,
-                get_randomic_asteroid_offscreen_y_position(thread_rng.clone()),
+                get_randomic_asteroid_offscreen_y_position(game_rng),
                 0.
             );
         },
         BorderSide::Top => {
             return vec3(
-                get_randomic_asteroid_offscreen_x_position(thread_rng.clone()),
+                get_randomic_asteroid_offscreen_x_position(game_rng),
                 TOP_BORDER_OFFSCREEN_POSITION - 10.,
                 0.
             );
         },
         BorderSide::Bottom => {
             return vec3(
-                get_randomic_asteroid_offscreen_x_position(thread_rng.clone()),
+                get_randomic_asteroid_offscreen_x_position(game_rng),
                 BOTTOM_BORDER_OFFSCREEN_POSITION + 10.,
                 0.
             );
@@ -387,27 +469,27 @@ fn get_randomic_asteroid_offscreen_position(
 }
 
 fn get_randomic_asteroid_offscreen_x_position(
-    mut thread_rng: ThreadRng
+    game_rng: &mut GameRng
 ) -> f32 {
-    return thread_rng.gen_range(LEFT_BORDER_POSITION..=RIGHT_BORDER_POSITION);
+    return game_rng.gen_range(LEFT_BORDER_POSITION..=RIGHT_BORDER_POSITION);
 }
 
 fn get_randomic_asteroid_offscreen_y_position(
-    mut thread_rng: ThreadRng
+    game_rng: &mut GameRng
 ) -> f32 {
-    return thread_rng.gen_range(BOTTOM_BORDER_POSITION..=TOP_BORDER_POSITION);
+    return game_rng.gen_range(BOTTOM_BORDER_POSITION..=TOP_BORDER_POSITION);
 }
 
 fn set_asteroid_movement_direction_after_border_outbounds(
     time: &Res<Time>,
     mut asteroid_entity: Mut<'_, Asteroid>,
     asteroid_transform: Mut<'_, Transform>,
-    mut thread_rng: ThreadRng
+    game_rng: &mut GameRng
 ) {
     if asteroid_transform.translation.x <= RIGHT_BORDER_OFFSCREEN_POSITION - 10. {
         asteroid_entity.movement_direction = vec3(
             -1.,
-            thread_rng.gen_range(BOTTOM_BORDER_POSITION..=TOP_BORDER_POSITION),
+            game_rng.gen_range(BOTTOM_BORDER_POSITION..=TOP_BORDER_POSITION),
             0.
         ) * time.delta_seconds();
     }
@@ -415,14 +497,14 @@ fn set_asteroid_movement_direction_after_border_outbounds(
     if asteroid_transform.translation.x >= LEFT_BORDER_OFFSCREEN_POSITION + 10. {
         asteroid_entity.movement_direction = vec3(
             1.,
-            thread_rng.gen_range(BOTTOM_BORDER_POSITION..=TOP_BORDER_POSITION),
+            game_rng.gen_range(BOTTOM_BORDER_POSITION..=TOP_BORDER_POSITION),
             0.
         ) * time.delta_seconds();
     }
 
     if asteroid_transform.translation.y <= TOP_BORDER_OFFSCREEN_POSITION - 10. {
         asteroid_entity.movement_direction = vec3(
-            thread_rng.gen_range(LEFT_BORDER_POSITION..=RIGHT_BORDER_POSITION),
+            game_rng.gen_range(LEFT_BORDER_POSITION..=RIGHT_BORDER_POSITION),
             -1.,
             0.
         ) * time.delta_seconds();
@@ -430,9 +512,35 @@ fn set_asteroid_movement_direction_after_border_outbounds(
 
     if asteroid_transform.translation.y >= BOTTOM_BORDER_OFFSCREEN_POSITION + 10. {
         asteroid_entity.movement_direction = vec3(
-            thread_rng.gen_range(LEFT_BORDER_POSITION..=RIGHT_BORDER_POSITION),
+            game_rng.gen_range(LEFT_BORDER_POSITION..=RIGHT_BORDER_POSITION),
             1.,
             0.
         ) * time.delta_seconds();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn health_reports_destruction_only_on_the_last_hit() {
+        let mut asteroid_health: Health = Health::new(3);
+
+        assert!(!asteroid_health.take_hit());
+        assert!(!asteroid_health.take_hit());
+        assert_eq!(asteroid_health.hit_points, 1);
+        assert!(asteroid_health.take_hit());
+        assert_eq!(asteroid_health.hit_points, 0);
+        assert_eq!(asteroid_health.maximum_hit_points, 3);
+    }
+
+    #[test]
+    fn health_never_goes_below_zero() {
+        let mut asteroid_health: Health = Health::new(1);
+
+        assert!(asteroid_health.take_hit());
+        assert!(asteroid_health.take_hit());
+        assert_eq!(asteroid_health.hit_points, 0);
+    }
+}
@@ -0,0 +1,584 @@
+use bevy::prelude::*;
+
+use crate::GameState;
+use crate::GameMode;
+use crate::GameRng;
+use crate::config::GameConfig;
+use crate::PausingState;
+use crate::InputSet;
+use crate::StartGame;
+use crate::ship_select::SelectedHulls;
+use crate::RestartGame;
+use crate::BackgroundMusic;
+use crate::asteroid::Asteroid;
+use crate::weapon::Weapon;
+use crate::network::NetworkSession;
+use crate::ui::{self, ScoreboardScore};
+
+use crate::hero_ship::{
+    self,
+    HeroShip,
+    HeroShipInput,
+    PlayerId
+};
+
+use crate::constants::autopilot::{
+    ATTRACT_MODE_IDLE_SECONDS,
+    SOAK_RESTART_DELAY_SECONDS,
+    SOAK_REPORT_INTERVAL_SECONDS,
+    THREAT_LOOKAHEAD_SECONDS,
+    THREAT_EVASION_SECONDS,
+    THREAT_SAFETY_MARGIN,
+    TARGET_MAX_INTERCEPT_SECONDS,
+    CRUISE_MOVEMENT_SPEED,
+    MINIMUM_AIM_TOLERANCE_RADIANS,
+    EVASION_THRUST_TOLERANCE_RADIANS
+};
+
+use crate::constants::ui_values::TOP_TEXT_VAL_PX;
+
+pub struct AutopilotPlugin;
+
+impl Plugin for AutopilotPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        if let Some(autopilot_mode) = AutopilotMode::from_args(std::env::args().skip(1).collect()) {
+            app.insert_resource(autopilot_mode);
+        }
+
+        app.init_resource::<AttractModeIdleTimer>();
+        app.init_resource::<AutopilotStatistics>();
+        app.init_resource::<SoakRestartTimer>();
+        app.init_resource::<SoakReportTimer>();
+        app.add_systems(Update, (
+            attach_autopilot_to_hero_ships
+        ).run_if(resource_exists::<AutopilotMode>));
+        app.add_systems(FixedUpdate, (
+            drive_hero_ship_autopilot
+        ).in_set(InputSet).after(hero_ship::read_hero_ship_keyboard_input));
+        app.add_systems(Update, (
+            check_for_attract_mode_idle
+        ).run_if(in_state(GameState::StartScreen).and_then(
+            not(resource_exists::<AutopilotMode>).and_then(not(resource_exists::<NetworkSession>))
+        )));
+        app.add_systems(OnEnter(GameState::InGame), (
+            spawn_attract_mode_text
+        ).run_if(resource_exists_and_equals(AutopilotMode::Attract)));
+        app.add_systems(Update, (
+            check_for_leaving_attract_mode_by_keyboard
+        ).after(crate::check_for_pausing_by_keyboard).run_if(
+            in_state(GameState::InGame).and_then(resource_exists_and_equals(AutopilotMode::Attract))
+        ));
+        app.add_systems(OnEnter(GameState::EndGame), (
+            leave_attract_mode
+        ).run_if(resource_exists_and_equals(AutopilotMode::Attract)));
+        app.add_systems(OnEnter(GameState::StartScreen), (
+            reset_after_attract_mode
+        ).run_if(resource_exists_and_equals(AutopilotMode::Attract)));
+        app.add_systems(OnEnter(GameState::InGame), (
+            start_soak_run
+        ).run_if(resource_exists_and_equals(AutopilotMode::Soak)));
+        app.add_systems(OnEnter(GameState::EndGame), (
+            finish_soak_run
+        ).run_if(resource_exists_and_equals(AutopilotMode::Soak)));
+        app.add_systems(Update, (
+            start_soak_test
+        ).before(crate::start_game).run_if(
+            in_state(GameState::StartScreen).and_then(resource_exists_and_equals(AutopilotMode::Soak))
+        ));
+        app.add_systems(Update, (
+            restart_soak_test
+        ).before(crate::restart_game).run_if(
+            in_state(GameState::EndGame).and_then(resource_exists_and_equals(AutopilotMode::Soak))
+        ));
+        app.add_systems(Update, (
+            report_soak_statistics
+        ).run_if(in_state(GameState::InGame).and_then(resource_exists_and_equals(AutopilotMode::Soak))));
+    }
+}
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutopilotMode {
+    Attract,
+    Soak
+}
+
+impl AutopilotMode {
+    fn from_args(args: Vec<String>) -> Option<Self> {
+        if args.iter().any(|arg| arg == "--autopilot") {
+            return Some(Self::Soak);
+        }
+        return None;
+    }
+}
+
+#[derive(Component)]
+pub struct Autopilot;
+
+#[derive(Component)]
+pub struct AttractModeText;
+
+#[derive(Resource, Deref, DerefMut)]
+pub struct AttractModeIdleTimer(pub Timer);
+
+impl Default for AttractModeIdleTimer {
+    fn default() -> Self {
+        return Self(Timer::from_seconds(ATTRACT_MODE_IDLE_SECONDS, TimerMode::Once));
+    }
+}
+
+#[derive(Resource, Deref, DerefMut)]
+pub struct SoakRestartTimer(pub Timer);
+
+impl Default for SoakRestartTimer {
+    fn default() -> Self {
+        return Self(Timer::from_seconds(SOAK_RESTART_DELAY_SECONDS, TimerMode::Once));
+    }
+}
+
+#[derive(Resource, Deref, DerefMut)]
+pub struct SoakReportTimer(pub Timer);
+
+impl Default for SoakReportTimer {
+    fn default() -> Self {
+        return Self(Timer::from_seconds(SOAK_REPORT_INTERVAL_SECONDS, TimerMode::Repeating));
+    }
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct AutopilotStatistics {
+    pub runs: usize,
+    pub total_score: usize,
+    pub best_score: usize,
+    pub total_survival_seconds: f32,
+    pub run_started_at: f32
+}
+
+impl AutopilotStatistics {
+    pub fn average_score(&self) -> f32 {
+        if self.runs == 0 {
+            return 0.;
+        }
+        return self.total_score as f32 / self.runs as f32;
+    }
+
+    pub fn average_survival_seconds(&self) -> f32 {
+        if self.runs == 0 {
+            return 0.;
+        }
+        return self.total_survival_seconds / self.runs as f32;
+    }
+}
+
+struct AsteroidTrack {
+    position: Vec2,
+    velocity: Vec2,
+    radius: f32
+}
+
+pub fn attach_autopilot_to_hero_ships(
+    mut commands: Commands,
+    hero_ship_query: Query<Entity, (With<HeroShip>, Without<Autopilot>)>
+) {
+    for hero_ship_entity in &hero_ship_query {
+        commands.entity(hero_ship_entity).insert(Autopilot);
+    }
+}
+
+pub fn drive_hero_ship_autopilot(
+    game_config: Res<GameConfig>,
+    mut hero_ship_query: Query<(
+        &HeroShip,
+        &Transform,
+        &Visibility,
+        &Weapon,
+        &mut HeroShipInput
+    ), With<Autopilot>>,
+    asteroid_query: Query<(&Asteroid, &Transform)>
+) {
+    let asteroid_tracks: Vec<AsteroidTrack> = asteroid_query.iter()
+        .map(|(asteroid, asteroid_transform)| AsteroidTrack {
+            position: asteroid_transform.translation.truncate(),
+            velocity: asteroid.movement_direction.truncate() * asteroid.movement_speed.unwrap_or(0.),
+            radius: asteroid.collider_ball_size
+        })
+        .collect();
+
+    for (
+        hero_ship_entity,
+        hero_ship_transform,
+        hero_ship_visibility,
+        weapon,
+        mut hero_ship_input
+    ) in &mut hero_ship_query {
+        if hero_ship_visibility == Visibility::Hidden {
+            *hero_ship_input = HeroShipInput::default();
+            continue;
+        }
+
+        *hero_ship_input = decide_autopilot_input(
+            hero_ship_entity,
+            hero_ship_transform,
+            weapon.projectile_movement_speed(&game_config),
+            &asteroid_tracks
+        );
+    }
+}
+
+fn decide_autopilot_input(
+    hero_ship_entity: &HeroShip,
+    hero_ship_transform: &Transform,
+    projectile_movement_speed: Option<f32>,
+    asteroid_tracks: &[AsteroidTrack]
+) -> HeroShipInput {
+    let mut hero_ship_input: HeroShipInput = HeroShipInput::default();
+    let hero_ship_position: Vec2 = hero_ship_transform.translation.truncate();
+    let hero_ship_facing: Vec2 = (hero_ship_transform.rotation * Vec3::Y).truncate();
+    let hero_ship_velocity: Vec2 = hero_ship_entity.velocity;
+
+    if let Some((threat_time, threat_closest_offset)) =
+        find_most_imminent_threat(hero_ship_position, hero_ship_velocity, asteroid_tracks)
+    {
+        if threat_time < THREAT_EVASION_SECONDS {
+            let evasion_direction: Vec2 = (-threat_closest_offset).try_normalize()
+                .unwrap_or(hero_ship_facing.perp());
+            let evasion_error: f32 = hero_ship_facing.angle_between(evasion_direction);
+
+            steer_towards(evasion_error, EVASION_THRUST_TOLERANCE_RADIANS / 2., hero_ship_entity, &mut hero_ship_input);
+            hero_ship_input.accelerate = evasion_error.abs() < EVASION_THRUST_TOLERANCE_RADIANS;
+            return hero_ship_input;
+        }
+    }
+
+    if let Some((aim_error, aim_tolerance)) = find_best_target(hero_ship_position, hero_ship_facing, projectile_movement_speed, asteroid_tracks) {
+        steer_towards(aim_error, aim_tolerance, hero_ship_entity, &mut hero_ship_input);
+        hero_ship_input.shoot = aim_error.abs() < aim_tolerance;
+    }
+
+    hero_ship_input.brake = hero_ship_entity.get_movement_speed() > CRUISE_MOVEMENT_SPEED;
+    return hero_ship_input;
+}
+
+fn find_most_imminent_threat(
+    hero_ship_position: Vec2,
+    hero_ship_velocity: Vec2,
+    asteroid_tracks: &[AsteroidTrack]
+) -> Option<(f32, Vec2)> {
+    let mut most_imminent_threat: Option<(f32, Vec2)> = None;
+
+    for asteroid_track in asteroid_tracks {
+        let relative_position: Vec2 = asteroid_track.position - hero_ship_position;
+        let relative_velocity: Vec2 = asteroid_track.velocity - hero_ship_velocity;
+        let closest_approach_time: f32 = if relative_velocity.length_squared() > f32::EPSILON {
+            (-relative_position.dot(relative_velocity) / relative_velocity.length_squared())
+                .clamp(0., THREAT_LOOKAHEAD_SECONDS)
+        } else {
+            0.
+        };
+        let closest_offset: Vec2 = relative_position + relative_velocity * closest_approach_time;
+
+        if
+            closest_offset.length() < asteroid_track.radius + THREAT_SAFETY_MARGIN &&
+            most_imminent_threat.is_none_or(|(threat_time, _)| closest_approach_time < threat_time)
+        {
+            most_imminent_threat = Some((closest_approach_time, closest_offset));
+        }
+    }
+    return most_imminent_threat;
+}
+
+fn find_best_target(
+    hero_ship_position: Vec2,
+    hero_ship_facing: Vec2,
+    projectile_movement_speed: Option<f32>,
+    asteroid_tracks: &[AsteroidTrack]
+) -> Option<(f32, f32)> {
+    let mut best_target: Option<(f32, f32, f32)> = None;
+
+    for asteroid_track in asteroid_tracks {
+        let relative_position: Vec2 = asteroid_track.position - hero_ship_position;
+        let Some(intercept_time) = calculate_intercept_time(
+            relative_position,
+            asteroid_track.velocity,
+            projectile_movement_speed
+        ) else {
+            continue;
+        };
+
+        if intercept_time > TARGET_MAX_INTERCEPT_SECONDS {
+            continue;
+        }
+
+        let intercept_offset: Vec2 = relative_position + asteroid_track.velocity * intercept_time;
+        let aim_error: f32 = hero_ship_facing.angle_between(intercept_offset);
+        let aim_tolerance: f32 = ((asteroid_track.radius / intercept_offset.length().max(1.)).atan() * 0.5)
+            .max(MINIMUM_AIM_TOLERANCE_RADIANS);
+        let target_cost: f32 = intercept_time + aim_error.abs();
+
+        if best_target.is_none_or(|(best_target_cost, _, _)| target_cost < best_target_cost) {
+            best_target = Some((target_cost, aim_error, aim_tolerance));
+        }
+    }
+    return best_target.map(|(_, aim_error, aim_tolerance)| (aim_error, aim_tolerance));
+}
+
+fn calculate_intercept_time(
+    relative_position: Vec2,
+    target_velocity: Vec2,
+    projectile_movement_speed: Option<f32>
+) -> Option<f32> {
+    let Some(projectile_movement_speed) = projectile_movement_speed else {
+        return Some(0.);
+    };
+    let quadratic_a: f32 = target_velocity.length_squared() - projectile_movement_speed * projectile_movement_speed;
+    let quadratic_b: f32 = 2. * relative_position.dot(target_velocity);
+    let quadratic_c: f32 = relative_position.length_squared();
+
+    if quadratic_a.abs() < f32::EPSILON {
+        if quadratic_b.abs() < f32::EPSILON {
+            return None;
+        }
+        let intercept_time: f32 = -quadratic_c / quadratic_b;
+        return (intercept_time > 0.).then_some(intercept_time);
+    }
+
+    let discriminant: f32 = quadratic_b * quadratic_b - 4. * quadratic_a * quadratic_c;
+
+    if discriminant < 0. {
+        return None;
+    }
+
+    let discriminant_root: f32 = discriminant.sqrt();
+    return [
+        (-quadratic_b - discriminant_root) / (2. * quadratic_a),
+        (-quadratic_b + discriminant_root) / (2. * quadratic_a)
+    ].into_iter()
+        .filter(|intercept_time| *intercept_time > 0.)
+        .min_by(|first_time, second_time| first_time.total_cmp(second_time));
+}
+
+fn steer_towards(
+    heading_error: f32,
+    heading_tolerance: f32,
+    hero_ship_entity: &HeroShip,
+    hero_ship_input: &mut HeroShipInput
+) {
+    let stopping_angle: f32 = hero_ship_entity.rotation_speed.powi(2) /
+        (2. * f32::to_radians(hero_ship_entity.rotation_speed_drag));
+    let is_coasting_onto_heading: bool =
+        hero_ship_entity.rotation_direction == heading_error.signum() && stopping_angle >= heading_error.abs();
+
+    if heading_error.abs() < heading_tolerance || is_coasting_onto_heading {
+        return;
+    }
+
+    if heading_error > 0. {
+        hero_ship_input.rotate_left = true;
+    } else {
+        hero_ship_input.rotate_right = true;
+    }
+}
+
+pub fn check_for_attract_mode_idle(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut attract_mode_idle_timer: ResMut<AttractModeIdleTimer>,
+    mut start_game_events: EventWriter<StartGame>
+) {
+    if keyboard_input.get_pressed().next().is_some() {
+        attract_mode_idle_timer.reset();
+        return;
+    }
+    attract_mode_idle_timer.tick(time.delta());
+
+    if attract_mode_idle_timer.just_finished() {
+        attract_mode_idle_timer.reset();
+        commands.insert_resource(AutopilotMode::Attract);
+        start_game_events.send(StartGame { game_mode: GameMode::SinglePlayer, seed: None });
+    }
+}
+
+pub fn spawn_attract_mode_text(
+    mut commands: Commands
+) {
+    commands.spawn((
+        TextBundle::from_section(
+            "Demo - Press any key!",
+            TextStyle {
+                font_size: 30.,
+                color: Color::SALMON,
+                ..default()
+            },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(TOP_TEXT_VAL_PX + 180.),
+            left: Val::Px(270.),
+            ..default()
+        }),
+        AttractModeText,
+    ));
+}
+
+pub fn check_for_leaving_attract_mode_by_keyboard(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    next_state: ResMut<NextState<GameState>>
+) {
+    if keyboard_input.get_just_pressed().next().is_some() {
+        leave_attract_mode(next_state);
+    }
+}
+
+pub fn leave_attract_mode(
+    mut next_state: ResMut<NextState<GameState>>
+) {
+    next_state.set(GameState::StartScreen);
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn reset_after_attract_mode(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    background_music: Res<BackgroundMusic>,
+    game_config: Res<GameConfig>,
+    game_mode: Res<GameMode>,
+    game_rng: ResMut<GameRng>,
+    selected_hulls: Res<SelectedHulls>,
+    mut next_state_pausing_state: ResMut<NextState<PausingState>>,
+    audio_query: Query<(Entity, &Handle<AudioSource>)>,
+    leftover_query: Query<Entity, Or<(
+        With<AttractModeText>,
+        With<ui::EndGameMenu>,
+        With<ui::PauseMenu>
+    )>>
+) {
+    for leftover_entity in &leftover_query {
+        commands.entity(leftover_entity).despawn_recursive();
+    }
+
+    for (audio_entity, audio_source_handle) in &audio_query {
+        if *audio_source_handle == background_music.0 {
+            commands.entity(audio_entity).despawn_recursive();
+        }
+    }
+
+    commands.remove_resource::<AutopilotMode>();
+    crate::reset_run(&mut commands);
+    hero_ship::spawn_hero_ship_lives(commands.reborrow(), &asset_server, PlayerId::One);
+    crate::setup_main_entities(commands, asset_server, game_config, game_mode, game_rng, selected_hulls);
+    next_state_pausing_state.set(PausingState::Running);
+}
+
+pub fn start_soak_test(
+    mut start_game_events: EventWriter<StartGame>
+) {
+    start_game_events.send(StartGame { game_mode: GameMode::SinglePlayer, seed: None });
+}
+
+pub fn restart_soak_test(
+    time: Res<Time>,
+    mut soak_restart_timer: ResMut<SoakRestartTimer>,
+    mut restart_game_events: EventWriter<RestartGame>
+) {
+    soak_restart_timer.tick(time.delta());
+
+    if soak_restart_timer.just_finished() {
+        soak_restart_timer.reset();
+        restart_game_events.send(RestartGame::default());
+    }
+}
+
+pub fn start_soak_run(
+    time: Res<Time>,
+    mut autopilot_statistics: ResMut<AutopilotStatistics>
+) {
+    autopilot_statistics.run_started_at = time.elapsed_seconds();
+}
+
+pub fn finish_soak_run(
+    time: Res<Time>,
+    scoreboard_score: Res<ScoreboardScore>,
+    mut autopilot_statistics: ResMut<AutopilotStatistics>
+) {
+    let run_score: usize = scoreboard_score.get(PlayerId::One);
+    let run_survival_seconds: f32 = time.elapsed_seconds() - autopilot_statistics.run_started_at;
+
+    autopilot_statistics.runs += 1;
+    autopilot_statistics.total_score += run_score;
+    autopilot_statistics.best_score = autopilot_statistics.best_score.max(run_score);
+    autopilot_statistics.total_survival_seconds += run_survival_seconds;
+
+    info!(
+        "Autopilot run {} ended with {} points after {:.1}s (average {:.1} points, {:.1}s, best {}).",
+        autopilot_statistics.runs,
+        run_score,
+        run_survival_seconds,
+        autopilot_statistics.average_score(),
+        autopilot_statistics.average_survival_seconds(),
+        autopilot_statistics.best_score
+    );
+}
+
+pub fn report_soak_statistics(
+    time: Res<Time>,
+    scoreboard_score: Res<ScoreboardScore>,
+    autopilot_statistics: Res<AutopilotStatistics>,
+    mut soak_report_timer: ResMut<SoakReportTimer>
+) {
+    soak_report_timer.tick(time.delta());
+
+    if soak_report_timer.just_finished() {
+        info!(
+            "Autopilot soak test: {} runs finished, current run at {} points (average {:.1} points, best {}).",
+            autopilot_statistics.runs,
+            scoreboard_score.get(PlayerId::One),
+            autopilot_statistics.average_score(),
+            autopilot_statistics.best_score
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::math::vec2;
+
+    #[test]
+    fn intercept_time_of_a_stationary_target_is_distance_over_projectile_speed() {
+        let intercept_time: f32 = calculate_intercept_time(vec2(300., 0.), Vec2::ZERO, Some(600.)).unwrap();
+
+        assert!((intercept_time - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn intercept_time_follows_the_weapon_projectile_speed() {
+        let slow_intercept_time: f32 = calculate_intercept_time(vec2(300., 100.), vec2(-40., 20.), Some(300.)).unwrap();
+        let fast_intercept_time: f32 = calculate_intercept_time(vec2(300., 100.), vec2(-40., 20.), Some(900.)).unwrap();
+
+        assert!(fast_intercept_time < slow_intercept_time);
+    }
+
+    #[test]
+    fn intercept_point_is_reached_by_the_projectile() {
+        let relative_position: Vec2 = vec2(250., -120.);
+        let target_velocity: Vec2 = vec2(60., 35.);
+        let intercept_time: f32 = calculate_intercept_time(relative_position, target_velocity, Some(500.)).unwrap();
+        let intercept_offset: Vec2 = relative_position + target_velocity * intercept_time;
+
+        assert!((intercept_offset.length() - 500. * intercept_time).abs() < 1e-2);
+    }
+
+    #[test]
+    fn hitscan_weapons_intercept_immediately() {
+        assert_eq!(calculate_intercept_time(vec2(300., 0.), vec2(50., 0.), None), Some(0.));
+    }
+
+    #[test]
+    fn target_outrunning_the_projectile_cannot_be_intercepted() {
+        assert_eq!(calculate_intercept_time(vec2(300., 0.), vec2(800., 0.), Some(500.)), None);
+    }
+}
@@ -7,26 +7,48 @@ use bevy::{
 };
 
 use bevy_rapier2d::prelude::*;
+use std::collections::HashSet;
 
 use crate::projectile::Projectile;
+use crate::power_up::ActivePowerUps;
+use crate::shop::ShipUpgrades;
 use crate::ui::ScoreboardScore;
-use crate::PausingState;
+use crate::statistics::RunStatistics;
+use crate::combo::{self, ScoreCombo};
+use crate::popup::ShowPopup;
 use crate::GameState;
+use crate::GameMode;
+use crate::GameRng;
+use crate::GameplaySet;
+use crate::config::GameConfig;
+use crate::energy_shield::{EnergyShield, bounce_asteroid_off_energy_shield};
 
 use crate::hero_ship::{
     HeroShip,
+    HeroShipHull,
+    PlayerId,
     HeroShipDestroyedSound,
     HeroShipRemainingLives,
+    HeroShipLifeLost,
     spawn_hero_ship_destroyed_spritesheet
 };
 
 use crate::asteroid::{
     Asteroid,
-    AsteroidType,
+    AsteroidDestroyed,
     AsteroidDestroyedSound,
+    AsteroidHitSound,
+    AsteroidHitFlash,
+    Health,
     spawn_asteroids_after_collision
 };
 
+use crate::constants::asteroid_hit_values::{
+    ASTEROID_HIT_FLASH_COLOR,
+    ASTEROID_HIT_PUSHBACK_DISTANCE,
+    ASTEROID_HIT_SOUND_VOLUME
+};
+
 pub struct CollisionPlugin;
 
 impl Plugin for CollisionPlugin {
@@ -34,70 +56,214 @@ impl Plugin for CollisionPlugin {
         &self,
         app: &mut App
     ) {
+        app.add_event::<AsteroidHit>();
         app.add_systems(FixedUpdate, (
             detect_asteroid_projectile_collision,
+            apply_asteroid_hits.after(detect_asteroid_projectile_collision),
             detect_asteroid_hero_ship_collision
-        ).run_if(in_state(PausingState::Running).and_then(in_state(GameState::InGame))));
+        ).in_set(GameplaySet));
+        app.add_systems(FixedUpdate, (
+            detect_projectile_hero_ship_collision
+        ).in_set(GameplaySet).run_if(resource_equals(GameMode::Versus)));
     }
 }
 
+#[derive(Event, Clone, Debug)]
+pub struct AsteroidHit {
+    pub asteroid_entity: Entity,
+    pub player_id: PlayerId,
+    pub direction: Vec3
+}
+
 pub fn detect_asteroid_projectile_collision(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
     mut collision_events: EventReader<CollisionEvent>,
+    mut asteroid_hit_events: EventWriter<AsteroidHit>,
+    mut run_statistics: ResMut<RunStatistics>,
+    mut projectile_query: Query<&mut Projectile>,
+    asteroid_query: Query<Entity, With<Asteroid>>
+) {
+    let mut consumed_projectile_entities: HashSet<Entity> = HashSet::new();
+
+    for collision_event in collision_events.read() {
+        let CollisionEvent::Started(first_entity, second_entity, _) = *collision_event else {
+            continue;
+        };
+
+        for (asteroid_entity, projectile_entity) in [(first_entity, second_entity), (second_entity, first_entity)] {
+            if consumed_projectile_entities.contains(&projectile_entity) {
+                continue;
+            }
+
+            let (Ok(asteroid_entity), Ok(mut projectile_component)) =
+                (asteroid_query.get(asteroid_entity), projectile_query.get_mut(projectile_entity))
+            else {
+                continue;
+            };
+
+            if !projectile_component.has_hit_asteroid {
+                projectile_component.has_hit_asteroid = true;
+                run_statistics.shots_hit += 1;
+            }
+
+            if projectile_component.pierce_count > 0 {
+                projectile_component.pierce_count -= 1;
+            } else {
+                consumed_projectile_entities.insert(projectile_entity);
+                commands.entity(projectile_entity).despawn();
+            }
+
+            asteroid_hit_events.send(AsteroidHit {
+                asteroid_entity,
+                player_id: projectile_component.owner,
+                direction: projectile_component.direction
+            });
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn apply_asteroid_hits(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut asteroid_hit_events: EventReader<AsteroidHit>,
+    game_config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>,
     mut scoreboard_score: ResMut<ScoreboardScore>,
+    mut asteroid_destroyed_events: EventWriter<AsteroidDestroyed>,
     asteroid_destroyed_sound: Res<AsteroidDestroyedSound>,
-    projectile_query: Query<(Entity, &Projectile)>,
-    asteroid_query: Query<(Entity, &Asteroid, &Transform), Without<HeroShip>>
+    asteroid_hit_sound: Res<AsteroidHitSound>,
+    mut show_popup_events: EventWriter<ShowPopup>,
+    mut score_combo_query: Query<(&PlayerId, &mut ScoreCombo), With<HeroShip>>,
+    mut asteroid_query: Query<(
+        &Asteroid,
+        &mut Transform,
+        &mut Sprite,
+        Option<&mut Health>
+    ), Without<HeroShip>>
 ) {
-    for collision_event in collision_events.read() {
-        match *collision_event {
-            CollisionEvent::Started(first_entity, second_entity, _) => {
-                for (asteroid_entity, asteroid_component, asteroid_transform) in &asteroid_query {
-                    for (projectile_entity, _) in &projectile_query {
-                        if 
-                            (asteroid_entity == first_entity || asteroid_entity == second_entity) &&
-                            (projectile_entity == first_entity || projectile_entity == second_entity)
-                        {
-                            commands.entity(projectile_entity).despawn();
-                            commands.entity(asteroid_entity).despawn();
+    let mut destroyed_asteroid_entities: HashSet<Entity> = HashSet::new();
 
-                            commands.spawn(AudioBundle {
-                                source: asteroid_destroyed_sound.clone(),
-                                settings: PlaybackSettings::DESPAWN
-                            });
+    for asteroid_hit_event in asteroid_hit_events.read() {
+        if destroyed_asteroid_entities.contains(&asteroid_hit_event.asteroid_entity) {
+            continue;
+        }
 
-                            spawn_asteroids_after_collision(
-                                commands.reborrow(),
-                                &asset_server,
-                                asteroid_component,
-                                asteroid_transform
-                            );
+        let Ok((
+            asteroid_component,
+            mut asteroid_transform,
+            mut asteroid_sprite,
+            mut asteroid_health
+        )) = asteroid_query.get_mut(asteroid_hit_event.asteroid_entity) else {
+            continue;
+        };
 
-                            increase_score_based_on_asteroid_type(
-                                asteroid_component,
-                                &mut scoreboard_score
-                            );
-                        }
-                    }
-                }
-            },
-            CollisionEvent::Stopped(_, _, _) => ()
+        if let Some(asteroid_health) = asteroid_health.as_deref_mut() {
+            if !asteroid_health.take_hit() {
+                damage_armored_asteroid(
+                    commands.reborrow(),
+                    &asteroid_hit_sound,
+                    asteroid_hit_event,
+                    &mut asteroid_transform,
+                    &mut asteroid_sprite
+                );
+
+                increase_score_based_on_asteroid_hit(
+                    asteroid_component,
+                    asteroid_hit_event.player_id,
+                    combo::get_combo_multiplier(&score_combo_query, asteroid_hit_event.player_id, &game_config.combo),
+                    &game_config,
+                    &mut scoreboard_score
+                );
+                continue;
+            }
         }
+        destroyed_asteroid_entities.insert(asteroid_hit_event.asteroid_entity);
+        commands.entity(asteroid_hit_event.asteroid_entity).despawn();
+        asteroid_destroyed_events.send(AsteroidDestroyed {
+            position: asteroid_transform.translation,
+            archetype: asteroid_component.archetype.clone()
+        });
+
+        commands.spawn(AudioBundle {
+            source: asteroid_destroyed_sound.clone(),
+            settings: PlaybackSettings::DESPAWN
+        });
+
+        spawn_asteroids_after_collision(
+            commands.reborrow(),
+            &asset_server,
+            &game_config,
+            &mut game_rng,
+            asteroid_component,
+            &asteroid_transform
+        );
+
+        let combo_multiplier: u32 = combo::record_combo_kill(&mut score_combo_query, asteroid_hit_event.player_id, &game_config.combo);
+        let base_points: usize = increase_score_based_on_asteroid_archetype(
+            asteroid_component,
+            asteroid_hit_event.player_id,
+            combo_multiplier,
+            &game_config,
+            &mut scoreboard_score
+        );
+
+        show_popup_events.send(ShowPopup {
+            message: combo::get_score_popup_message(base_points, combo_multiplier),
+            translation: asteroid_transform.translation,
+            color: asteroid_hit_event.player_id.color()
+        });
     }
 }
 
-fn increase_score_based_on_asteroid_type(
+fn increase_score_based_on_asteroid_archetype(
+    asteroid_component: &Asteroid,
+    player_id: PlayerId,
+    combo_multiplier: u32,
+    game_config: &GameConfig,
+    scoreboard_score: &mut ResMut<ScoreboardScore>
+) -> usize {
+    let Some(asteroid_archetype) = game_config.asteroids.get_archetype(&asteroid_component.archetype) else {
+        return 0;
+    };
+    scoreboard_score.increase(player_id, asteroid_archetype.score * combo_multiplier as usize);
+    return asteroid_archetype.score;
+}
+
+fn increase_score_based_on_asteroid_hit(
     asteroid_component: &Asteroid,
+    player_id: PlayerId,
+    combo_multiplier: u32,
+    game_config: &GameConfig,
     scoreboard_score: &mut ResMut<ScoreboardScore>
 ) {
-    match asteroid_component.asteroid_type {
-        AsteroidType::Small => { scoreboard_score.score += 50; },
-        AsteroidType::Medium => { scoreboard_score.score += 30; },
-        AsteroidType::Big => { scoreboard_score.score += 10; }
+    if let Some(asteroid_archetype) = game_config.asteroids.get_archetype(&asteroid_component.archetype) {
+        scoreboard_score.increase(player_id, asteroid_archetype.hit_score * combo_multiplier as usize);
     }
 }
 
+fn damage_armored_asteroid(
+    mut commands: Commands,
+    asteroid_hit_sound: &Res<AsteroidHitSound>,
+    asteroid_hit_event: &AsteroidHit,
+    asteroid_transform: &mut Transform,
+    asteroid_sprite: &mut Sprite
+) {
+    asteroid_transform.translation += asteroid_hit_event.direction.normalize_or_zero() * ASTEROID_HIT_PUSHBACK_DISTANCE;
+    asteroid_sprite.color = ASTEROID_HIT_FLASH_COLOR;
+    commands.entity(asteroid_hit_event.asteroid_entity).insert(AsteroidHitFlash::default());
+
+    commands.spawn(AudioBundle {
+        source: asteroid_hit_sound.0.clone(),
+        settings: PlaybackSettings {
+            mode: Despawn,
+            volume: Volume::new(ASTEROID_HIT_SOUND_VOLUME),
+            ..default()
+        }
+    });
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn detect_asteroid_hero_ship_collision(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -106,48 +272,159 @@ pub fn detect_asteroid_hero_ship_collision(
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     mut hero_ship_remaining_lives: ResMut<HeroShipRemainingLives>,
     mut hero_ship_destroyed_sound: Res<HeroShipDestroyedSound>,
-    mut hero_ship_query: Query<(Entity, &HeroShip, &mut Transform, &mut Visibility, &mut CollisionGroups), Without<Asteroid>>,
-    asteroid_query: Query<Entity, With<Asteroid>>
+    asteroid_hit_sound: Res<AsteroidHitSound>,
+    mut show_popup_events: EventWriter<ShowPopup>,
+    mut hero_ship_life_lost_events: EventWriter<HeroShipLifeLost>,
+    game_config: Res<GameConfig>,
+    mut hero_ship_query: Query<(
+        Entity,
+        &PlayerId,
+        &Transform,
+        &mut Visibility,
+        &mut CollisionGroups,
+        &mut ActivePowerUps,
+        &mut ShipUpgrades,
+        &HeroShipHull,
+        &EnergyShield
+    ), (With<HeroShip>, Without<Asteroid>)>,
+    mut asteroid_query: Query<(Entity, &mut Asteroid, &mut Transform), Without<HeroShip>>
 ) {
     for collision_event in collision_events.read() {
         match *collision_event {
             CollisionEvent::Started(first_entity, second_entity, _) => {
-                let (
+                for (
                     hero_ship_entity,
-                    _,
+                    player_id,
                     hero_ship_transform,
                     mut hero_ship_visibility,
-                    mut hero_ship_collision_groups
-                ) = hero_ship_query.single_mut();
-
-                for asteroid_entity in &asteroid_query {
-                    if
-                        (asteroid_entity == first_entity || asteroid_entity == second_entity) &&
-                        (hero_ship_entity == first_entity || hero_ship_entity == second_entity)
-                    {
-                        if hero_ship_remaining_lives.lives_remaining > 0 {
-                            hero_ship_remaining_lives.lives_remaining -= 1;
-                        }
+                    mut hero_ship_collision_groups,
+                    mut active_power_ups,
+                    mut ship_upgrades,
+                    hero_ship_hull,
+                    energy_shield
+                ) in &mut hero_ship_query {
+                    for (asteroid_entity, mut asteroid, mut asteroid_transform) in &mut asteroid_query {
+                        if
+                            (asteroid_entity == first_entity || asteroid_entity == second_entity) &&
+                            (hero_ship_entity == first_entity || hero_ship_entity == second_entity)
+                        {
+                            if energy_shield.is_raised {
+                                bounce_asteroid_off_energy_shield(
+                                    commands.reborrow(),
+                                    &asteroid_hit_sound,
+                                    &game_config.energy_shield,
+                                    hero_ship_transform.translation,
+                                    &mut asteroid,
+                                    &mut asteroid_transform
+                                );
+                                continue;
+                            }
+
+                            if absorb_hit_with_shield(commands.reborrow(), &asteroid_hit_sound, &mut active_power_ups, &mut ship_upgrades) {
+                                continue;
+                            }
+
+                            decrease_hero_ship_lives(
+                                &mut next_state,
+                                &mut hero_ship_remaining_lives,
+                                &mut show_popup_events,
+                                &mut hero_ship_life_lost_events,
+                                *player_id,
+                                hero_ship_transform.translation,
+                                &mut hero_ship_visibility,
+                                &mut hero_ship_collision_groups
+                            );
+                            active_power_ups.clear();
 
-                        if hero_ship_remaining_lives.lives_remaining == 0 {
-                            next_state.set(GameState::EndGame);
                             spawn_hero_ship_destroyed(
                                 commands.reborrow(),
                                 &asset_server,
                                 &mut texture_atlas_layouts,
                                 &mut hero_ship_destroyed_sound,
-                                hero_ship_transform.clone()
+                                hero_ship_hull,
+                                *hero_ship_transform
                             );
-                        } else {
-                            *hero_ship_visibility = Visibility::Hidden;
-                            *hero_ship_collision_groups = CollisionGroups::new(Group::GROUP_1, Group::all());
+                        }
+                    }
+                }
+            },
+            CollisionEvent::Stopped(_, _, _) => ()
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn detect_projectile_hero_ship_collision(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    game_config: Res<GameConfig>,
+    mut scoreboard_score: ResMut<ScoreboardScore>,
+    mut hero_ship_remaining_lives: ResMut<HeroShipRemainingLives>,
+    mut hero_ship_destroyed_sound: Res<HeroShipDestroyedSound>,
+    asteroid_hit_sound: Res<AsteroidHitSound>,
+    mut show_popup_events: EventWriter<ShowPopup>,
+    mut hero_ship_life_lost_events: EventWriter<HeroShipLifeLost>,
+    mut hero_ship_query: Query<(
+        Entity,
+        &PlayerId,
+        &Transform,
+        &mut Visibility,
+        &mut CollisionGroups,
+        &mut ActivePowerUps,
+        &mut ShipUpgrades,
+        &HeroShipHull
+    ), With<HeroShip>>,
+    projectile_query: Query<(Entity, &Projectile)>
+) {
+    for collision_event in collision_events.read() {
+        match *collision_event {
+            CollisionEvent::Started(first_entity, second_entity, _) => {
+                for (
+                    hero_ship_entity,
+                    player_id,
+                    hero_ship_transform,
+                    mut hero_ship_visibility,
+                    mut hero_ship_collision_groups,
+                    mut active_power_ups,
+                    mut ship_upgrades,
+                    hero_ship_hull
+                ) in &mut hero_ship_query {
+                    for (projectile_entity, projectile_component) in &projectile_query {
+                        if
+                            (projectile_entity == first_entity || projectile_entity == second_entity) &&
+                            (hero_ship_entity == first_entity || hero_ship_entity == second_entity) &&
+                            projectile_component.owner != *player_id &&
+                            *hero_ship_visibility == Visibility::Visible
+                        {
+                            commands.entity(projectile_entity).despawn();
+
+                            if absorb_hit_with_shield(commands.reborrow(), &asteroid_hit_sound, &mut active_power_ups, &mut ship_upgrades) {
+                                continue;
+                            }
+                            scoreboard_score.increase(projectile_component.owner, game_config.scores.hero_ship_destroyed);
+
+                            decrease_hero_ship_lives(
+                                &mut next_state,
+                                &mut hero_ship_remaining_lives,
+                                &mut show_popup_events,
+                                &mut hero_ship_life_lost_events,
+                                *player_id,
+                                hero_ship_transform.translation,
+                                &mut hero_ship_visibility,
+                                &mut hero_ship_collision_groups
+                            );
+                            active_power_ups.clear();
 
                             spawn_hero_ship_destroyed(
                                 commands.reborrow(),
                                 &asset_server,
                                 &mut texture_atlas_layouts,
                                 &mut hero_ship_destroyed_sound,
-                                hero_ship_transform.clone()
+                                hero_ship_hull,
+                                *hero_ship_transform
                             );
                         }
                     }
@@ -158,11 +435,74 @@ pub fn detect_asteroid_hero_ship_collision(
     }
 }
 
+fn absorb_hit_with_shield(
+    mut commands: Commands,
+    asteroid_hit_sound: &Res<AsteroidHitSound>,
+    active_power_ups: &mut ActivePowerUps,
+    ship_upgrades: &mut ShipUpgrades
+) -> bool {
+    if !active_power_ups.consume_shield() && !ship_upgrades.consume_shield_charge() {
+        return false;
+    }
+
+    commands.spawn(AudioBundle {
+        source: asteroid_hit_sound.0.clone(),
+        settings: PlaybackSettings {
+            mode: Despawn,
+            volume: Volume::new(ASTEROID_HIT_SOUND_VOLUME),
+            ..default()
+        }
+    });
+    return true;
+}
+
+#[allow(clippy::too_many_arguments)]
+fn decrease_hero_ship_lives(
+    next_state: &mut ResMut<NextState<GameState>>,
+    hero_ship_remaining_lives: &mut ResMut<HeroShipRemainingLives>,
+    show_popup_events: &mut EventWriter<ShowPopup>,
+    hero_ship_life_lost_events: &mut EventWriter<HeroShipLifeLost>,
+    player_id: PlayerId,
+    hero_ship_translation: Vec3,
+    hero_ship_visibility: &mut Visibility,
+    hero_ship_collision_groups: &mut CollisionGroups
+) {
+    if let Some(lives_remaining) = hero_ship_remaining_lives.lives_remaining.get_mut(&player_id) {
+        *lives_remaining = lives_remaining.saturating_sub(1);
+    }
+    hero_ship_life_lost_events.send(HeroShipLifeLost { player_id });
+
+    show_popup_events.send(ShowPopup {
+        message: get_life_lost_popup_message(hero_ship_remaining_lives.get(player_id)),
+        translation: hero_ship_translation,
+        color: Color::RED
+    });
+
+    if hero_ship_remaining_lives.are_all_lives_lost() {
+        next_state.set(GameState::EndGame);
+    } else if hero_ship_remaining_lives.get(player_id) == 0 {
+        *hero_ship_visibility = Visibility::Hidden;
+        *hero_ship_collision_groups = CollisionGroups::new(Group::NONE, Group::NONE);
+    } else {
+        *hero_ship_visibility = Visibility::Hidden;
+        *hero_ship_collision_groups = CollisionGroups::new(Group::GROUP_1, Group::all());
+    }
+}
+
+fn get_life_lost_popup_message(lives_remaining: usize) -> String {
+    match lives_remaining {
+        0 => return "Out of lives!".to_string(),
+        1 => return "Last life!".to_string(),
+        _ => return format!("-1 life, {} left", lives_remaining)
+    }
+}
+
 fn spawn_hero_ship_destroyed(
     mut commands: Commands,
     asset_server: &Res<AssetServer>,
     texture_atlas_layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
     hero_ship_destroyed_sound: &mut Res<HeroShipDestroyedSound>,
+    hero_ship_hull: &HeroShipHull,
     hero_ship_transform: Transform
 ) {
     commands.spawn(AudioBundle {
@@ -176,8 +516,166 @@ fn spawn_hero_ship_destroyed(
 
     spawn_hero_ship_destroyed_spritesheet(
         commands.reborrow(),
-        &asset_server,
+        asset_server,
         texture_atlas_layouts,
+        &hero_ship_hull.sprites.destroyed,
         hero_ship_transform.translation
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_rapier2d::rapier::geometry::CollisionEventFlags;
+    use crate::config::{AsteroidArchetypeConfig, AsteroidSplitConfig};
+    use crate::constants::image_handles::MEDIUM_ASTEROID_HANDLE_IMAGE;
+
+    fn build_asteroid_hits_app() -> App {
+        let mut game_config: GameConfig = GameConfig::default();
+        game_config.asteroids.archetypes.push(AsteroidArchetypeConfig {
+            name: "armored".to_string(),
+            sprite: MEDIUM_ASTEROID_HANDLE_IMAGE.to_string(),
+            collider_ball_size: 30.,
+            movement_speed: (110., 110.),
+            rotation_speed: (60., 60.),
+            hit_points: 3,
+            hit_score: 5,
+            score: 60,
+            split_into: vec![AsteroidSplitConfig { archetype: "small".to_string(), count: 2 }]
+        });
+
+        let mut app: App = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<Image>();
+        app.init_asset::<AudioSource>();
+        app.insert_resource(game_config);
+        app.init_resource::<GameRng>();
+        app.init_resource::<ScoreboardScore>();
+        app.init_resource::<AsteroidDestroyedSound>();
+        app.init_resource::<AsteroidHitSound>();
+        app.add_event::<AsteroidHit>();
+        app.add_event::<AsteroidDestroyed>();
+        app.add_event::<ShowPopup>();
+        app.add_systems(Update, apply_asteroid_hits);
+        return app;
+    }
+
+    fn spawn_test_asteroid(
+        app: &mut App,
+        archetype_name: &str,
+        hit_points: u32
+    ) -> Entity {
+        let asteroid_entity: Entity = app.world.spawn((
+            Asteroid { archetype: archetype_name.to_string(), ..default() },
+            Transform::default(),
+            Sprite::default()
+        )).id();
+
+        if hit_points > 1 {
+            app.world.entity_mut(asteroid_entity).insert(Health::new(hit_points));
+        }
+        return asteroid_entity;
+    }
+
+    fn hit_asteroid(
+        app: &mut App,
+        asteroid_entity: Entity
+    ) {
+        app.world.send_event(AsteroidHit { asteroid_entity, player_id: PlayerId::One, direction: Vec3::X });
+        app.update();
+    }
+
+    fn count_asteroids(
+        app: &mut App,
+        archetype_name: &str
+    ) -> usize {
+        return app.world.query::<&Asteroid>().iter(&app.world)
+            .filter(|asteroid_component| asteroid_component.archetype == archetype_name)
+            .count();
+    }
+
+    fn build_asteroid_projectile_collision_app() -> App {
+        let mut app: App = App::new();
+        app.init_resource::<RunStatistics>();
+        app.add_event::<CollisionEvent>();
+        app.add_event::<AsteroidHit>();
+        app.add_systems(Update, detect_asteroid_projectile_collision);
+        return app;
+    }
+
+    fn touch_asteroids_with_projectile(
+        app: &mut App,
+        pierce_count: u32,
+        asteroid_count: usize
+    ) -> usize {
+        let projectile_entity: Entity = app.world.spawn(Projectile { pierce_count, ..default() }).id();
+
+        for _ in 0..asteroid_count {
+            let asteroid_entity: Entity = app.world.spawn(Asteroid::default()).id();
+            app.world.send_event(CollisionEvent::Started(asteroid_entity, projectile_entity, CollisionEventFlags::empty()));
+        }
+        app.update();
+
+        return app.world.resource::<Events<AsteroidHit>>().len();
+    }
+
+    #[test]
+    fn projectile_touching_two_asteroids_in_one_tick_only_damages_the_first() {
+        let mut app: App = build_asteroid_projectile_collision_app();
+
+        assert_eq!(touch_asteroids_with_projectile(&mut app, 0, 2), 1);
+        assert_eq!(app.world.resource::<RunStatistics>().shots_hit, 1);
+    }
+
+    #[test]
+    fn piercing_projectile_damages_one_asteroid_more_than_its_pierce_count() {
+        let mut app: App = build_asteroid_projectile_collision_app();
+
+        assert_eq!(touch_asteroids_with_projectile(&mut app, 1, 3), 2);
+    }
+
+    #[test]
+    fn armored_asteroid_only_splits_once_its_health_runs_out() {
+        let mut app: App = build_asteroid_hits_app();
+        let asteroid_entity: Entity = spawn_test_asteroid(&mut app, "armored", 3);
+
+        hit_asteroid(&mut app, asteroid_entity);
+        assert_eq!(app.world.get::<Health>(asteroid_entity).unwrap().hit_points, 2);
+        assert_eq!(app.world.get::<Transform>(asteroid_entity).unwrap().translation, Vec3::X * ASTEROID_HIT_PUSHBACK_DISTANCE);
+        assert_eq!(app.world.get::<Sprite>(asteroid_entity).unwrap().color, ASTEROID_HIT_FLASH_COLOR);
+        assert!(app.world.get::<AsteroidHitFlash>(asteroid_entity).is_some());
+
+        hit_asteroid(&mut app, asteroid_entity);
+        assert_eq!(app.world.get::<Health>(asteroid_entity).unwrap().hit_points, 1);
+        assert_eq!(count_asteroids(&mut app, "small"), 0);
+
+        hit_asteroid(&mut app, asteroid_entity);
+        assert!(app.world.get_entity(asteroid_entity).is_none());
+        assert_eq!(count_asteroids(&mut app, "small"), 2);
+    }
+
+    #[test]
+    fn armored_asteroid_rewards_partial_hits_and_its_destruction() {
+        let mut app: App = build_asteroid_hits_app();
+        let asteroid_entity: Entity = spawn_test_asteroid(&mut app, "armored", 3);
+
+        hit_asteroid(&mut app, asteroid_entity);
+        assert_eq!(app.world.resource::<ScoreboardScore>().get(PlayerId::One), 5);
+
+        hit_asteroid(&mut app, asteroid_entity);
+        hit_asteroid(&mut app, asteroid_entity);
+        assert_eq!(app.world.resource::<ScoreboardScore>().get(PlayerId::One), 5 + 5 + 60);
+    }
+
+    #[test]
+    fn asteroid_without_health_is_destroyed_by_a_single_hit() {
+        let mut app: App = build_asteroid_hits_app();
+        let asteroid_entity: Entity = spawn_test_asteroid(&mut app, "medium", 1);
+
+        hit_asteroid(&mut app, asteroid_entity);
+
+        assert!(app.world.get_entity(asteroid_entity).is_none());
+        assert_eq!(count_asteroids(&mut app, "small"), 2);
+        assert_eq!(app.world.resource::<ScoreboardScore>().get(PlayerId::One), 30);
+    }
+}
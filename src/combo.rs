@@ -0,0 +1,238 @@
+use bevy::prelude::*;
+
+use crate::GameplaySet;
+use crate::collision;
+use crate::config::{GameConfig, ComboConfig};
+use crate::projectile::{self, ProjectileMissed};
+
+use crate::hero_ship::{
+    HeroShip,
+    HeroShipLifeLost,
+    PlayerId
+};
+
+pub struct ComboPlugin;
+
+impl Plugin for ComboPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.add_systems(FixedUpdate, (
+            tick_score_combos,
+            reset_score_combos_on_miss_streak.after(projectile::despawn_projectile_after_border_outbounds),
+            reset_score_combos_on_life_lost
+                .after(collision::detect_asteroid_hero_ship_collision)
+                .after(collision::detect_projectile_hero_ship_collision)
+        ).in_set(GameplaySet));
+    }
+}
+
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct ScoreCombo {
+    pub kills: u32,
+    pub window_seconds_remaining: f32,
+    pub miss_streak: u32
+}
+
+impl ScoreCombo {
+    pub fn get_multiplier(
+        &self,
+        combo_config: &ComboConfig
+    ) -> u32 {
+        return (1 + self.kills / combo_config.kills_per_multiplier).min(combo_config.maximum_multiplier);
+    }
+
+    pub fn record_kill(
+        &mut self,
+        combo_config: &ComboConfig
+    ) -> u32 {
+        if self.window_seconds_remaining <= 0. {
+            self.kills = 0;
+        }
+        self.kills += 1;
+        self.window_seconds_remaining = combo_config.window_seconds;
+        self.miss_streak = 0;
+        return self.get_multiplier(combo_config);
+    }
+
+    pub fn record_miss(
+        &mut self,
+        combo_config: &ComboConfig
+    ) {
+        self.miss_streak += 1;
+
+        if self.miss_streak >= combo_config.miss_streak_reset {
+            self.reset();
+        }
+    }
+
+    pub fn tick(
+        &mut self,
+        delta_seconds: f32
+    ) {
+        self.window_seconds_remaining = (self.window_seconds_remaining - delta_seconds).max(0.);
+
+        if self.window_seconds_remaining == 0. {
+            self.kills = 0;
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+pub fn record_combo_kill(
+    score_combo_query: &mut Query<(&PlayerId, &mut ScoreCombo), With<HeroShip>>,
+    player_id: PlayerId,
+    combo_config: &ComboConfig
+) -> u32 {
+    for (hero_ship_player_id, mut score_combo) in score_combo_query.iter_mut() {
+        if *hero_ship_player_id == player_id {
+            return score_combo.record_kill(combo_config);
+        }
+    }
+    return 1;
+}
+
+pub fn get_combo_multiplier(
+    score_combo_query: &Query<(&PlayerId, &mut ScoreCombo), With<HeroShip>>,
+    player_id: PlayerId,
+    combo_config: &ComboConfig
+) -> u32 {
+    for (hero_ship_player_id, score_combo) in score_combo_query.iter() {
+        if *hero_ship_player_id == player_id {
+            return score_combo.get_multiplier(combo_config);
+        }
+    }
+    return 1;
+}
+
+pub fn get_score_popup_message(
+    base_points: usize,
+    multiplier: u32
+) -> String {
+    if multiplier > 1 {
+        return format!("+{} x{}", base_points, multiplier);
+    }
+    return format!("+{}", base_points);
+}
+
+pub fn tick_score_combos(
+    time: Res<Time>,
+    mut score_combo_query: Query<&mut ScoreCombo, With<HeroShip>>
+) {
+    for mut score_combo in &mut score_combo_query {
+        score_combo.tick(time.delta_seconds());
+    }
+}
+
+pub fn reset_score_combos_on_miss_streak(
+    game_config: Res<GameConfig>,
+    mut projectile_missed_events: EventReader<ProjectileMissed>,
+    mut score_combo_query: Query<(&PlayerId, &mut ScoreCombo), With<HeroShip>>
+) {
+    for projectile_missed_event in projectile_missed_events.read() {
+        for (player_id, mut score_combo) in &mut score_combo_query {
+            if *player_id == projectile_missed_event.owner {
+                score_combo.record_miss(&game_config.combo);
+            }
+        }
+    }
+}
+
+pub fn reset_score_combos_on_life_lost(
+    mut hero_ship_life_lost_events: EventReader<HeroShipLifeLost>,
+    mut score_combo_query: Query<(&PlayerId, &mut ScoreCombo), With<HeroShip>>
+) {
+    for hero_ship_life_lost_event in hero_ship_life_lost_events.read() {
+        for (player_id, mut score_combo) in &mut score_combo_query {
+            if *player_id == hero_ship_life_lost_event.player_id {
+                score_combo.reset();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_combo_config() -> ComboConfig {
+        return ComboConfig {
+            window_seconds: 2.,
+            kills_per_multiplier: 3,
+            maximum_multiplier: 3,
+            miss_streak_reset: 3
+        };
+    }
+
+    #[test]
+    fn multiplier_grows_every_few_kills_up_to_the_maximum() {
+        let combo_config: ComboConfig = get_test_combo_config();
+        let mut score_combo: ScoreCombo = ScoreCombo::default();
+        let multipliers: Vec<u32> = (0..9).map(|_| score_combo.record_kill(&combo_config)).collect();
+
+        assert_eq!(multipliers, vec![1, 1, 2, 2, 2, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn combo_is_lost_when_the_window_runs_out() {
+        let combo_config: ComboConfig = get_test_combo_config();
+        let mut score_combo: ScoreCombo = ScoreCombo::default();
+
+        for _ in 0..3 {
+            score_combo.record_kill(&combo_config);
+        }
+        score_combo.tick(1.5);
+        assert_eq!(score_combo.get_multiplier(&combo_config), 2);
+
+        score_combo.tick(0.5);
+        assert_eq!(score_combo.get_multiplier(&combo_config), 1);
+        assert_eq!(score_combo.record_kill(&combo_config), 1);
+    }
+
+    #[test]
+    fn combo_is_lost_after_a_streak_of_misses() {
+        let combo_config: ComboConfig = get_test_combo_config();
+        let mut score_combo: ScoreCombo = ScoreCombo::default();
+
+        for _ in 0..3 {
+            score_combo.record_kill(&combo_config);
+        }
+        score_combo.record_miss(&combo_config);
+        score_combo.record_miss(&combo_config);
+        score_combo.record_kill(&combo_config);
+        score_combo.record_miss(&combo_config);
+        score_combo.record_miss(&combo_config);
+        assert_eq!(score_combo.kills, 4);
+
+        score_combo.record_miss(&combo_config);
+        assert_eq!(score_combo.kills, 0);
+    }
+
+    #[test]
+    fn score_popup_shows_the_base_points_and_the_multiplier() {
+        assert_eq!(get_score_popup_message(100, 1), "+100");
+        assert_eq!(get_score_popup_message(100, 3), "+100 x3");
+    }
+
+    #[test]
+    fn only_the_hero_ship_that_lost_a_life_loses_its_combo() {
+        let mut app: App = App::new();
+        app.add_event::<HeroShipLifeLost>();
+        app.add_systems(Update, reset_score_combos_on_life_lost);
+
+        let score_combo: ScoreCombo = ScoreCombo { kills: 4, window_seconds_remaining: 1., miss_streak: 0 };
+        let first_hero_ship_entity: Entity = app.world.spawn((HeroShip::default(), PlayerId::One, score_combo)).id();
+        let second_hero_ship_entity: Entity = app.world.spawn((HeroShip::default(), PlayerId::Two, score_combo)).id();
+
+        app.world.send_event(HeroShipLifeLost { player_id: PlayerId::One });
+        app.update();
+
+        assert_eq!(app.world.get::<ScoreCombo>(first_hero_ship_entity).unwrap().kills, 0);
+        assert_eq!(app.world.get::<ScoreCombo>(second_hero_ship_entity).unwrap().kills, 4);
+    }
+}
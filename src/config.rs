@@ -0,0 +1,1125 @@
+use bevy::{
+    prelude::*,
+    asset::{
+        io::Reader,
+        AssetLoader,
+        AsyncReadExt,
+        LoadContext
+    },
+    utils::BoxedFuture
+};
+
+use bevy_rapier2d::prelude::*;
+use serde::Deserialize;
+use thiserror::Error;
+
+use std::{
+    sync::{
+        Arc,
+        Mutex
+    },
+    time::Duration
+};
+
+use crate::asteroid::{
+    Asteroid,
+    AsteroidCheckForTypesTimer,
+    Health
+};
+
+use crate::hero_ship::{
+    FlightModel,
+    HeroShip,
+    HeroShipHull,
+    HeroShipRespawnTimer,
+    HeroShipStillAliveTimer
+};
+
+use crate::projectile::ProjectileSpawnTimer;
+use crate::shop::ShipUpgrades;
+use crate::weapon::Weapon;
+use crate::ui::ShowToast;
+
+use crate::constants::config_handles::GAME_CONFIG_HANDLE;
+
+use crate::constants::hero_ship_movement_values::{
+    HERO_SHIP_MOVEMENT_SPEED_DRAG,
+    HERO_SHIP_INCREMENTAL_MOVEMENT_SPEED,
+    HERO_SHIP_MAX_MOVEMENT_SPEED,
+    HERO_SHIP_ROTATION_SPEED_DRAG,
+    HERO_SHIP_INCREMENTAL_ROTATION_SPEED,
+    HERO_SHIP_MAX_ROTATION_SPEED,
+    HERO_SHIP_COLLIDER_BALL_SIZE,
+    STANDARD_HULL,
+    STANDARD_HULL_DESCRIPTION
+};
+
+use crate::constants::image_handles::{
+    HERO_SHIP_HANDLE_IMAGE,
+    HERO_SHIP_FIRE_HANDLE_IMAGE,
+    HERO_SHIP_DESTROYED_HANDLE_IMAGE,
+    HERO_SHIP_THREE_LIVES_HANDLE_IMAGE,
+    HERO_SHIP_TWO_LIVES_HANDLE_IMAGE,
+    HERO_SHIP_ONE_LIVE_HANDLE_IMAGE,
+    SMALL_ASTEROID_HANDLE_IMAGE,
+    MEDIUM_ASTEROID_HANDLE_IMAGE,
+    BIG_ASTEROID_HANDLE_IMAGE
+};
+
+use crate::constants::asteroid::{
+    INITIAL_BIG_ASTEROIDS_ONSCREEN,
+    SMALL_ASTEROID_ARCHETYPE,
+    MEDIUM_ASTEROID_ARCHETYPE,
+    BIG_ASTEROID_ARCHETYPE,
+    MEDIUM_ASTEROIDS_AFTER_BIG_ASTEROID,
+    SMALL_ASTEROIDS_AFTER_MEDIUM_ASTEROID,
+    ASTEROID_HIT_POINTS,
+    ASTEROID_HIT_SCORE,
+    SMALL_ASTEROID_COLLIDER_BALL_SIZE,
+    MEDIUM_ASTEROID_COLLIDER_BALL_SIZE,
+    BIG_ASTEROID_COLLIDER_BALL_SIZE
+};
+
+use crate::constants::asteroid_movement_values::{
+    SMALL_ASTEROID_MOVEMENT_SPEED,
+    SMALL_ASTEROID_ROTATION_SPEED,
+    MEDIUM_ASTEROID_MOVEMENT_SPEED,
+    MEDIUM_ASTEROID_ROTATION_SPEED,
+    BIG_ASTEROID_MOVEMENT_SPEED,
+    BIG_ASTEROID_ROTATION_SPEED
+};
+
+use crate::constants::projectile_movement_values::{
+    PROJECTILE_MOVEMENT_SPEED,
+    PROJECTILE_COLLIDER_BALL_SIZE,
+    PROJECTILE_PIERCE_COUNT,
+    PROJECTILE_RICOCHET_COUNT
+};
+
+use crate::constants::timers::{
+    HERO_SHIP_RESPAWN_SECONDS,
+    HERO_SHIP_STILL_ALIVE_SECONDS,
+    PROJECTILE_SPAWN_SECONDS,
+    ASTEROID_CHECK_FOR_TYPES_SECONDS
+};
+
+use crate::constants::scores::{
+    SMALL_ASTEROID_SCORE,
+    MEDIUM_ASTEROID_SCORE,
+    BIG_ASTEROID_SCORE,
+    STILL_ALIVE_SCORE
+};
+
+use crate::constants::weapons::{
+    CHARGED_SHOT_COOLDOWN_SECONDS,
+    CHARGED_SHOT_MINIMUM_CHARGE_SECONDS,
+    CHARGED_SHOT_MAXIMUM_CHARGE_SECONDS,
+    CHARGED_SHOT_MOVEMENT_SPEED,
+    CHARGED_SHOT_MINIMUM_COLLIDER_BALL_SIZE,
+    CHARGED_SHOT_MAXIMUM_COLLIDER_BALL_SIZE,
+    CHARGED_SHOT_PIERCE_COUNT,
+    LASER_BEAM_LENGTH,
+    LASER_BEAM_WIDTH,
+    LASER_BEAM_DAMAGE_SECONDS,
+    HOMING_MISSILE_COOLDOWN_SECONDS,
+    HOMING_MISSILE_MOVEMENT_SPEED,
+    HOMING_MISSILE_TURN_SPEED,
+    HOMING_MISSILE_COLLIDER_BALL_SIZE
+};
+
+use crate::constants::shop::{
+    UPGRADE_BASE_COST,
+    UPGRADE_COST_INCREASE,
+    UPGRADE_MAXIMUM_LEVEL,
+    MOVEMENT_SPEED_MAXIMUM_FACTOR,
+    ROTATION_SPEED_INCREMENTATION_FACTOR,
+    FIRE_RATE_FACTOR
+};
+
+use crate::constants::energy_shield::{
+    ENERGY_SHIELD_MAXIMUM_ENERGY,
+    ENERGY_SHIELD_DRAIN_PER_SECOND,
+    ENERGY_SHIELD_RECHARGE_PER_SECOND,
+    ENERGY_SHIELD_MINIMUM_RAISE_ENERGY,
+    ENERGY_SHIELD_BOUNCE_DISTANCE
+};
+
+use crate::constants::combo::{
+    COMBO_WINDOW_SECONDS,
+    COMBO_KILLS_PER_MULTIPLIER,
+    COMBO_MAXIMUM_MULTIPLIER,
+    COMBO_MISS_STREAK_RESET
+};
+
+use crate::constants::power_ups::{
+    POWER_UP_DROP_CHANCE,
+    POWER_UP_LIFETIME_SECONDS,
+    POWER_UP_MOVEMENT_SPEED,
+    POWER_UP_COLLIDER_BALL_SIZE,
+    SHIELD_SECONDS,
+    RAPID_FIRE_SECONDS,
+    RAPID_FIRE_SPAWN_SECONDS_FACTOR,
+    SPREAD_SHOT_SECONDS,
+    SPREAD_SHOT_PROJECTILES,
+    SPREAD_SHOT_MAXIMUM_PROJECTILES,
+    SPREAD_SHOT_ANGLE
+};
+
+use crate::constants::versus::HERO_SHIP_DESTROYED_SCORE;
+
+pub struct ConfigPlugin;
+
+impl Plugin for ConfigPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.init_resource::<GameConfig>();
+        app.init_resource::<GameConfigLoadErrors>();
+        app.init_asset::<GameConfig>();
+        app.init_asset_loader::<GameConfigLoader>();
+        app.add_systems(Startup, load_game_config);
+        app.add_systems(Update, (
+            apply_game_config,
+            report_game_config_load_errors
+        ));
+    }
+}
+
+#[derive(Asset, Resource, TypePath, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct GameConfig {
+    pub hero_ship: HeroShipConfig,
+    pub asteroids: AsteroidsConfig,
+    pub projectile: ProjectileConfig,
+    pub weapons: WeaponsConfig,
+    pub power_ups: PowerUpsConfig,
+    pub shop: ShopConfig,
+    pub energy_shield: EnergyShieldConfig,
+    pub combo: ComboConfig,
+    pub scores: ScoresConfig
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct HeroShipConfig {
+    pub respawn_seconds: f32,
+    pub flight_model: FlightModel,
+    pub hulls: Vec<HullConfig>
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct HullConfig {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub movement_speed_drag: f32,
+    pub movement_speed_incrementation: f32,
+    pub movement_speed_maximum: f32,
+    pub rotation_speed_drag: f32,
+    pub rotation_speed_incrementation: f32,
+    pub rotation_speed_maximum: f32,
+    pub collider_ball_size: f32,
+    #[serde(default)]
+    pub sprites: HullSpritesConfig,
+    #[serde(default = "default_hull_weapons")]
+    pub weapons: Vec<Weapon>
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct HullSpritesConfig {
+    pub ship: String,
+    pub fire: String,
+    pub destroyed: String,
+    pub three_lives: String,
+    pub two_lives: String,
+    pub one_live: String
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct AsteroidsConfig {
+    pub initial_archetype: String,
+    pub initial_asteroids_onscreen: usize,
+    pub check_for_types_seconds: f32,
+    pub archetypes: Vec<AsteroidArchetypeConfig>
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AsteroidArchetypeConfig {
+    pub name: String,
+    pub sprite: String,
+    pub collider_ball_size: f32,
+    pub movement_speed: (f32, f32),
+    pub rotation_speed: (f32, f32),
+    #[serde(default = "default_asteroid_hit_points")]
+    pub hit_points: u32,
+    #[serde(default)]
+    pub hit_score: usize,
+    pub score: usize,
+    #[serde(default)]
+    pub split_into: Vec<AsteroidSplitConfig>
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AsteroidSplitConfig {
+    pub archetype: String,
+    pub count: usize
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectileConfig {
+    pub movement_speed: f32,
+    pub collider_ball_size: f32,
+    pub spawn_seconds: f32,
+    pub pierce_count: u32,
+    pub ricochet_count: u32
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct WeaponsConfig {
+    pub charged_shot: ChargedShotConfig,
+    pub laser_beam: LaserBeamConfig,
+    pub homing_missile: HomingMissileConfig
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChargedShotConfig {
+    pub cooldown_seconds: f32,
+    pub minimum_charge_seconds: f32,
+    pub maximum_charge_seconds: f32,
+    pub movement_speed: f32,
+    pub minimum_collider_ball_size: f32,
+    pub maximum_collider_ball_size: f32,
+    pub pierce_count: u32
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct LaserBeamConfig {
+    pub length: f32,
+    pub width: f32,
+    pub damage_seconds: f32
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct HomingMissileConfig {
+    pub cooldown_seconds: f32,
+    pub movement_speed: f32,
+    pub turn_speed: f32,
+    pub collider_ball_size: f32
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShopConfig {
+    pub upgrade_base_cost: usize,
+    pub upgrade_cost_increase: usize,
+    pub upgrade_maximum_level: u32,
+    pub movement_speed_maximum_factor: f32,
+    pub rotation_speed_incrementation_factor: f32,
+    pub fire_rate_factor: f32
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnergyShieldConfig {
+    pub maximum_energy: f32,
+    pub drain_per_second: f32,
+    pub recharge_per_second: f32,
+    pub minimum_raise_energy: f32,
+    pub bounce_distance: f32
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ComboConfig {
+    pub window_seconds: f32,
+    pub kills_per_multiplier: u32,
+    pub maximum_multiplier: u32,
+    pub miss_streak_reset: u32
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct PowerUpsConfig {
+    pub drop_chance: f32,
+    pub lifetime_seconds: f32,
+    pub movement_speed: f32,
+    pub collider_ball_size: f32,
+    pub shield_seconds: f32,
+    pub rapid_fire_seconds: f32,
+    pub rapid_fire_spawn_seconds_factor: f32,
+    pub spread_shot_seconds: f32,
+    pub spread_shot_projectiles: usize,
+    pub spread_shot_maximum_projectiles: usize,
+    pub spread_shot_angle: f32
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScoresConfig {
+    pub still_alive: usize,
+    pub still_alive_seconds: f32,
+    pub hero_ship_destroyed: usize
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        return Self {
+            hero_ship: HeroShipConfig::default(),
+            asteroids: AsteroidsConfig::default(),
+            projectile: ProjectileConfig::default(),
+            weapons: WeaponsConfig::default(),
+            power_ups: PowerUpsConfig::default(),
+            shop: ShopConfig::default(),
+            energy_shield: EnergyShieldConfig::default(),
+            combo: ComboConfig::default(),
+            scores: ScoresConfig::default()
+        };
+    }
+}
+
+impl Default for HeroShipConfig {
+    fn default() -> Self {
+        return Self {
+            respawn_seconds: HERO_SHIP_RESPAWN_SECONDS,
+            flight_model: FlightModel::default(),
+            hulls: vec![HullConfig {
+                name: STANDARD_HULL.to_string(),
+                description: STANDARD_HULL_DESCRIPTION.to_string(),
+                movement_speed_drag: HERO_SHIP_MOVEMENT_SPEED_DRAG,
+                movement_speed_incrementation: HERO_SHIP_INCREMENTAL_MOVEMENT_SPEED,
+                movement_speed_maximum: HERO_SHIP_MAX_MOVEMENT_SPEED,
+                rotation_speed_drag: HERO_SHIP_ROTATION_SPEED_DRAG,
+                rotation_speed_incrementation: HERO_SHIP_INCREMENTAL_ROTATION_SPEED,
+                rotation_speed_maximum: HERO_SHIP_MAX_ROTATION_SPEED,
+                collider_ball_size: HERO_SHIP_COLLIDER_BALL_SIZE,
+                sprites: HullSpritesConfig::default(),
+                weapons: default_hull_weapons()
+            }]
+        };
+    }
+}
+
+impl Default for HullSpritesConfig {
+    fn default() -> Self {
+        return Self {
+            ship: HERO_SHIP_HANDLE_IMAGE.to_string(),
+            fire: HERO_SHIP_FIRE_HANDLE_IMAGE.to_string(),
+            destroyed: HERO_SHIP_DESTROYED_HANDLE_IMAGE.to_string(),
+            three_lives: HERO_SHIP_THREE_LIVES_HANDLE_IMAGE.to_string(),
+            two_lives: HERO_SHIP_TWO_LIVES_HANDLE_IMAGE.to_string(),
+            one_live: HERO_SHIP_ONE_LIVE_HANDLE_IMAGE.to_string()
+        };
+    }
+}
+
+fn default_hull_weapons() -> Vec<Weapon> {
+    return vec![Weapon::Blaster, Weapon::ChargedShot, Weapon::LaserBeam, Weapon::HomingMissile];
+}
+
+impl Default for AsteroidsConfig {
+    fn default() -> Self {
+        return Self {
+            initial_archetype: BIG_ASTEROID_ARCHETYPE.to_string(),
+            initial_asteroids_onscreen: INITIAL_BIG_ASTEROIDS_ONSCREEN,
+            check_for_types_seconds: ASTEROID_CHECK_FOR_TYPES_SECONDS,
+            archetypes: vec![
+                AsteroidArchetypeConfig {
+                    name: SMALL_ASTEROID_ARCHETYPE.to_string(),
+                    sprite: SMALL_ASTEROID_HANDLE_IMAGE.to_string(),
+                    collider_ball_size: SMALL_ASTEROID_COLLIDER_BALL_SIZE,
+                    movement_speed: (SMALL_ASTEROID_MOVEMENT_SPEED, SMALL_ASTEROID_MOVEMENT_SPEED),
+                    rotation_speed: (SMALL_ASTEROID_ROTATION_SPEED, SMALL_ASTEROID_ROTATION_SPEED),
+                    hit_points: ASTEROID_HIT_POINTS,
+                    hit_score: ASTEROID_HIT_SCORE,
+                    score: SMALL_ASTEROID_SCORE,
+                    split_into: Vec::new()
+                },
+                AsteroidArchetypeConfig {
+                    name: MEDIUM_ASTEROID_ARCHETYPE.to_string(),
+                    sprite: MEDIUM_ASTEROID_HANDLE_IMAGE.to_string(),
+                    collider_ball_size: MEDIUM_ASTEROID_COLLIDER_BALL_SIZE,
+                    movement_speed: (MEDIUM_ASTEROID_MOVEMENT_SPEED, MEDIUM_ASTEROID_MOVEMENT_SPEED),
+                    rotation_speed: (MEDIUM_ASTEROID_ROTATION_SPEED, MEDIUM_ASTEROID_ROTATION_SPEED),
+                    hit_points: ASTEROID_HIT_POINTS,
+                    hit_score: ASTEROID_HIT_SCORE,
+                    score: MEDIUM_ASTEROID_SCORE,
+                    split_into: vec![AsteroidSplitConfig {
+                        archetype: SMALL_ASTEROID_ARCHETYPE.to_string(),
+                        count: SMALL_ASTEROIDS_AFTER_MEDIUM_ASTEROID
+                    }]
+                },
+                AsteroidArchetypeConfig {
+                    name: BIG_ASTEROID_ARCHETYPE.to_string(),
+                    sprite: BIG_ASTEROID_HANDLE_IMAGE.to_string(),
+                    collider_ball_size: BIG_ASTEROID_COLLIDER_BALL_SIZE,
+                    movement_speed: (BIG_ASTEROID_MOVEMENT_SPEED, BIG_ASTEROID_MOVEMENT_SPEED),
+                    rotation_speed: (BIG_ASTEROID_ROTATION_SPEED, BIG_ASTEROID_ROTATION_SPEED),
+                    hit_points: ASTEROID_HIT_POINTS,
+                    hit_score: ASTEROID_HIT_SCORE,
+                    score: BIG_ASTEROID_SCORE,
+                    split_into: vec![AsteroidSplitConfig {
+                        archetype: MEDIUM_ASTEROID_ARCHETYPE.to_string(),
+                        count: MEDIUM_ASTEROIDS_AFTER_BIG_ASTEROID
+                    }]
+                }
+            ]
+        };
+    }
+}
+
+impl Default for ProjectileConfig {
+    fn default() -> Self {
+        return Self {
+            movement_speed: PROJECTILE_MOVEMENT_SPEED,
+            collider_ball_size: PROJECTILE_COLLIDER_BALL_SIZE,
+            spawn_seconds: PROJECTILE_SPAWN_SECONDS,
+            pierce_count: PROJECTILE_PIERCE_COUNT,
+            ricochet_count: PROJECTILE_RICOCHET_COUNT
+        };
+    }
+}
+
+impl Default for ChargedShotConfig {
+    fn default() -> Self {
+        return Self {
+            cooldown_seconds: CHARGED_SHOT_COOLDOWN_SECONDS,
+            minimum_charge_seconds: CHARGED_SHOT_MINIMUM_CHARGE_SECONDS,
+            maximum_charge_seconds: CHARGED_SHOT_MAXIMUM_CHARGE_SECONDS,
+            movement_speed: CHARGED_SHOT_MOVEMENT_SPEED,
+            minimum_collider_ball_size: CHARGED_SHOT_MINIMUM_COLLIDER_BALL_SIZE,
+            maximum_collider_ball_size: CHARGED_SHOT_MAXIMUM_COLLIDER_BALL_SIZE,
+            pierce_count: CHARGED_SHOT_PIERCE_COUNT
+        };
+    }
+}
+
+impl Default for LaserBeamConfig {
+    fn default() -> Self {
+        return Self {
+            length: LASER_BEAM_LENGTH,
+            width: LASER_BEAM_WIDTH,
+            damage_seconds: LASER_BEAM_DAMAGE_SECONDS
+        };
+    }
+}
+
+impl Default for HomingMissileConfig {
+    fn default() -> Self {
+        return Self {
+            cooldown_seconds: HOMING_MISSILE_COOLDOWN_SECONDS,
+            movement_speed: HOMING_MISSILE_MOVEMENT_SPEED,
+            turn_speed: HOMING_MISSILE_TURN_SPEED,
+            collider_ball_size: HOMING_MISSILE_COLLIDER_BALL_SIZE
+        };
+    }
+}
+
+impl Default for ShopConfig {
+    fn default() -> Self {
+        return Self {
+            upgrade_base_cost: UPGRADE_BASE_COST,
+            upgrade_cost_increase: UPGRADE_COST_INCREASE,
+            upgrade_maximum_level: UPGRADE_MAXIMUM_LEVEL,
+            movement_speed_maximum_factor: MOVEMENT_SPEED_MAXIMUM_FACTOR,
+            rotation_speed_incrementation_factor: ROTATION_SPEED_INCREMENTATION_FACTOR,
+            fire_rate_factor: FIRE_RATE_FACTOR
+        };
+    }
+}
+
+impl Default for EnergyShieldConfig {
+    fn default() -> Self {
+        return Self {
+            maximum_energy: ENERGY_SHIELD_MAXIMUM_ENERGY,
+            drain_per_second: ENERGY_SHIELD_DRAIN_PER_SECOND,
+            recharge_per_second: ENERGY_SHIELD_RECHARGE_PER_SECOND,
+            minimum_raise_energy: ENERGY_SHIELD_MINIMUM_RAISE_ENERGY,
+            bounce_distance: ENERGY_SHIELD_BOUNCE_DISTANCE
+        };
+    }
+}
+
+impl Default for ComboConfig {
+    fn default() -> Self {
+        return Self {
+            window_seconds: COMBO_WINDOW_SECONDS,
+            kills_per_multiplier: COMBO_KILLS_PER_MULTIPLIER,
+            maximum_multiplier: COMBO_MAXIMUM_MULTIPLIER,
+            miss_streak_reset: COMBO_MISS_STREAK_RESET
+        };
+    }
+}
+
+impl Default for PowerUpsConfig {
+    fn default() -> Self {
+        return Self {
+            drop_chance: POWER_UP_DROP_CHANCE,
+            lifetime_seconds: POWER_UP_LIFETIME_SECONDS,
+            movement_speed: POWER_UP_MOVEMENT_SPEED,
+            collider_ball_size: POWER_UP_COLLIDER_BALL_SIZE,
+            shield_seconds: SHIELD_SECONDS,
+            rapid_fire_seconds: RAPID_FIRE_SECONDS,
+            rapid_fire_spawn_seconds_factor: RAPID_FIRE_SPAWN_SECONDS_FACTOR,
+            spread_shot_seconds: SPREAD_SHOT_SECONDS,
+            spread_shot_projectiles: SPREAD_SHOT_PROJECTILES,
+            spread_shot_maximum_projectiles: SPREAD_SHOT_MAXIMUM_PROJECTILES,
+            spread_shot_angle: SPREAD_SHOT_ANGLE
+        };
+    }
+}
+
+impl Default for ScoresConfig {
+    fn default() -> Self {
+        return Self {
+            still_alive: STILL_ALIVE_SCORE,
+            still_alive_seconds: HERO_SHIP_STILL_ALIVE_SECONDS,
+            hero_ship_destroyed: HERO_SHIP_DESTROYED_SCORE
+        };
+    }
+}
+
+impl GameConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let mut errors: Vec<String> = Vec::new();
+        let mut check_positive = |name: &str, value: f32| {
+            if !value.is_finite() || value <= 0. {
+                errors.push(format!("{} must be a number greater than 0 (got {})", name, value));
+            }
+        };
+
+        check_positive("hero_ship.respawn_seconds", self.hero_ship.respawn_seconds);
+
+        for hull in &self.hero_ship.hulls {
+            let hull_name: &str = &hull.name;
+
+            check_positive(&format!("hero_ship.{}.movement_speed_drag", hull_name), hull.movement_speed_drag);
+            check_positive(&format!("hero_ship.{}.movement_speed_incrementation", hull_name), hull.movement_speed_incrementation);
+            check_positive(&format!("hero_ship.{}.movement_speed_maximum", hull_name), hull.movement_speed_maximum);
+            check_positive(&format!("hero_ship.{}.rotation_speed_drag", hull_name), hull.rotation_speed_drag);
+            check_positive(&format!("hero_ship.{}.rotation_speed_incrementation", hull_name), hull.rotation_speed_incrementation);
+            check_positive(&format!("hero_ship.{}.rotation_speed_maximum", hull_name), hull.rotation_speed_maximum);
+            check_positive(&format!("hero_ship.{}.collider_ball_size", hull_name), hull.collider_ball_size);
+        }
+        check_positive("asteroids.check_for_types_seconds", self.asteroids.check_for_types_seconds);
+
+        for asteroid_archetype in &self.asteroids.archetypes {
+            let archetype_name: &str = &asteroid_archetype.name;
+
+            check_positive(&format!("asteroids.{}.collider_ball_size", archetype_name), asteroid_archetype.collider_ball_size);
+            check_positive(&format!("asteroids.{}.movement_speed", archetype_name), asteroid_archetype.movement_speed.0);
+            check_positive(&format!("asteroids.{}.movement_speed", archetype_name), asteroid_archetype.movement_speed.1);
+            check_positive(&format!("asteroids.{}.rotation_speed", archetype_name), asteroid_archetype.rotation_speed.0);
+            check_positive(&format!("asteroids.{}.rotation_speed", archetype_name), asteroid_archetype.rotation_speed.1);
+        }
+
+        check_positive("projectile.movement_speed", self.projectile.movement_speed);
+        check_positive("projectile.collider_ball_size", self.projectile.collider_ball_size);
+        check_positive("projectile.spawn_seconds", self.projectile.spawn_seconds);
+        check_positive("weapons.charged_shot.cooldown_seconds", self.weapons.charged_shot.cooldown_seconds);
+        check_positive("weapons.charged_shot.minimum_charge_seconds", self.weapons.charged_shot.minimum_charge_seconds);
+        check_positive("weapons.charged_shot.maximum_charge_seconds", self.weapons.charged_shot.maximum_charge_seconds);
+        check_positive("weapons.charged_shot.movement_speed", self.weapons.charged_shot.movement_speed);
+        check_positive("weapons.charged_shot.minimum_collider_ball_size", self.weapons.charged_shot.minimum_collider_ball_size);
+        check_positive("weapons.charged_shot.maximum_collider_ball_size", self.weapons.charged_shot.maximum_collider_ball_size);
+        check_positive("weapons.laser_beam.length", self.weapons.laser_beam.length);
+        check_positive("weapons.laser_beam.width", self.weapons.laser_beam.width);
+        check_positive("weapons.laser_beam.damage_seconds", self.weapons.laser_beam.damage_seconds);
+        check_positive("weapons.homing_missile.cooldown_seconds", self.weapons.homing_missile.cooldown_seconds);
+        check_positive("weapons.homing_missile.movement_speed", self.weapons.homing_missile.movement_speed);
+        check_positive("weapons.homing_missile.turn_speed", self.weapons.homing_missile.turn_speed);
+        check_positive("weapons.homing_missile.collider_ball_size", self.weapons.homing_missile.collider_ball_size);
+        check_positive("power_ups.lifetime_seconds", self.power_ups.lifetime_seconds);
+        check_positive("power_ups.movement_speed", self.power_ups.movement_speed);
+        check_positive("power_ups.collider_ball_size", self.power_ups.collider_ball_size);
+        check_positive("power_ups.shield_seconds", self.power_ups.shield_seconds);
+        check_positive("power_ups.rapid_fire_seconds", self.power_ups.rapid_fire_seconds);
+        check_positive("power_ups.rapid_fire_spawn_seconds_factor", self.power_ups.rapid_fire_spawn_seconds_factor);
+        check_positive("power_ups.spread_shot_seconds", self.power_ups.spread_shot_seconds);
+        check_positive("power_ups.spread_shot_angle", self.power_ups.spread_shot_angle);
+        check_positive("energy_shield.maximum_energy", self.energy_shield.maximum_energy);
+        check_positive("energy_shield.drain_per_second", self.energy_shield.drain_per_second);
+        check_positive("energy_shield.recharge_per_second", self.energy_shield.recharge_per_second);
+        check_positive("energy_shield.minimum_raise_energy", self.energy_shield.minimum_raise_energy);
+        check_positive("energy_shield.bounce_distance", self.energy_shield.bounce_distance);
+        check_positive("combo.window_seconds", self.combo.window_seconds);
+        check_positive("scores.still_alive_seconds", self.scores.still_alive_seconds);
+
+        if self.weapons.charged_shot.minimum_charge_seconds > self.weapons.charged_shot.maximum_charge_seconds {
+            errors.push("weapons.charged_shot.minimum_charge_seconds must not exceed weapons.charged_shot.maximum_charge_seconds".to_string());
+        }
+
+        if self.weapons.charged_shot.minimum_collider_ball_size > self.weapons.charged_shot.maximum_collider_ball_size {
+            errors.push(
+                "weapons.charged_shot.minimum_collider_ball_size must not exceed weapons.charged_shot.maximum_collider_ball_size".to_string()
+            );
+        }
+
+        if !(0. ..=1.).contains(&self.power_ups.drop_chance) {
+            errors.push(format!("power_ups.drop_chance must be between 0 and 1 (got {})", self.power_ups.drop_chance));
+        }
+
+        if self.energy_shield.minimum_raise_energy > self.energy_shield.maximum_energy {
+            errors.push("energy_shield.minimum_raise_energy must not exceed energy_shield.maximum_energy".to_string());
+        }
+
+        if self.power_ups.rapid_fire_spawn_seconds_factor > 1. {
+            errors.push("power_ups.rapid_fire_spawn_seconds_factor must not exceed 1".to_string());
+        }
+
+        if self.power_ups.spread_shot_projectiles < 2 {
+            errors.push("power_ups.spread_shot_projectiles must be at least 2".to_string());
+        }
+
+        if self.power_ups.spread_shot_projectiles > self.power_ups.spread_shot_maximum_projectiles {
+            errors.push("power_ups.spread_shot_projectiles must not exceed power_ups.spread_shot_maximum_projectiles".to_string());
+        }
+
+        if self.combo.kills_per_multiplier == 0 {
+            errors.push("combo.kills_per_multiplier must be at least 1".to_string());
+        }
+
+        if self.combo.maximum_multiplier == 0 {
+            errors.push("combo.maximum_multiplier must be at least 1".to_string());
+        }
+
+        if self.combo.miss_streak_reset == 0 {
+            errors.push("combo.miss_streak_reset must be at least 1".to_string());
+        }
+
+        if self.shop.upgrade_maximum_level == 0 {
+            errors.push("shop.upgrade_maximum_level must be at least 1".to_string());
+        }
+
+        if !self.shop.movement_speed_maximum_factor.is_finite() || self.shop.movement_speed_maximum_factor < 1. {
+            errors.push(format!("shop.movement_speed_maximum_factor must be at least 1 (got {})", self.shop.movement_speed_maximum_factor));
+        }
+
+        if !self.shop.rotation_speed_incrementation_factor.is_finite() || self.shop.rotation_speed_incrementation_factor < 1. {
+            errors.push(format!(
+                "shop.rotation_speed_incrementation_factor must be at least 1 (got {})",
+                self.shop.rotation_speed_incrementation_factor
+            ));
+        }
+
+        if !self.shop.fire_rate_factor.is_finite() || self.shop.fire_rate_factor <= 0. || self.shop.fire_rate_factor > 1. {
+            errors.push(format!("shop.fire_rate_factor must be greater than 0 and at most 1 (got {})", self.shop.fire_rate_factor));
+        }
+
+        if self.asteroids.initial_asteroids_onscreen == 0 {
+            errors.push("asteroids.initial_asteroids_onscreen must be at least 1".to_string());
+        }
+
+        if self.asteroids.get_archetype(&self.asteroids.initial_archetype).is_none() {
+            errors.push(format!("asteroids.initial_archetype \"{}\" is not a defined archetype", self.asteroids.initial_archetype));
+        }
+        errors.extend(self.hero_ship.validate_hulls());
+        errors.extend(self.asteroids.validate_archetypes());
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+        return Err(errors.join("; "));
+    }
+}
+
+impl HeroShipConfig {
+    pub fn get_hull(
+        &self,
+        hull_name: &str
+    ) -> Option<&HullConfig> {
+        return self.hulls.iter().find(|hull| hull.name == hull_name);
+    }
+
+    pub fn get_hull_or_default(
+        &self,
+        hull_name: &str
+    ) -> &HullConfig {
+        return self.get_hull(hull_name).unwrap_or(&self.hulls[0]);
+    }
+
+    fn validate_hulls(&self) -> Vec<String> {
+        let mut errors: Vec<String> = Vec::new();
+
+        if self.hulls.is_empty() {
+            errors.push("hero_ship.hulls must define at least one hull".to_string());
+        }
+
+        for (hull_index, hull) in self.hulls.iter().enumerate() {
+            let hull_name: &str = &hull.name;
+
+            if hull_name.is_empty() {
+                errors.push("hero_ship hull names must not be empty".to_string());
+            }
+
+            if self.hulls[..hull_index].iter().any(|previous_hull| previous_hull.name == hull_name) {
+                errors.push(format!("hero_ship hull \"{}\" is defined more than once", hull_name));
+            }
+
+            if hull.rotation_speed_incrementation > hull.rotation_speed_maximum {
+                errors.push(format!("hero_ship.{0}.rotation_speed_incrementation must not exceed hero_ship.{0}.rotation_speed_maximum", hull_name));
+            }
+
+            let hull_sprites: [(&str, &String); 6] = [
+                ("ship", &hull.sprites.ship),
+                ("fire", &hull.sprites.fire),
+                ("destroyed", &hull.sprites.destroyed),
+                ("three_lives", &hull.sprites.three_lives),
+                ("two_lives", &hull.sprites.two_lives),
+                ("one_live", &hull.sprites.one_live)
+            ];
+
+            for (sprite_name, sprite_path) in hull_sprites {
+                if sprite_path.is_empty() {
+                    errors.push(format!("hero_ship.{}.sprites.{} must not be empty", hull_name, sprite_name));
+                }
+            }
+
+            if hull.weapons.is_empty() {
+                errors.push(format!("hero_ship.{}.weapons must list at least one weapon", hull_name));
+            }
+        }
+        return errors;
+    }
+}
+
+impl AsteroidsConfig {
+    pub fn get_archetype(
+        &self,
+        archetype_name: &str
+    ) -> Option<&AsteroidArchetypeConfig> {
+        return self.archetypes.iter().find(|asteroid_archetype| asteroid_archetype.name == archetype_name);
+    }
+
+    fn validate_archetypes(&self) -> Vec<String> {
+        let mut errors: Vec<String> = Vec::new();
+
+        for (archetype_index, asteroid_archetype) in self.archetypes.iter().enumerate() {
+            let archetype_name: &str = &asteroid_archetype.name;
+
+            if self.archetypes[..archetype_index].iter().any(|previous_archetype| previous_archetype.name == archetype_name) {
+                errors.push(format!("asteroids archetype \"{}\" is defined more than once", archetype_name));
+            }
+
+            if asteroid_archetype.sprite.is_empty() {
+                errors.push(format!("asteroids.{}.sprite must not be empty", archetype_name));
+            }
+
+            if asteroid_archetype.movement_speed.0 > asteroid_archetype.movement_speed.1 {
+                errors.push(format!("asteroids.{}.movement_speed must be a (minimum, maximum) range", archetype_name));
+            }
+
+            if asteroid_archetype.rotation_speed.0 > asteroid_archetype.rotation_speed.1 {
+                errors.push(format!("asteroids.{}.rotation_speed must be a (minimum, maximum) range", archetype_name));
+            }
+
+            if asteroid_archetype.hit_points == 0 {
+                errors.push(format!("asteroids.{}.hit_points must be at least 1", archetype_name));
+            }
+
+            for asteroid_split in &asteroid_archetype.split_into {
+                if self.get_archetype(&asteroid_split.archetype).is_none() {
+                    errors.push(format!(
+                        "asteroids.{}.split_into refers to \"{}\", which is not a defined archetype",
+                        archetype_name,
+                        asteroid_split.archetype
+                    ));
+                }
+            }
+
+            if self.is_archetype_splitting_into_itself(archetype_name, archetype_name, &mut Vec::new()) {
+                errors.push(format!("asteroids.{}.split_into must not lead back to \"{}\"", archetype_name, archetype_name));
+            }
+        }
+        return errors;
+    }
+
+    fn is_archetype_splitting_into_itself<'a>(
+        &'a self,
+        root_archetype_name: &str,
+        archetype_name: &str,
+        visited_archetype_names: &mut Vec<&'a str>
+    ) -> bool {
+        let Some(asteroid_archetype) = self.get_archetype(archetype_name) else {
+            return false;
+        };
+
+        for asteroid_split in &asteroid_archetype.split_into {
+            if asteroid_split.archetype == root_archetype_name {
+                return true;
+            }
+
+            if visited_archetype_names.contains(&asteroid_split.archetype.as_str()) {
+                continue;
+            }
+            visited_archetype_names.push(&asteroid_split.archetype);
+
+            if self.is_archetype_splitting_into_itself(root_archetype_name, &asteroid_split.archetype, visited_archetype_names) {
+                return true;
+            }
+        }
+        return false;
+    }
+}
+
+fn default_asteroid_hit_points() -> u32 {
+    return ASTEROID_HIT_POINTS;
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct GameConfigHandle(pub Handle<GameConfig>);
+
+#[derive(Resource, Default, Clone)]
+pub struct GameConfigLoadErrors(pub Arc<Mutex<Vec<String>>>);
+
+pub struct GameConfigLoader {
+    load_errors: GameConfigLoadErrors
+}
+
+impl FromWorld for GameConfigLoader {
+    fn from_world(world: &mut World) -> Self {
+        return Self { load_errors: world.get_resource_or_insert_with(GameConfigLoadErrors::default).clone() };
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GameConfigLoaderError {
+    #[error("could not read the game config: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse the game config at {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("invalid game config: {0}")]
+    Invalid(String)
+}
+
+impl AssetLoader for GameConfigLoader {
+    type Asset = GameConfig;
+    type Settings = ();
+    type Error = GameConfigLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        _load_context: &'a mut LoadContext
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        return Box::pin(async move {
+            let game_config_result: Result<GameConfig, GameConfigLoaderError> = read_game_config(reader).await;
+
+            if let Err(game_config_error) = &game_config_result {
+                self.load_errors.0.lock().unwrap().push(game_config_error.to_string());
+            }
+            return game_config_result;
+        });
+    }
+
+    fn extensions(&self) -> &[&str] {
+        return &["config.ron"];
+    }
+}
+
+async fn read_game_config(
+    reader: &mut Reader<'_>
+) -> Result<GameConfig, GameConfigLoaderError> {
+    let mut bytes: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut bytes).await?;
+
+    let game_config: GameConfig = ron::de::from_bytes(&bytes)?;
+    game_config.validate().map_err(GameConfigLoaderError::Invalid)?;
+    return Ok(game_config);
+}
+
+pub fn load_game_config(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>
+) {
+    commands.insert_resource(GameConfigHandle(asset_server.load(GAME_CONFIG_HANDLE)));
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn apply_game_config(
+    mut commands: Commands,
+    mut is_game_config_loaded: Local<bool>,
+    mut asset_events: EventReader<AssetEvent<GameConfig>>,
+    mut show_toast_events: EventWriter<ShowToast>,
+    game_config_assets: Res<Assets<GameConfig>>,
+    game_config_handle: Res<GameConfigHandle>,
+    mut game_config: ResMut<GameConfig>,
+    mut hero_ship_still_alive_timer: ResMut<HeroShipStillAliveTimer>,
+    mut asteroid_check_for_types_timer: ResMut<AsteroidCheckForTypesTimer>,
+    mut hero_ship_query: Query<(
+        &mut HeroShip,
+        &mut HeroShipHull,
+        &ShipUpgrades,
+        &mut Collider,
+        &mut HeroShipRespawnTimer,
+        &mut ProjectileSpawnTimer
+    )>,
+    asset_server: Res<AssetServer>,
+    mut asteroid_query: Query<(
+        Entity,
+        &mut Asteroid,
+        &mut Collider,
+        &mut Handle<Image>,
+        Option<&mut Health>
+    ), Without<HeroShip>>
+) {
+    for asset_event in asset_events.read() {
+        let AssetEvent::LoadedWithDependencies { id } = asset_event else {
+            continue;
+        };
+
+        if *id != game_config_handle.id() {
+            continue;
+        }
+
+        let Some(loaded_game_config) = game_config_assets.get(*id) else {
+            continue;
+        };
+        *game_config = loaded_game_config.clone();
+
+        hero_ship_still_alive_timer.set_duration(Duration::from_secs_f32(game_config.scores.still_alive_seconds));
+        asteroid_check_for_types_timer.set_duration(Duration::from_secs_f32(game_config.asteroids.check_for_types_seconds));
+
+        for (
+            mut hero_ship_entity,
+            mut hero_ship_hull,
+            ship_upgrades,
+            mut hero_ship_collider,
+            mut hero_ship_respawn_timer,
+            mut projectile_spawn_timer
+        ) in &mut hero_ship_query {
+            hero_ship_hull.0 = game_config.hero_ship.get_hull_or_default(&hero_ship_hull.name).clone();
+            hero_ship_entity.apply_config(&hero_ship_hull);
+            ship_upgrades.apply_to_hero_ship(&mut hero_ship_entity, &game_config.shop);
+            *hero_ship_collider = Collider::ball(hero_ship_hull.collider_ball_size);
+            hero_ship_respawn_timer.set_duration(Duration::from_secs_f32(game_config.hero_ship.respawn_seconds));
+            projectile_spawn_timer.0.set_duration(Duration::from_secs_f32(game_config.projectile.spawn_seconds));
+        }
+
+        for (
+            asteroid_entity_id,
+            mut asteroid_entity,
+            mut asteroid_collider,
+            mut asteroid_handle_image,
+            asteroid_health
+        ) in &mut asteroid_query {
+            asteroid_entity.apply_config(&asset_server, &game_config.asteroids);
+            *asteroid_collider = Collider::ball(asteroid_entity.collider_ball_size);
+
+            if let Some(handle_image) = &asteroid_entity.handle_image {
+                *asteroid_handle_image = handle_image.clone();
+            }
+
+            let Some(asteroid_archetype) = game_config.asteroids.get_archetype(&asteroid_entity.archetype) else {
+                continue;
+            };
+
+            match asteroid_health {
+                Some(_) if asteroid_archetype.hit_points <= 1 => {
+                    commands.entity(asteroid_entity_id).remove::<Health>();
+                },
+                Some(mut asteroid_health) => {
+                    asteroid_health.maximum_hit_points = asteroid_archetype.hit_points;
+                    asteroid_health.hit_points = asteroid_health.hit_points.min(asteroid_archetype.hit_points);
+                },
+                None if asteroid_archetype.hit_points > 1 => {
+                    commands.entity(asteroid_entity_id).insert(Health::new(asteroid_archetype.hit_points));
+                },
+                None => ()
+            }
+        }
+        info!("Game config loaded from {}.", GAME_CONFIG_HANDLE);
+
+        if *is_game_config_loaded {
+            show_toast_events.send(ShowToast { message: "Game config reloaded!".to_string(), color: Color::GREEN });
+        }
+        *is_game_config_loaded = true;
+    }
+}
+
+pub fn report_game_config_load_errors(
+    game_config_load_errors: Res<GameConfigLoadErrors>,
+    mut show_toast_events: EventWriter<ShowToast>
+) {
+    for game_config_load_error in game_config_load_errors.0.lock().unwrap().drain(..) {
+        show_toast_events.send(ShowToast {
+            message: format!("Keeping the last good game config, {}", game_config_load_error),
+            color: Color::RED
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_game_config_is_valid() {
+        assert_eq!(GameConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn shipped_game_config_parses_and_is_valid() {
+        let game_config: GameConfig = ron::de::from_str(include_str!("../assets/config/game.config.ron")).unwrap();
+
+        assert_eq!(game_config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn non_positive_values_are_reported_by_name() {
+        let mut game_config: GameConfig = GameConfig::default();
+        game_config.projectile.movement_speed = -1.;
+        game_config.scores.still_alive_seconds = f32::NAN;
+
+        let validation_error: String = game_config.validate().unwrap_err();
+
+        assert!(validation_error.contains("projectile.movement_speed must be a number greater than 0"));
+        assert!(validation_error.contains("scores.still_alive_seconds must be a number greater than 0"));
+    }
+
+    #[test]
+    fn inverted_ranges_are_reported() {
+        let mut game_config: GameConfig = GameConfig::default();
+        game_config.weapons.charged_shot.minimum_charge_seconds = game_config.weapons.charged_shot.maximum_charge_seconds + 1.;
+        game_config.energy_shield.minimum_raise_energy = game_config.energy_shield.maximum_energy + 1.;
+        game_config.hero_ship.hulls[0].rotation_speed_incrementation = game_config.hero_ship.hulls[0].rotation_speed_maximum + 1.;
+
+        let validation_error: String = game_config.validate().unwrap_err();
+
+        assert!(validation_error.contains("minimum_charge_seconds must not exceed"));
+        assert!(validation_error.contains("minimum_raise_energy must not exceed"));
+        assert!(validation_error.contains("rotation_speed_incrementation must not exceed"));
+    }
+
+    #[test]
+    fn unknown_initial_archetype_is_reported() {
+        let mut game_config: GameConfig = GameConfig::default();
+        game_config.asteroids.initial_archetype = "gigantic".to_string();
+
+        assert!(game_config.validate().unwrap_err().contains("\"gigantic\" is not a defined archetype"));
+    }
+
+    #[test]
+    fn out_of_range_drop_chance_is_reported() {
+        let mut game_config: GameConfig = GameConfig::default();
+        game_config.power_ups.drop_chance = 1.5;
+
+        assert!(game_config.validate().unwrap_err().contains("power_ups.drop_chance must be between 0 and 1"));
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        assert!(ron::de::from_str::<GameConfig>("(projectile: (movement_sped: 400.))").is_err());
+    }
+}
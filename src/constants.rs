@@ -5,10 +5,18 @@ pub mod image_handles {
     pub const HERO_SHIP_THREE_LIVES_HANDLE_IMAGE: &str = "textures/sprites/ships/asteroids_hero_ship_three_lives_96x96.png";
     pub const HERO_SHIP_TWO_LIVES_HANDLE_IMAGE: &str = "textures/sprites/ships/asteroids_hero_ship_two_lives_96x96.png";
     pub const HERO_SHIP_ONE_LIVE_HANDLE_IMAGE: &str = "textures/sprites/ships/asteroids_hero_ship_one_live_96x96.png";
+    pub const ENERGY_SHIELD_HANDLE_IMAGE: &str = "textures/sprites/ships/asteroids_energy_shield_40x40.png";
     pub const SMALL_ASTEROID_HANDLE_IMAGE: &str = "textures/sprites/asteroids/asteroids_small_asteroid_24x24.png";
     pub const MEDIUM_ASTEROID_HANDLE_IMAGE: &str = "textures/sprites/asteroids/asteroids_medium_asteroid_48x48.png";
     pub const BIG_ASTEROID_HANDLE_IMAGE: &str = "textures/sprites/asteroids/asteroids_big_asteroid_150x150.png";
     pub const PROJECTILE_HANDLE_IMAGE: &str = "textures/sprites/projectiles/ship_projectile_4x4.png";
+    pub const CHARGED_SHOT_HANDLE_IMAGE: &str = "textures/sprites/projectiles/ship_charged_shot_16x16.png";
+    pub const HOMING_MISSILE_HANDLE_IMAGE: &str = "textures/sprites/projectiles/ship_homing_missile_6x12.png";
+    pub const LASER_BEAM_HANDLE_IMAGE: &str = "textures/sprites/projectiles/ship_laser_beam_4x4.png";
+}
+
+pub mod config_handles {
+    pub const GAME_CONFIG_HANDLE: &str = "config/game.config.ron";
 }
 
 pub mod audio_source_handles {
@@ -17,6 +25,10 @@ pub mod audio_source_handles {
     pub const HERO_SHIP_DESTROYED_SOUND_HANDLE_AUDIO_SOURCE: &str = "sounds/hero_ship_destroyed_sound.ogg";
     pub const PROJECTILE_SPAWN_SOUND_HANDLE_AUDIO_SOURCE: &str = "sounds/projectile_spawn_sound.ogg";
     pub const ASTEROID_DESTROYED_SOUND_HANDLE_AUDIO_SOURCE: &str = "sounds/asteroid_destroyed_sound.ogg";
+    pub const ASTEROID_HIT_SOUND_HANDLE_AUDIO_SOURCE: &str = "sounds/asteroid_hit_sound.ogg";
+    pub const CHARGED_SHOT_SOUND_HANDLE_AUDIO_SOURCE: &str = "sounds/charged_shot_sound.ogg";
+    pub const LASER_BEAM_SOUND_HANDLE_AUDIO_SOURCE: &str = "sounds/laser_beam_sound.ogg";
+    pub const HOMING_MISSILE_SOUND_HANDLE_AUDIO_SOURCE: &str = "sounds/homing_missile_sound.ogg";
 }
 
 pub mod borders {
@@ -32,20 +44,41 @@ pub mod borders {
 
 pub mod hero_ship_movement_values {
     pub const HERO_SHIP_MOVEMENT_SPEED_DRAG: f32 = 100.;
-    pub const HERO_SHIP_INCREMENTAL_MOVEMENT_SPEED: f32 = 3.;
+    pub const HERO_SHIP_INCREMENTAL_MOVEMENT_SPEED: f32 = 192.;
     pub const HERO_SHIP_MAX_MOVEMENT_SPEED: f32 = 320.;
     pub const HERO_SHIP_ROTATION_SPEED_DRAG: f32 = 250.;
     pub const HERO_SHIP_INCREMENTAL_ROTATION_SPEED: f32 = 15.;
     pub const HERO_SHIP_MAX_ROTATION_SPEED: f32 = 360.;
+    pub const HERO_SHIP_COLLIDER_BALL_SIZE: f32 = 5.;
+    pub const ARCADE_COASTING_DRAG_FACTOR: f32 = 0.15;
+    pub const ASSISTED_COASTING_DRAG_FACTOR: f32 = 3.;
+    pub const STANDARD_HULL: &str = "Standard";
+    pub const STANDARD_HULL_DESCRIPTION: &str = "Balanced handling, carries every weapon.";
 }
 
 pub mod asteroid {
     pub const INITIAL_BIG_ASTEROIDS_ONSCREEN: usize = 4;
+    pub const SMALL_ASTEROID_ARCHETYPE: &str = "small";
+    pub const MEDIUM_ASTEROID_ARCHETYPE: &str = "medium";
+    pub const BIG_ASTEROID_ARCHETYPE: &str = "big";
+    pub const MEDIUM_ASTEROIDS_AFTER_BIG_ASTEROID: usize = 3;
+    pub const SMALL_ASTEROIDS_AFTER_MEDIUM_ASTEROID: usize = 2;
+    pub const ASTEROID_HIT_POINTS: u32 = 1;
+    pub const ASTEROID_HIT_SCORE: usize = 0;
     pub const SMALL_ASTEROID_COLLIDER_BALL_SIZE: f32 = 5.5;
     pub const MEDIUM_ASTEROID_COLLIDER_BALL_SIZE: f32 = 30.;
     pub const BIG_ASTEROID_COLLIDER_BALL_SIZE: f32 = 70.;
 }
 
+pub mod asteroid_hit_values {
+    use bevy::render::color::Color;
+
+    pub const ASTEROID_HIT_FLASH_SECONDS: f32 = 0.12;
+    pub const ASTEROID_HIT_FLASH_COLOR: Color = Color::rgb(1., 0.45, 0.3);
+    pub const ASTEROID_HIT_PUSHBACK_DISTANCE: f32 = 8.;
+    pub const ASTEROID_HIT_SOUND_VOLUME: f32 = 0.6;
+}
+
 pub mod asteroid_movement_values {
     pub const SMALL_ASTEROID_MOVEMENT_SPEED: f32 = 175.;
     pub const SMALL_ASTEROID_ROTATION_SPEED: f32 = 150.;
@@ -56,10 +89,171 @@ pub mod asteroid_movement_values {
 }
 
 pub mod projectile_movement_values {
-    pub const PROJECTILE_MOVEMENT_SPEED: f32 = 200.;    
+    pub const PROJECTILE_MOVEMENT_SPEED: f32 = 200.;
+    pub const PROJECTILE_COLLIDER_BALL_SIZE: f32 = 3.5;
+    pub const PROJECTILE_PIERCE_COUNT: u32 = 0;
+    pub const PROJECTILE_RICOCHET_COUNT: u32 = 0;
+}
+
+pub mod weapons {
+    pub const CHARGED_SHOT_COOLDOWN_SECONDS: f32 = 0.6;
+    pub const CHARGED_SHOT_MINIMUM_CHARGE_SECONDS: f32 = 0.15;
+    pub const CHARGED_SHOT_MAXIMUM_CHARGE_SECONDS: f32 = 1.2;
+    pub const CHARGED_SHOT_MOVEMENT_SPEED: f32 = 260.;
+    pub const CHARGED_SHOT_MINIMUM_COLLIDER_BALL_SIZE: f32 = 4.;
+    pub const CHARGED_SHOT_MAXIMUM_COLLIDER_BALL_SIZE: f32 = 12.;
+    pub const CHARGED_SHOT_PIERCE_COUNT: u32 = 3;
+    pub const CHARGED_SHOT_SOUND_VOLUME: f32 = 0.8;
+    pub const LASER_BEAM_LENGTH: f32 = 400.;
+    pub const LASER_BEAM_WIDTH: f32 = 3.;
+    pub const LASER_BEAM_DAMAGE_SECONDS: f32 = 0.15;
+    pub const LASER_BEAM_SOUND_VOLUME: f32 = 0.3;
+    pub const HOMING_MISSILE_COOLDOWN_SECONDS: f32 = 0.5;
+    pub const HOMING_MISSILE_MOVEMENT_SPEED: f32 = 170.;
+    pub const HOMING_MISSILE_TURN_SPEED: f32 = 180.;
+    pub const HOMING_MISSILE_COLLIDER_BALL_SIZE: f32 = 4.;
+    pub const HOMING_MISSILE_SOUND_VOLUME: f32 = 0.5;
+}
+
+pub mod timers {
+    pub const HERO_SHIP_RESPAWN_SECONDS: f32 = 2.;
+    pub const HERO_SHIP_STILL_ALIVE_SECONDS: f32 = 10.;
+    pub const PROJECTILE_SPAWN_SECONDS: f32 = 0.2;
+    pub const ASTEROID_CHECK_FOR_TYPES_SECONDS: f32 = 4.5;
+}
+
+pub mod scores {
+    pub const SMALL_ASTEROID_SCORE: usize = 50;
+    pub const MEDIUM_ASTEROID_SCORE: usize = 30;
+    pub const BIG_ASTEROID_SCORE: usize = 10;
+    pub const STILL_ALIVE_SCORE: usize = 5;
+}
+
+pub mod power_ups {
+    pub const POWER_UP_DROP_CHANCE: f32 = 0.1;
+    pub const POWER_UP_LIFETIME_SECONDS: f32 = 8.;
+    pub const POWER_UP_MOVEMENT_SPEED: f32 = 40.;
+    pub const POWER_UP_ROTATION_SPEED: f32 = 90.;
+    pub const POWER_UP_COLLIDER_BALL_SIZE: f32 = 8.;
+    pub const POWER_UP_SPRITE_SIZE: f32 = 14.;
+    pub const POWER_UP_LABEL_FONT_SIZE: f32 = 12.;
+    pub const SHIELD_SECONDS: f32 = 10.;
+    pub const RAPID_FIRE_SECONDS: f32 = 8.;
+    pub const RAPID_FIRE_SPAWN_SECONDS_FACTOR: f32 = 0.5;
+    pub const SPREAD_SHOT_SECONDS: f32 = 8.;
+    pub const SPREAD_SHOT_PROJECTILES: usize = 3;
+    pub const SPREAD_SHOT_MAXIMUM_PROJECTILES: usize = 5;
+    pub const SPREAD_SHOT_ANGLE: f32 = 30.;
+    pub const EXTRA_LIFE_MAXIMUM_LIVES: usize = 3;
+}
+
+pub mod energy_shield {
+    pub const ENERGY_SHIELD_MAXIMUM_ENERGY: f32 = 100.;
+    pub const ENERGY_SHIELD_DRAIN_PER_SECOND: f32 = 45.;
+    pub const ENERGY_SHIELD_RECHARGE_PER_SECOND: f32 = 15.;
+    pub const ENERGY_SHIELD_MINIMUM_RAISE_ENERGY: f32 = 25.;
+    pub const ENERGY_SHIELD_BOUNCE_DISTANCE: f32 = 12.;
+    pub const ENERGY_SHIELD_BAR_WIDTH: f32 = 120.;
+    pub const ENERGY_SHIELD_BAR_HEIGHT: f32 = 8.;
+}
+
+pub mod statistics {
+    pub const PERSONAL_BEST_FILE_PATH: &str = "save/personal_best.ron";
+    pub const LIFETIME_STATISTICS_FILE_PATH: &str = "save/lifetime_statistics.ron";
+    pub const CAREER_MENU_FONT_SIZE: f32 = 20.;
+    pub const END_GAME_MENU_FONT_SIZE: f32 = 22.;
+    pub const END_GAME_MENU_ANIMATION_SECONDS: f32 = 0.6;
+    pub const END_GAME_MENU_HIDDEN_TOP_VAL_PX: f32 = -360.;
+    pub const END_GAME_MENU_TOP_VAL_PX: f32 = 40.;
+    pub const END_GAME_MENU_BACKGROUND_ALPHA: f32 = 0.8;
+}
+
+pub mod combo {
+    pub const COMBO_WINDOW_SECONDS: f32 = 2.;
+    pub const COMBO_KILLS_PER_MULTIPLIER: u32 = 3;
+    pub const COMBO_MAXIMUM_MULTIPLIER: u32 = 5;
+    pub const COMBO_MISS_STREAK_RESET: u32 = 3;
+}
+
+pub mod popups {
+    pub const POPUP_SECONDS: f32 = 0.8;
+    pub const POPUP_RISE_SPEED: f32 = 40.;
+    pub const POPUP_FONT_SIZE: f32 = 16.;
+    pub const POPUP_Z_POSITION: f32 = 2.;
+}
+
+pub mod achievements {
+    pub const SMALL_ASTEROID_STREAK_COUNT: usize = 10;
+    pub const SMALL_ASTEROID_STREAK_SECONDS: f32 = 5.;
+}
+
+pub mod menu {
+    pub const MENU_BUTTON_FONT_SIZE: f32 = 26.;
+    pub const MENU_BUTTON_WIDTH: f32 = 240.;
+    pub const MENU_BUTTON_HEIGHT: f32 = 36.;
+    pub const MENU_TRANSITION_SECONDS: f32 = 0.3;
+    pub const MENU_TITLE_FONT_SIZE: f32 = 50.;
+    pub const SUBMENU_TITLE_FONT_SIZE: f32 = 34.;
+    pub const MENU_TEXT_FONT_SIZE: f32 = 20.;
+}
+
+pub mod ship_select {
+    pub const SHIP_SELECT_MENU_FONT_SIZE: f32 = 24.;
+}
+
+pub mod shop {
+    pub const UPGRADE_BASE_COST: usize = 150;
+    pub const UPGRADE_COST_INCREASE: usize = 100;
+    pub const UPGRADE_MAXIMUM_LEVEL: u32 = 3;
+    pub const MOVEMENT_SPEED_MAXIMUM_FACTOR: f32 = 1.15;
+    pub const ROTATION_SPEED_INCREMENTATION_FACTOR: f32 = 1.25;
+    pub const FIRE_RATE_FACTOR: f32 = 0.85;
+    pub const SHOP_MENU_FONT_SIZE: f32 = 24.;
+}
+
+pub mod versus {
+    pub const HERO_SHIP_DESTROYED_SCORE: usize = 100;
+    pub const ROUND_WINNING_SCORE: usize = 750;
+    pub const ROUNDS_TO_WIN_MATCH: usize = 3;
+}
+
+pub mod network {
+    pub const INPUT_DELAY_FRAMES: u32 = 3;
+    pub const INPUT_REDUNDANCY_FRAMES: u32 = 8;
+    pub const CHECKSUM_INTERVAL_FRAMES: u32 = 64;
+    pub const DISCONNECT_TIMEOUT_SECONDS: f32 = 5.;
+    pub const RESEND_INTERVAL_SECONDS: f32 = 0.25;
+}
+
+pub mod autopilot {
+    pub const ATTRACT_MODE_IDLE_SECONDS: f32 = 15.;
+    pub const SOAK_RESTART_DELAY_SECONDS: f32 = 1.;
+    pub const SOAK_REPORT_INTERVAL_SECONDS: f32 = 60.;
+    pub const THREAT_LOOKAHEAD_SECONDS: f32 = 1.5;
+    pub const THREAT_EVASION_SECONDS: f32 = 0.75;
+    pub const THREAT_SAFETY_MARGIN: f32 = 25.;
+    pub const TARGET_MAX_INTERCEPT_SECONDS: f32 = 2.;
+    pub const CRUISE_MOVEMENT_SPEED: f32 = 60.;
+    pub const MINIMUM_AIM_TOLERANCE_RADIANS: f32 = 0.03;
+    pub const EVASION_THRUST_TOLERANCE_RADIANS: f32 = 0.6;
+}
+
+pub mod env {
+    pub const DEFAULT_FRAME_SKIP: u32 = 4;
+    pub const ACTION_SPACE_SIZE: u8 = 32;
+    pub const OBSERVED_ASTEROIDS: usize = 8;
+    pub const HERO_SHIP_OBSERVATION_SIZE: usize = 10;
+    pub const ASTEROID_OBSERVATION_SIZE: usize = 5;
+    pub const OBSERVATION_VELOCITY_SCALE: f32 = 200.;
+    pub const LIFE_LOST_REWARD: f32 = -100.;
+    pub const HEADLESS_LOG_FILTER: &str = "wgpu=error,naga=warn,bevy_asset::server=off";
 }
 
 pub mod ui_values {
     pub const TOP_TEXT_VAL_PX: f32 = 260. / 2. + 80.;
-    pub const RIGHT_TEXT_VAL_PX: f32 = 400. / 2. + 120.;
+    pub const TOAST_SECONDS: f32 = 4.;
+}
+
+pub mod save {
+    pub const SAVED_RUN_FILE_PATH: &str = "save/saved_run.scn.ron";
 }
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+
+use bevy::audio::{
+    PlaybackMode::Despawn,
+    Volume
+};
+
+use crate::GameplaySet;
+use crate::asteroid::{Asteroid, AsteroidHitSound};
+use crate::config::{GameConfig, EnergyShieldConfig};
+use crate::settings::{GameSettings, DownKeyAction};
+
+use crate::hero_ship::{
+    HeroShip,
+    HeroShipInput,
+    PlayerId
+};
+
+use crate::constants::image_handles::ENERGY_SHIELD_HANDLE_IMAGE;
+use crate::constants::asteroid_hit_values::ASTEROID_HIT_SOUND_VOLUME;
+
+pub struct EnergyShieldPlugin;
+
+impl Plugin for EnergyShieldPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.add_systems(FixedUpdate, (
+            update_energy_shield,
+            draw_energy_shield_bubble
+        ).chain().in_set(GameplaySet));
+    }
+}
+
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct EnergyShield {
+    pub energy: f32,
+    pub is_raised: bool
+}
+
+impl EnergyShield {
+    pub fn from_config(energy_shield_config: &EnergyShieldConfig) -> Self {
+        return Self {
+            energy: energy_shield_config.maximum_energy,
+            is_raised: false
+        };
+    }
+
+    pub fn get_energy_ratio(
+        &self,
+        energy_shield_config: &EnergyShieldConfig
+    ) -> f32 {
+        return (self.energy / energy_shield_config.maximum_energy).clamp(0., 1.);
+    }
+
+    fn can_be_raised(
+        &self,
+        energy_shield_config: &EnergyShieldConfig
+    ) -> bool {
+        if self.is_raised {
+            return self.energy > 0.;
+        }
+        return self.energy >= energy_shield_config.minimum_raise_energy;
+    }
+}
+
+#[derive(Component)]
+pub struct EnergyShieldBubble;
+
+pub fn spawn_energy_shield_bubble(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    player_id: PlayerId
+) {
+    parent.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: player_id.color(),
+                ..default()
+            },
+            texture: asset_server.load(ENERGY_SHIELD_HANDLE_IMAGE),
+            transform: Transform::from_xyz(0., 0., 1.),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        EnergyShieldBubble
+    ));
+}
+
+pub fn update_energy_shield(
+    time: Res<Time>,
+    game_config: Res<GameConfig>,
+    game_settings: Res<GameSettings>,
+    mut hero_ship_query: Query<(&HeroShipInput, &Visibility, &mut EnergyShield), With<HeroShip>>
+) {
+    let energy_shield_config: &EnergyShieldConfig = &game_config.energy_shield;
+
+    for (hero_ship_input, hero_ship_visibility, mut energy_shield) in &mut hero_ship_query {
+        let is_raising_shield: bool = game_settings.down_key_action == DownKeyAction::EnergyShield &&
+            hero_ship_input.brake &&
+            *hero_ship_visibility == Visibility::Visible;
+
+        if is_raising_shield && energy_shield.can_be_raised(energy_shield_config) {
+            energy_shield.energy = (energy_shield.energy - energy_shield_config.drain_per_second * time.delta_seconds()).max(0.);
+            energy_shield.is_raised = energy_shield.energy > 0.;
+        } else {
+            energy_shield.energy = (energy_shield.energy + energy_shield_config.recharge_per_second * time.delta_seconds())
+                .min(energy_shield_config.maximum_energy);
+            energy_shield.is_raised = false;
+        }
+    }
+}
+
+pub fn draw_energy_shield_bubble(
+    hero_ship_query: Query<(&EnergyShield, &Children), With<HeroShip>>,
+    mut energy_shield_bubble_query: Query<&mut Visibility, With<EnergyShieldBubble>>
+) {
+    for (energy_shield, hero_ship_children) in &hero_ship_query {
+        for hero_ship_child in hero_ship_children.iter() {
+            if let Ok(mut energy_shield_bubble_visibility) = energy_shield_bubble_query.get_mut(*hero_ship_child) {
+                *energy_shield_bubble_visibility = if energy_shield.is_raised { Visibility::Inherited } else { Visibility::Hidden };
+            }
+        }
+    }
+}
+
+pub fn bounce_asteroid_off_energy_shield(
+    mut commands: Commands,
+    asteroid_hit_sound: &Res<AsteroidHitSound>,
+    energy_shield_config: &EnergyShieldConfig,
+    hero_ship_translation: Vec3,
+    asteroid: &mut Asteroid,
+    asteroid_transform: &mut Transform
+) {
+    let bounce_direction: Vec3 = (asteroid_transform.translation - hero_ship_translation)
+        .truncate()
+        .try_normalize()
+        .unwrap_or(-asteroid.movement_direction.truncate())
+        .extend(0.);
+
+    asteroid.movement_direction = bounce_direction * asteroid.movement_direction.length();
+    asteroid_transform.translation += bounce_direction * energy_shield_config.bounce_distance;
+
+    commands.spawn(AudioBundle {
+        source: asteroid_hit_sound.0.clone(),
+        settings: PlaybackSettings {
+            mode: Despawn,
+            volume: Volume::new(ASTEROID_HIT_SOUND_VOLUME),
+            ..default()
+        }
+    });
+}
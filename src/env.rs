@@ -0,0 +1,394 @@
+use bevy::{
+    prelude::*,
+    ecs::{
+        schedule::ExecutorKind,
+        system::RunSystemOnce
+    },
+    input::InputPlugin,
+    log::LogPlugin,
+    time::TimeUpdateStrategy,
+    window::WindowFocused
+};
+
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng
+};
+
+use std::time::{
+    Duration,
+    Instant
+};
+
+use thiserror::Error;
+
+use crate::GameState;
+use crate::GameMode;
+use crate::GameRng;
+use crate::ship_select::SelectedHulls;
+use crate::config::GameConfig;
+use crate::GamePlugin;
+use crate::PausingState;
+use crate::InputSet;
+use crate::ui::ScoreboardScore;
+use crate::asteroid::Asteroid;
+
+pub use crate::hero_ship::HeroShipInput;
+
+use crate::hero_ship::{
+    self,
+    HeroShip,
+    HeroShipRemainingLives,
+    PlayerId
+};
+
+use crate::constants::borders::{
+    RIGHT_BORDER_POSITION,
+    TOP_BORDER_POSITION
+};
+
+use crate::constants::asteroid::BIG_ASTEROID_COLLIDER_BALL_SIZE;
+
+use crate::constants::env::{
+    DEFAULT_FRAME_SKIP,
+    ACTION_SPACE_SIZE,
+    OBSERVED_ASTEROIDS,
+    HERO_SHIP_OBSERVATION_SIZE,
+    ASTEROID_OBSERVATION_SIZE,
+    OBSERVATION_VELOCITY_SCALE,
+    LIFE_LOST_REWARD,
+    HEADLESS_LOG_FILTER
+};
+
+pub const OBSERVATION_SIZE: usize = HERO_SHIP_OBSERVATION_SIZE + OBSERVED_ASTEROIDS * ASTEROID_OBSERVATION_SIZE;
+
+#[derive(Error, Debug)]
+pub enum EnvironmentRolloutError {
+    #[error("invalid rollout steps \"{0}\"")]
+    InvalidRolloutSteps(String),
+    #[error("invalid frame skip \"{0}\"")]
+    InvalidFrameSkip(String)
+}
+
+pub struct AsteroidsEnv {
+    app: App,
+    frame_skip: u32,
+    last_score: usize,
+    last_lives: usize
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct EnvironmentAction(pub HeroShipInput);
+
+impl AsteroidsEnv {
+    pub fn new(frame_skip: u32) -> Self {
+        let mut app: App = build_headless_app();
+
+        app.add_systems(FixedUpdate, (
+            apply_environment_action
+        ).in_set(InputSet).after(hero_ship::read_hero_ship_keyboard_input));
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        return Self {
+            app,
+            frame_skip: frame_skip.max(1),
+            last_score: 0,
+            last_lives: 0
+        };
+    }
+
+    pub fn reset(
+        &mut self,
+        seed: u64
+    ) -> Vec<f32> {
+        self.app.world.run_system_once_with(seed, reset_environment_world);
+        self.last_score = self.app.world.resource::<ScoreboardScore>().get(PlayerId::One);
+        self.last_lives = self.app.world.resource::<HeroShipRemainingLives>().get(PlayerId::One);
+        return self.observe();
+    }
+
+    pub fn step(
+        &mut self,
+        action: HeroShipInput
+    ) -> (Vec<f32>, f32, bool) {
+        self.app.world.insert_resource(EnvironmentAction(action));
+
+        for _ in 0..self.frame_skip {
+            self.app.update();
+
+            if self.is_done() {
+                break;
+            }
+        }
+
+        let score: usize = self.app.world.resource::<ScoreboardScore>().get(PlayerId::One);
+        let lives: usize = self.app.world.resource::<HeroShipRemainingLives>().get(PlayerId::One);
+        let reward: f32 = score.saturating_sub(self.last_score) as f32 +
+            self.last_lives.saturating_sub(lives) as f32 * LIFE_LOST_REWARD;
+
+        self.last_score = score;
+        self.last_lives = lives;
+        return (self.observe(), reward, self.is_done());
+    }
+
+    pub fn is_done(&self) -> bool {
+        return self.app.world.resource::<HeroShipRemainingLives>().are_all_lives_lost();
+    }
+
+    pub fn observe(&mut self) -> Vec<f32> {
+        let mut observation: Vec<f32> = Vec::with_capacity(OBSERVATION_SIZE);
+        let lives: usize = self.app.world.resource::<HeroShipRemainingLives>().get(PlayerId::One);
+        let mut hero_ship_query = self.app.world.query::<(&HeroShip, &PlayerId, &Transform, &Visibility)>();
+        let mut hero_ship_position: Vec2 = Vec2::ZERO;
+
+        if let Some((hero_ship_entity, _, hero_ship_transform, hero_ship_visibility)) = hero_ship_query
+            .iter(&self.app.world)
+            .find(|(_, player_id, _, _)| **player_id == PlayerId::One)
+        {
+            let hero_ship_facing: Vec3 = hero_ship_transform.rotation * Vec3::Y;
+            hero_ship_position = hero_ship_transform.translation.truncate();
+
+            observation.extend([
+                hero_ship_position.x / RIGHT_BORDER_POSITION,
+                hero_ship_position.y / TOP_BORDER_POSITION,
+                hero_ship_facing.x,
+                hero_ship_facing.y,
+                hero_ship_entity.get_movement_speed() / hero_ship_entity.movement_speed_maximum,
+                hero_ship_entity.velocity.x / OBSERVATION_VELOCITY_SCALE,
+                hero_ship_entity.velocity.y / OBSERVATION_VELOCITY_SCALE,
+                hero_ship_entity.rotation_speed / f32::to_radians(hero_ship_entity.rotation_speed_maximum),
+                (*hero_ship_visibility != Visibility::Hidden) as u8 as f32,
+                lives as f32 / 3.
+            ]);
+        } else {
+            observation.extend([0.; HERO_SHIP_OBSERVATION_SIZE]);
+        }
+
+        let mut asteroid_query = self.app.world.query::<(&Asteroid, &Transform)>();
+        let mut asteroid_observations: Vec<(f32, [f32; ASTEROID_OBSERVATION_SIZE])> = asteroid_query
+            .iter(&self.app.world)
+            .map(|(asteroid, asteroid_transform)| {
+                let relative_position: Vec2 = asteroid_transform.translation.truncate() - hero_ship_position;
+                let velocity: Vec3 = asteroid.movement_direction * asteroid.movement_speed.unwrap_or(0.);
+
+                return (relative_position.length_squared(), [
+                    relative_position.x / RIGHT_BORDER_POSITION,
+                    relative_position.y / TOP_BORDER_POSITION,
+                    velocity.x / OBSERVATION_VELOCITY_SCALE,
+                    velocity.y / OBSERVATION_VELOCITY_SCALE,
+                    asteroid.collider_ball_size / BIG_ASTEROID_COLLIDER_BALL_SIZE
+                ]);
+            })
+            .collect();
+        asteroid_observations.sort_by(|first, second| first.0.total_cmp(&second.0));
+
+        for (_, asteroid_observation) in asteroid_observations.into_iter().take(OBSERVED_ASTEROIDS) {
+            observation.extend(asteroid_observation);
+        }
+        observation.resize(OBSERVATION_SIZE, 0.);
+        return observation;
+    }
+}
+
+pub fn build_headless_app() -> App {
+    let mut app: App = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        LogPlugin { filter: HEADLESS_LOG_FILTER.to_string(), ..default() },
+        AssetPlugin { watch_for_changes_override: Some(false), ..default() },
+        HierarchyPlugin,
+        TransformPlugin,
+        InputPlugin
+    ));
+    app.init_asset::<Image>();
+    app.init_asset::<AudioSource>();
+    app.init_asset::<TextureAtlasLayout>();
+    app.add_event::<WindowFocused>();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1. / 64.)));
+    app.add_plugins(GamePlugin);
+    app.init_resource::<EnvironmentAction>();
+    app.add_systems(Startup, setup_environment);
+    app.add_systems(Last, despawn_environment_sounds);
+
+    for (_, schedule) in app.world.resource_mut::<Schedules>().iter_mut() {
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    }
+    return app;
+}
+
+fn setup_environment(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>
+) {
+    crate::set_game_sounds(commands.reborrow(), &asset_server);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn reset_environment_world(
+    In(seed): In<u64>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_config: Res<GameConfig>,
+    game_mode: Res<GameMode>,
+    mut game_rng: ResMut<GameRng>,
+    selected_hulls: Res<SelectedHulls>,
+    mut next_state_game_state: ResMut<NextState<GameState>>,
+    mut next_state_pausing_state: ResMut<NextState<PausingState>>
+) {
+    game_rng.0 = StdRng::seed_from_u64(seed);
+    crate::reset_run(&mut commands);
+    hero_ship::spawn_hero_ships_lives(commands.reborrow(), &asset_server, *game_mode);
+    crate::setup_main_entities(commands, asset_server, game_config, game_mode, game_rng, selected_hulls);
+    next_state_game_state.set(GameState::InGame);
+    next_state_pausing_state.set(PausingState::Running);
+}
+
+fn apply_environment_action(
+    environment_action: Res<EnvironmentAction>,
+    mut hero_ship_query: Query<(&PlayerId, &mut HeroShipInput)>
+) {
+    for (player_id, mut hero_ship_input) in &mut hero_ship_query {
+        if *player_id == PlayerId::One {
+            *hero_ship_input = environment_action.0;
+        }
+    }
+}
+
+fn despawn_environment_sounds(
+    mut commands: Commands,
+    audio_query: Query<Entity, With<Handle<AudioSource>>>
+) {
+    for audio_entity in &audio_query {
+        commands.entity(audio_entity).despawn_recursive();
+    }
+}
+
+fn get_environment_rollout_arguments(args: &[String]) -> Result<Option<(usize, u32)>, EnvironmentRolloutError> {
+    let argument_value = |name: &str| -> Option<String> {
+        return args.iter().position(|arg| arg == name).and_then(|index| args.get(index + 1).cloned());
+    };
+
+    let Some(rollout_steps) = argument_value("--env-rollout") else {
+        return Ok(None);
+    };
+    let rollout_steps: usize = rollout_steps.parse().map_err(|_| EnvironmentRolloutError::InvalidRolloutSteps(rollout_steps))?;
+    let frame_skip: u32 = match argument_value("--frame-skip") {
+        Some(frame_skip) => frame_skip.parse().ok()
+            .filter(|parsed_frame_skip| *parsed_frame_skip > 0)
+            .ok_or(EnvironmentRolloutError::InvalidFrameSkip(frame_skip))?,
+        None => DEFAULT_FRAME_SKIP
+    };
+    return Ok(Some((rollout_steps, frame_skip)));
+}
+
+pub fn run_environment_rollout_from_args(args: Vec<String>) -> Result<bool, EnvironmentRolloutError> {
+    let Some((rollout_steps, frame_skip)) = get_environment_rollout_arguments(&args)? else {
+        return Ok(false);
+    };
+
+    let mut action_rng: StdRng = StdRng::seed_from_u64(0);
+    let mut asteroids_env: AsteroidsEnv = AsteroidsEnv::new(frame_skip);
+    let mut finished_episodes: usize = 0;
+    let mut episode_return: f32 = 0.;
+    let mut total_return: f32 = 0.;
+
+    asteroids_env.reset(0);
+    let rollout_started_at: Instant = Instant::now();
+
+    for _ in 0..rollout_steps {
+        let action: HeroShipInput = HeroShipInput::from_bits(action_rng.gen_range(0..ACTION_SPACE_SIZE));
+        let (_, reward, done) = asteroids_env.step(action);
+        episode_return += reward;
+
+        if done {
+            finished_episodes += 1;
+            total_return += episode_return;
+            episode_return = 0.;
+            asteroids_env.reset(finished_episodes as u64);
+        }
+    }
+
+    let rollout_seconds: f32 = rollout_started_at.elapsed().as_secs_f32();
+    info!(
+        "Environment rollout: {} steps in {:.2}s ({:.0} steps/sec), {} episodes finished, average return {:.1}.",
+        rollout_steps,
+        rollout_seconds,
+        rollout_steps as f32 / rollout_seconds,
+        finished_episodes,
+        if finished_episodes > 0 { total_return / finished_episodes as f32 } else { 0. }
+    );
+    return Ok(true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play_rollout(
+        seed: u64,
+        rollout_steps: usize
+    ) -> Vec<(Vec<f32>, f32, bool)> {
+        let mut action_rng: StdRng = StdRng::seed_from_u64(seed);
+        let mut asteroids_env: AsteroidsEnv = AsteroidsEnv::new(DEFAULT_FRAME_SKIP);
+        let mut transitions: Vec<(Vec<f32>, f32, bool)> = vec![(asteroids_env.reset(seed), 0., false)];
+
+        for _ in 0..rollout_steps {
+            let action: HeroShipInput = HeroShipInput::from_bits(action_rng.gen_range(0..ACTION_SPACE_SIZE));
+            transitions.push(asteroids_env.step(action));
+        }
+        return transitions;
+    }
+
+    #[test]
+    fn rollout_arguments_are_parsed_and_malformed_ones_rejected() {
+        let to_args = |args: &[&str]| -> Vec<String> {
+            return args.iter().map(|arg| arg.to_string()).collect();
+        };
+
+        assert!(matches!(get_environment_rollout_arguments(&[]), Ok(None)));
+        assert!(matches!(
+            get_environment_rollout_arguments(&to_args(&["--env-rollout", "100"])),
+            Ok(Some((100, DEFAULT_FRAME_SKIP)))
+        ));
+        assert!(matches!(
+            get_environment_rollout_arguments(&to_args(&["--env-rollout", "100", "--frame-skip", "2"])),
+            Ok(Some((100, 2)))
+        ));
+        assert!(matches!(
+            get_environment_rollout_arguments(&to_args(&["--env-rollout", "many"])),
+            Err(EnvironmentRolloutError::InvalidRolloutSteps(_))
+        ));
+        assert!(matches!(
+            get_environment_rollout_arguments(&to_args(&["--env-rollout", "100", "--frame-skip", "0"])),
+            Err(EnvironmentRolloutError::InvalidFrameSkip(_))
+        ));
+    }
+
+    #[test]
+    fn reset_with_the_same_seed_and_actions_is_deterministic() {
+        let first_rollout: Vec<(Vec<f32>, f32, bool)> = play_rollout(7, 150);
+        let second_rollout: Vec<(Vec<f32>, f32, bool)> = play_rollout(7, 150);
+
+        assert_eq!(first_rollout, second_rollout);
+    }
+
+    #[test]
+    fn observation_includes_the_hero_ship_velocity() {
+        let mut asteroids_env: AsteroidsEnv = AsteroidsEnv::new(DEFAULT_FRAME_SKIP);
+        let first_observation: Vec<f32> = asteroids_env.reset(3);
+        let accelerate: HeroShipInput = HeroShipInput { accelerate: true, ..default() };
+        let mut observation: Vec<f32> = first_observation.clone();
+
+        for _ in 0..10 {
+            observation = asteroids_env.step(accelerate).0;
+        }
+
+        assert_eq!(first_observation.len(), OBSERVATION_SIZE);
+        assert_eq!(&first_observation[5..7], &[0., 0.]);
+        assert!(Vec2::new(observation[5], observation[6]).length() > 0.);
+    }
+}
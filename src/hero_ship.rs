@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
-use std::sync::Mutex;
+use serde::Deserialize;
+use std::collections::HashMap;
 
 use bevy::audio::{
     PlaybackMode::Despawn,
@@ -9,25 +10,33 @@ use bevy::audio::{
 
 use crate::PausingState;
 use crate::GameState;
+use crate::GameMode;
+use crate::RunScoped;
+use crate::ResetRun;
+use crate::InputSet;
+use crate::GameplaySet;
 use crate::projectile::{self, Projectile};
+use crate::power_up::ActivePowerUps;
+use crate::shop::ShipUpgrades;
+use crate::weapon::{self, Weapon, WeaponSounds, WeaponState};
+use crate::energy_shield::{self, EnergyShield};
+use crate::settings::{GameSettings, DownKeyAction};
 use crate::ui::ScoreboardScore;
+use crate::statistics::RunStatistics;
+use crate::combo::ScoreCombo;
+use crate::ship_select::SelectedHulls;
+use crate::config::{GameConfig, HullConfig};
 
-use crate::constants::image_handles::{
-    HERO_SHIP_HANDLE_IMAGE,
-    HERO_SHIP_FIRE_HANDLE_IMAGE,
-    HERO_SHIP_DESTROYED_HANDLE_IMAGE,
-    HERO_SHIP_THREE_LIVES_HANDLE_IMAGE,
-    HERO_SHIP_TWO_LIVES_HANDLE_IMAGE,
-    HERO_SHIP_ONE_LIVE_HANDLE_IMAGE
-};
+use crate::constants::image_handles::HERO_SHIP_THREE_LIVES_HANDLE_IMAGE;
 
 use crate::constants::hero_ship_movement_values::{
-    HERO_SHIP_MOVEMENT_SPEED_DRAG,
-    HERO_SHIP_INCREMENTAL_MOVEMENT_SPEED,
-    HERO_SHIP_MAX_MOVEMENT_SPEED,
-    HERO_SHIP_ROTATION_SPEED_DRAG,
-    HERO_SHIP_INCREMENTAL_ROTATION_SPEED,
-    HERO_SHIP_MAX_ROTATION_SPEED
+    ARCADE_COASTING_DRAG_FACTOR,
+    ASSISTED_COASTING_DRAG_FACTOR
+};
+
+use crate::constants::timers::{
+    HERO_SHIP_RESPAWN_SECONDS,
+    HERO_SHIP_STILL_ALIVE_SECONDS
 };
 
 use crate::constants::borders::{
@@ -44,9 +53,10 @@ impl Plugin for HeroShipPlugin {
         &self,
         app: &mut App
     ) {
+        app.add_event::<HeroShipLifeLost>();
         app.init_resource::<HeroShipRemainingLives>();
         app.init_resource::<HeroShipStillAliveTimer>();
-        app.init_resource::<HeroShipRespawnTimer>();
+        app.add_systems(ResetRun, reset_hero_ship_run_state);
         app.add_systems(Update, check_for_hero_ship_lives);
         app.add_systems(Update, (
             animate_hero_ship_destroyed_spritesheet
@@ -54,6 +64,9 @@ impl Plugin for HeroShipPlugin {
         app.add_systems(Update, (
             animate_hero_ship_destroyed_spritesheet
         ).run_if(in_state(PausingState::Running).and_then(in_state(GameState::InGame))));
+        app.add_systems(FixedUpdate, (
+            read_hero_ship_keyboard_input
+        ).in_set(InputSet));
         app.add_systems(FixedUpdate, (
             dynamic_hero_ship_still_alive_check,
             respawn_hero_ship_on_demand,
@@ -61,33 +74,273 @@ impl Plugin for HeroShipPlugin {
             draw_hero_ship_fire,
             set_hero_ship_position_after_border_outbounds,
             hero_ship_fire_projectile
-        ).run_if(in_state(PausingState::Running).and_then(in_state(GameState::InGame))));
+        ).in_set(GameplaySet));
     }
 }
 
-#[derive(Component)]
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FlightModel {
+    #[default]
+    Direct,
+    Arcade,
+    Assisted
+}
+
+impl FlightModel {
+    fn get_coasting_drag_factor(&self) -> f32 {
+        match self {
+            FlightModel::Direct => return 1.,
+            FlightModel::Arcade => return ARCADE_COASTING_DRAG_FACTOR,
+            FlightModel::Assisted => return ASSISTED_COASTING_DRAG_FACTOR
+        }
+    }
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
 pub struct HeroShip {
-    pub movement_speed: f32,
+    pub velocity: Vec2,
     pub movement_speed_drag: f32,
     pub movement_speed_incrementation: f32,
     pub movement_speed_maximum: f32,
     pub rotation_speed: f32,
+    pub rotation_direction: f32,
     pub rotation_speed_drag: f32,
     pub rotation_speed_incrementation: f32,
     pub rotation_speed_maximum: f32
 }
 
-impl Default for HeroShip {
-    fn default() -> Self {
+impl HeroShip {
+    pub fn from_config(hull_config: &HullConfig) -> Self {
         return Self {
-            movement_speed: 0.,
-            movement_speed_drag: HERO_SHIP_MOVEMENT_SPEED_DRAG,
-            movement_speed_incrementation: HERO_SHIP_INCREMENTAL_MOVEMENT_SPEED,
-            movement_speed_maximum: HERO_SHIP_MAX_MOVEMENT_SPEED,
+            velocity: Vec2::ZERO,
+            movement_speed_drag: hull_config.movement_speed_drag,
+            movement_speed_incrementation: hull_config.movement_speed_incrementation,
+            movement_speed_maximum: hull_config.movement_speed_maximum,
             rotation_speed: 0.,
-            rotation_speed_drag: HERO_SHIP_ROTATION_SPEED_DRAG,
-            rotation_speed_incrementation: HERO_SHIP_INCREMENTAL_ROTATION_SPEED,
-            rotation_speed_maximum: HERO_SHIP_MAX_ROTATION_SPEED
+            rotation_direction: 0.,
+            rotation_speed_drag: hull_config.rotation_speed_drag,
+            rotation_speed_incrementation: hull_config.rotation_speed_incrementation,
+            rotation_speed_maximum: hull_config.rotation_speed_maximum
+        };
+    }
+
+    pub fn apply_config(
+        &mut self,
+        hull_config: &HullConfig
+    ) {
+        self.movement_speed_drag = hull_config.movement_speed_drag;
+        self.movement_speed_incrementation = hull_config.movement_speed_incrementation;
+        self.movement_speed_maximum = hull_config.movement_speed_maximum;
+        self.rotation_speed_drag = hull_config.rotation_speed_drag;
+        self.rotation_speed_incrementation = hull_config.rotation_speed_incrementation;
+        self.rotation_speed_maximum = hull_config.rotation_speed_maximum;
+        self.velocity = self.velocity.clamp_length_max(self.movement_speed_maximum);
+    }
+
+    pub fn get_movement_speed(&self) -> f32 {
+        return self.velocity.length();
+    }
+
+    pub fn get_next_velocity(
+        &self,
+        flight_model: FlightModel,
+        facing: Vec2,
+        is_accelerating: bool,
+        is_braking: bool,
+        delta_seconds: f32
+    ) -> Vec2 {
+        let drag_speed_loss: f32 = self.movement_speed_drag * delta_seconds;
+        let thrust_speed_gain: f32 = self.movement_speed_incrementation * delta_seconds;
+
+        if flight_model == FlightModel::Direct {
+            let mut movement_speed: f32 = self.get_movement_speed();
+
+            if is_accelerating {
+                movement_speed = (movement_speed + thrust_speed_gain).min(self.movement_speed_maximum);
+            } else {
+                movement_speed -= drag_speed_loss;
+            }
+
+            if is_braking {
+                movement_speed -= drag_speed_loss;
+            }
+            return facing * movement_speed.max(0.);
+        }
+
+        let mut velocity: Vec2 = self.velocity;
+
+        if is_accelerating {
+            velocity = (velocity + facing * thrust_speed_gain).clamp_length_max(self.movement_speed_maximum);
+        } else {
+            velocity = reduce_speed(velocity, drag_speed_loss * flight_model.get_coasting_drag_factor());
+        }
+
+        if is_braking {
+            velocity = reduce_speed(velocity, drag_speed_loss);
+        }
+        return velocity;
+    }
+}
+
+fn reduce_speed(
+    velocity: Vec2,
+    speed_loss: f32
+) -> Vec2 {
+    return velocity.clamp_length_max((velocity.length() - speed_loss).max(0.));
+}
+
+#[derive(Component, Deref, DerefMut, Clone)]
+pub struct HeroShipHull(pub HullConfig);
+
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub enum PlayerId {
+    #[default]
+    One,
+    Two
+}
+
+impl PlayerId {
+    pub fn color(&self) -> Color {
+        match self {
+            PlayerId::One => return Color::WHITE,
+            PlayerId::Two => return Color::CYAN
+        }
+    }
+
+    pub fn spawn_position(
+        &self,
+        game_mode: GameMode
+    ) -> Vec3 {
+        match (game_mode, self) {
+            (GameMode::SinglePlayer, _) => return Vec3::new(0., 0., 0.),
+            (_, PlayerId::One) => return Vec3::new(-40., 0., 0.),
+            (_, PlayerId::Two) => return Vec3::new(40., 0., 0.)
+        }
+    }
+
+    pub fn opponent(&self) -> Self {
+        match self {
+            PlayerId::One => return PlayerId::Two,
+            PlayerId::Two => return PlayerId::One
+        }
+    }
+
+    pub fn projectile_collision_group(&self) -> Group {
+        match self {
+            PlayerId::One => return Group::GROUP_11,
+            PlayerId::Two => return Group::GROUP_12
+        }
+    }
+
+    pub fn hero_ship_collision_groups(
+        &self,
+        game_mode: GameMode
+    ) -> CollisionGroups {
+        match game_mode {
+            GameMode::Versus => return CollisionGroups::new(
+                Group::GROUP_10,
+                Group::GROUP_1 | Group::GROUP_2 | self.opponent().projectile_collision_group()
+            ),
+            _ => return CollisionGroups::new(Group::GROUP_10, Group::GROUP_1 | Group::GROUP_2)
+        }
+    }
+
+    fn lives_sprite_position(&self) -> Vec3 {
+        match self {
+            PlayerId::One => return Vec3::new(330., 235., 0.),
+            PlayerId::Two => return Vec3::new(-320., 230., 0.)
+        }
+    }
+}
+
+#[derive(Component, Clone)]
+pub struct HeroShipControls {
+    pub accelerate: Vec<KeyCode>,
+    pub brake: Vec<KeyCode>,
+    pub rotate_left: Vec<KeyCode>,
+    pub rotate_right: Vec<KeyCode>,
+    pub shoot: Vec<KeyCode>,
+    pub cycle_weapon: Vec<KeyCode>
+}
+
+impl HeroShipControls {
+    pub fn initialize(
+        player_id: PlayerId,
+        game_mode: GameMode
+    ) -> Self {
+        match (game_mode, player_id) {
+            (GameMode::SinglePlayer, _) => return Self {
+                accelerate: vec![KeyCode::ArrowUp, KeyCode::KeyW],
+                brake: vec![KeyCode::ArrowDown, KeyCode::KeyS],
+                rotate_left: vec![KeyCode::ArrowLeft, KeyCode::KeyA],
+                rotate_right: vec![KeyCode::ArrowRight, KeyCode::KeyD],
+                shoot: vec![KeyCode::Space],
+                cycle_weapon: vec![KeyCode::KeyQ]
+            },
+            (_, PlayerId::One) => return Self {
+                accelerate: vec![KeyCode::KeyW],
+                brake: vec![KeyCode::KeyS],
+                rotate_left: vec![KeyCode::KeyA],
+                rotate_right: vec![KeyCode::KeyD],
+                shoot: vec![KeyCode::Space],
+                cycle_weapon: vec![KeyCode::KeyQ]
+            },
+            (_, PlayerId::Two) => return Self {
+                accelerate: vec![KeyCode::ArrowUp],
+                brake: vec![KeyCode::ArrowDown],
+                rotate_left: vec![KeyCode::ArrowLeft],
+                rotate_right: vec![KeyCode::ArrowRight],
+                shoot: vec![KeyCode::ShiftRight],
+                cycle_weapon: vec![KeyCode::ControlRight]
+            }
+        }
+    }
+}
+
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeroShipInput {
+    pub accelerate: bool,
+    pub brake: bool,
+    pub rotate_left: bool,
+    pub rotate_right: bool,
+    pub shoot: bool,
+    pub cycle_weapon: bool
+}
+
+impl HeroShipInput {
+    pub fn from_keyboard(
+        keyboard_input: &ButtonInput<KeyCode>,
+        hero_ship_controls: &HeroShipControls
+    ) -> Self {
+        return Self {
+            accelerate: keyboard_input.any_pressed(hero_ship_controls.accelerate.iter().copied()),
+            brake: keyboard_input.any_pressed(hero_ship_controls.brake.iter().copied()),
+            rotate_left: keyboard_input.any_pressed(hero_ship_controls.rotate_left.iter().copied()),
+            rotate_right: keyboard_input.any_pressed(hero_ship_controls.rotate_right.iter().copied()),
+            shoot: keyboard_input.any_pressed(hero_ship_controls.shoot.iter().copied()),
+            cycle_weapon: keyboard_input.any_pressed(hero_ship_controls.cycle_weapon.iter().copied())
+        };
+    }
+
+    pub fn to_bits(self) -> u8 {
+        return self.accelerate as u8 |
+            (self.brake as u8) << 1 |
+            (self.rotate_left as u8) << 2 |
+            (self.rotate_right as u8) << 3 |
+            (self.shoot as u8) << 4 |
+            (self.cycle_weapon as u8) << 5;
+    }
+
+    pub fn from_bits(bits: u8) -> Self {
+        return Self {
+            accelerate: bits & 1 != 0,
+            brake: bits & (1 << 1) != 0,
+            rotate_left: bits & (1 << 2) != 0,
+            rotate_right: bits & (1 << 3) != 0,
+            shoot: bits & (1 << 4) != 0,
+            cycle_weapon: bits & (1 << 5) != 0
         };
     }
 }
@@ -120,32 +373,60 @@ impl HeroShipLivesSprite {
     }
 }
 
-#[derive(Resource)]
+#[derive(Event, Clone, Copy, Debug)]
+pub struct HeroShipLifeLost {
+    pub player_id: PlayerId
+}
+
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
 pub struct HeroShipRemainingLives {
-    pub lives_remaining: usize
+    pub lives_remaining: HashMap<PlayerId, usize>
 }
 
 impl Default for HeroShipRemainingLives {
     fn default() -> Self {
-        return Self { lives_remaining: 3 };
+        return Self { lives_remaining: HashMap::from([(PlayerId::One, 3)]) };
+    }
+}
+
+impl HeroShipRemainingLives {
+    pub fn reset(
+        &mut self,
+        game_mode: GameMode
+    ) {
+        self.lives_remaining = game_mode.players().into_iter().map(|player_id| (player_id, 3)).collect();
+    }
+
+    pub fn get(
+        &self,
+        player_id: PlayerId
+    ) -> usize {
+        return self.lives_remaining.get(&player_id).copied().unwrap_or(0);
+    }
+
+    pub fn are_all_lives_lost(&self) -> bool {
+        return self.lives_remaining.values().all(|lives_remaining| *lives_remaining == 0);
     }
 }
 
-#[derive(Resource, Deref, DerefMut)]
+#[derive(Component, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
 pub struct HeroShipRespawnTimer(pub Timer);
 
 impl Default for HeroShipRespawnTimer {
     fn default() -> Self {
-        return Self(Timer::from_seconds(2., TimerMode::Repeating));
+        return Self(Timer::from_seconds(HERO_SHIP_RESPAWN_SECONDS, TimerMode::Repeating));
     }
 }
 
-#[derive(Resource, Deref, DerefMut)]
+#[derive(Resource, Reflect, Deref, DerefMut)]
+#[reflect(Resource)]
 pub struct HeroShipStillAliveTimer(pub Timer);
 
 impl Default for HeroShipStillAliveTimer {
     fn default() -> Self {
-        return Self(Timer::from_seconds(10., TimerMode::Repeating));
+        return Self(Timer::from_seconds(HERO_SHIP_STILL_ALIVE_SECONDS, TimerMode::Repeating));
     }
 }
 
@@ -155,46 +436,115 @@ pub struct HeroShipLaunchingSound(pub Handle<AudioSource>);
 #[derive(Resource, Default, Deref, DerefMut)]
 pub struct HeroShipDestroyedSound(pub Handle<AudioSource>);
 
-lazy_static! { static ref HERO_SHIP_ROTATION_FACTOR: Mutex<f32> = Mutex::new(0.); }
+pub fn reset_hero_ship_run_state(
+    game_mode: Res<GameMode>,
+    mut hero_ship_remaining_lives: ResMut<HeroShipRemainingLives>,
+    mut hero_ship_still_alive_timer: ResMut<HeroShipStillAliveTimer>
+) {
+    hero_ship_remaining_lives.reset(*game_mode);
+    hero_ship_still_alive_timer.reset();
+}
 
-pub fn spawn_hero_ship(
+pub fn spawn_hero_ships(
     mut commands: Commands,
-    asset_server: &Res<AssetServer>
+    asset_server: &Res<AssetServer>,
+    game_config: &GameConfig,
+    game_mode: GameMode,
+    selected_hulls: &SelectedHulls
 ) {
-    let hero_ship_handle: Handle<Image> = asset_server.load(HERO_SHIP_HANDLE_IMAGE);
+    for player_id in game_mode.players() {
+        let hull_config: &HullConfig = selected_hulls.get_hull(player_id, &game_config.hero_ship);
 
-    commands.spawn((
+        spawn_hero_ship(commands.reborrow(), asset_server, game_config, game_mode, player_id, hull_config);
+    }
+}
+
+pub fn spawn_hero_ship(
+    mut commands: Commands,
+    asset_server: &Res<AssetServer>,
+    game_config: &GameConfig,
+    game_mode: GameMode,
+    player_id: PlayerId,
+    hull_config: &HullConfig
+) -> Entity {
+    let hero_ship_handle: Handle<Image> = asset_server.load(hull_config.sprites.ship.clone());
+
+    return commands.spawn((
         SpriteBundle {
+            sprite: Sprite {
+                color: player_id.color(),
+                ..default()
+            },
             texture: hero_ship_handle,
+            transform: Transform {
+                translation: player_id.spawn_position(game_mode),
+                ..default()
+            },
             visibility: Visibility::Visible,
             ..default()
         },
-        HeroShip::default(),
+        HeroShip::from_config(hull_config),
+        HeroShipHull(hull_config.clone()),
+        player_id,
+        HeroShipControls::initialize(player_id, game_mode),
+        HeroShipInput::default(),
+        ActivePowerUps::default(),
+        hull_config.weapons[0],
+        WeaponState::default(),
+        ShipUpgrades::default(),
+        EnergyShield::from_config(&game_config.energy_shield),
+        ScoreCombo::default(),
+        HeroShipRespawnTimer(Timer::from_seconds(game_config.hero_ship.respawn_seconds, TimerMode::Repeating)),
+        projectile::ProjectileSpawnTimer(Timer::from_seconds(game_config.projectile.spawn_seconds, TimerMode::Repeating))
     ))
     .insert(Name::new("Hero Ship"))
+    .insert(RunScoped)
     .insert(RigidBody::Dynamic)
-    .insert(Collider::ball(5.))
+    .insert(Collider::ball(hull_config.collider_ball_size))
     .insert(GravityScale(0.))
-    .insert(CollisionGroups::new(Group::GROUP_10, Group::GROUP_1));
+    .insert(ActiveEvents::COLLISION_EVENTS)
+    .insert(player_id.hero_ship_collision_groups(game_mode))
+    .with_children(|parent| {
+        weapon::spawn_laser_beam(parent, asset_server, player_id);
+        energy_shield::spawn_energy_shield_bubble(parent, asset_server, player_id);
+    })
+    .id();
+}
+
+pub fn spawn_hero_ships_lives(
+    mut commands: Commands,
+    asset_server: &Res<AssetServer>,
+    game_mode: GameMode
+) {
+    for player_id in game_mode.players() {
+        spawn_hero_ship_lives(commands.reborrow(), asset_server, player_id);
+    }
 }
 
 pub fn spawn_hero_ship_lives(
     mut commands: Commands,
-    asset_server: &Res<AssetServer>
+    asset_server: &Res<AssetServer>,
+    player_id: PlayerId
 ) {
     let hero_ship_lives_sprite: HeroShipLivesSprite =
-        HeroShipLivesSprite::initialize(&asset_server);
+        HeroShipLivesSprite::initialize(asset_server);
 
     commands.spawn((
         SpriteBundle {
+            sprite: Sprite {
+                color: player_id.color(),
+                ..default()
+            },
             texture: hero_ship_lives_sprite.handle_image.clone_weak(),
             transform: Transform {
-                translation: Vec3::new(330., 235., 0.),
+                translation: player_id.lives_sprite_position(),
                 ..default()
             },
             ..default()
         },
-        hero_ship_lives_sprite
+        hero_ship_lives_sprite,
+        player_id,
+        RunScoped
     ));
 }
 
@@ -202,13 +552,18 @@ pub fn check_for_hero_ship_lives(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     hero_ship_remaining_lives: Res<HeroShipRemainingLives>,
-    mut hero_ship_lives_sprite_query: Query<(Entity, &HeroShipLivesSprite, &mut Handle<Image>)>
+    hero_ship_query: Query<(&PlayerId, &HeroShipHull)>,
+    mut hero_ship_lives_sprite_query: Query<(Entity, &PlayerId, &mut Handle<Image>), With<HeroShipLivesSprite>>
 ) {
-    for (hero_ship_lives_sprite_entity, _, mut hero_ship_lives_sprite_handle_image) in &mut hero_ship_lives_sprite_query {
-        match hero_ship_remaining_lives.lives_remaining {
-            3 => { *hero_ship_lives_sprite_handle_image = asset_server.load(HERO_SHIP_THREE_LIVES_HANDLE_IMAGE); },
-            2 => { *hero_ship_lives_sprite_handle_image = asset_server.load(HERO_SHIP_TWO_LIVES_HANDLE_IMAGE); },
-            1 => { *hero_ship_lives_sprite_handle_image = asset_server.load(HERO_SHIP_ONE_LIVE_HANDLE_IMAGE); },
+    for (hero_ship_lives_sprite_entity, player_id, mut hero_ship_lives_sprite_handle_image) in &mut hero_ship_lives_sprite_query {
+        let Some((_, hero_ship_hull)) = hero_ship_query.iter().find(|(hero_ship_player_id, _)| *hero_ship_player_id == player_id) else {
+            continue;
+        };
+
+        match hero_ship_remaining_lives.get(*player_id) {
+            3 => { *hero_ship_lives_sprite_handle_image = asset_server.load(hero_ship_hull.sprites.three_lives.clone()); },
+            2 => { *hero_ship_lives_sprite_handle_image = asset_server.load(hero_ship_hull.sprites.two_lives.clone()); },
+            1 => { *hero_ship_lives_sprite_handle_image = asset_server.load(hero_ship_hull.sprites.one_live.clone()); },
             0 => { commands.entity(hero_ship_lives_sprite_entity).despawn_recursive(); },
             _ => {}
         }
@@ -217,18 +572,31 @@ pub fn check_for_hero_ship_lives(
 
 pub fn respawn_hero_ship_on_demand(
     time: Res<Time>,
-    mut hero_ship_respawn_timer: ResMut<HeroShipRespawnTimer>,
-    mut hero_ship_query: Query<(&HeroShip, &mut Transform, &mut Visibility, &mut CollisionGroups)>,
+    game_mode: Res<GameMode>,
+    hero_ship_remaining_lives: Res<HeroShipRemainingLives>,
+    mut hero_ship_query: Query<(
+        &PlayerId,
+        &mut HeroShipRespawnTimer,
+        &mut Transform,
+        &mut Visibility,
+        &mut CollisionGroups
+    ), With<HeroShip>>
 ) {
-    let (_, mut hero_ship_transform, mut hero_ship_visibility, mut hero_ship_collision_groups) = hero_ship_query.single_mut();
-
-    if *hero_ship_visibility == Visibility::Hidden {
-        hero_ship_respawn_timer.0.tick(time.delta());
-
-        if hero_ship_respawn_timer.just_finished() {
-            hero_ship_transform.translation = Vec3::new(0., 0., 0.);
-            *hero_ship_visibility = Visibility::Visible;
-            *hero_ship_collision_groups = CollisionGroups::new(Group::GROUP_10, Group::GROUP_1);
+    for (
+        player_id,
+        mut hero_ship_respawn_timer,
+        mut hero_ship_transform,
+        mut hero_ship_visibility,
+        mut hero_ship_collision_groups
+    ) in &mut hero_ship_query {
+        if *hero_ship_visibility == Visibility::Hidden && hero_ship_remaining_lives.get(*player_id) > 0 {
+            hero_ship_respawn_timer.0.tick(time.delta());
+
+            if hero_ship_respawn_timer.just_finished() {
+                hero_ship_transform.translation = player_id.spawn_position(*game_mode);
+                *hero_ship_visibility = Visibility::Visible;
+                *hero_ship_collision_groups = player_id.hero_ship_collision_groups(*game_mode);
+            }
         }
     }
 }
@@ -237,9 +605,10 @@ pub fn spawn_hero_ship_destroyed_spritesheet(
     mut commands: Commands,
     asset_server: &Res<AssetServer>,
     texture_atlas_layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
+    hero_ship_destroyed_handle_image: &str,
     hero_ship_last_position: Vec3
 ) {
-    let texture: Handle<Image> = asset_server.load(HERO_SHIP_DESTROYED_HANDLE_IMAGE);
+    let texture: Handle<Image> = asset_server.load(hero_ship_destroyed_handle_image.to_string());
     let texture_atlas_layout: TextureAtlasLayout = TextureAtlasLayout::from_grid(
         Vec2::new(24., 24.),
         7,
@@ -265,7 +634,8 @@ pub fn spawn_hero_ship_destroyed_spritesheet(
             ..default()
         },
         hero_ship_animation_indices,
-        HeroShipAnimationTimer::default()
+        HeroShipAnimationTimer::default(),
+        RunScoped
     ));
 }
 
@@ -286,72 +656,65 @@ pub fn animate_hero_ship_destroyed_spritesheet(
     }
 }
 
+pub fn read_hero_ship_keyboard_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut hero_ship_query: Query<(&HeroShipControls, &mut HeroShipInput)>
+) {
+    for (hero_ship_controls, mut hero_ship_input) in &mut hero_ship_query {
+        *hero_ship_input = HeroShipInput::from_keyboard(&keyboard_input, hero_ship_controls);
+    }
+}
+
 pub fn set_hero_ship_movement_and_rotation(
     mut commands: Commands,
     time: Res<Time>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    game_settings: Res<GameSettings>,
     hero_ship_launching_sound: Res<HeroShipLaunchingSound>,
-    mut hero_ship_query: Query<(&mut HeroShip, &mut Transform)>
+    game_config: Res<GameConfig>,
+    mut hero_ship_query: Query<(&mut HeroShip, &HeroShipInput, &mut Transform)>
 ) {
-    let mut movement_direction: Vec3;
-    let mut movement_distance: f32;
+    let mut hero_ship_facing: Vec2;
 
-    for (mut hero_ship_entity, mut hero_ship_transform) in &mut hero_ship_query {
-        set_hero_ship_rotation_factor(&keyboard_input, hero_ship_entity.reborrow());
-        increase_hero_ship_movement_speed(commands.reborrow(), &keyboard_input, &hero_ship_launching_sound, hero_ship_entity.reborrow());
-        apply_brake_on_hero_ship_movement_speed(&time, &keyboard_input, hero_ship_entity.reborrow());
+    for (mut hero_ship_entity, hero_ship_input, mut hero_ship_transform) in &mut hero_ship_query {
+        set_hero_ship_rotation_direction(hero_ship_input, &mut hero_ship_entity);
+        play_hero_ship_launching_sound(commands.reborrow(), hero_ship_input, &hero_ship_launching_sound);
 
         hero_ship_transform.rotate_z(
-            *HERO_SHIP_ROTATION_FACTOR.lock().unwrap() * hero_ship_entity.rotation_speed * time.delta_seconds()
+            hero_ship_entity.rotation_direction * hero_ship_entity.rotation_speed * time.delta_seconds()
+        );
+        hero_ship_facing = (hero_ship_transform.rotation * Vec3::Y).truncate();
+        hero_ship_entity.velocity = hero_ship_entity.get_next_velocity(
+            game_config.hero_ship.flight_model,
+            hero_ship_facing,
+            hero_ship_input.accelerate,
+            hero_ship_input.brake && game_settings.down_key_action == DownKeyAction::Brake,
+            time.delta_seconds()
         );
-        movement_direction = hero_ship_transform.rotation * Vec3::Y;
-        movement_distance = hero_ship_entity.movement_speed * time.delta_seconds();
-        hero_ship_transform.translation += movement_direction * movement_distance;
+        hero_ship_transform.translation += hero_ship_entity.velocity.extend(0.) * time.delta_seconds();
 
-        apply_drag_on_hero_ship_movement_speed(&time, &keyboard_input, hero_ship_entity.reborrow());
-        apply_drag_on_hero_ship_rotation_speed(&time, &keyboard_input, hero_ship_entity);
+        apply_drag_on_hero_ship_rotation_speed(&time, hero_ship_entity);
     }
 }
 
-fn set_hero_ship_rotation_factor(
-    keyboard_input: &Res<ButtonInput<KeyCode>>,
-    mut hero_ship_entity: Mut<'_, HeroShip>
+fn set_hero_ship_rotation_direction(
+    hero_ship_input: &HeroShipInput,
+    hero_ship_entity: &mut HeroShip
 ) {
-    if
-        keyboard_input.pressed(KeyCode::ArrowLeft) ||
-        keyboard_input.pressed(KeyCode::KeyA)
-    {
-        if hero_ship_entity.rotation_speed < f32::to_radians(hero_ship_entity.rotation_speed_maximum) {
-            hero_ship_entity.rotation_speed += f32::to_radians(hero_ship_entity.rotation_speed_incrementation);
-        }
-        *HERO_SHIP_ROTATION_FACTOR.lock().unwrap() = 1.;
-    }
+    let rotation_intent: f32 = hero_ship_input.rotate_left as u8 as f32 - hero_ship_input.rotate_right as u8 as f32;
 
-    if 
-        keyboard_input.pressed(KeyCode::ArrowRight) ||
-        keyboard_input.pressed(KeyCode::KeyD)
-    {
-        if hero_ship_entity.rotation_speed < f32::to_radians(hero_ship_entity.rotation_speed_maximum) {
-            hero_ship_entity.rotation_speed += f32::to_radians(hero_ship_entity.rotation_speed_incrementation);
-        }
-        *HERO_SHIP_ROTATION_FACTOR.lock().unwrap() = -1.;
+    hero_ship_entity.rotation_direction = rotation_intent;
+
+    if rotation_intent != 0. && hero_ship_entity.rotation_speed < f32::to_radians(hero_ship_entity.rotation_speed_maximum) {
+        hero_ship_entity.rotation_speed += f32::to_radians(hero_ship_entity.rotation_speed_incrementation);
     }
 }
 
-fn increase_hero_ship_movement_speed(
+fn play_hero_ship_launching_sound(
     mut commands: Commands,
-    keyboard_input: &Res<ButtonInput<KeyCode>>,
-    hero_ship_launching_sound: &Res<HeroShipLaunchingSound>,
-    mut hero_ship_entity: Mut<'_, HeroShip>
+    hero_ship_input: &HeroShipInput,
+    hero_ship_launching_sound: &Res<HeroShipLaunchingSound>
 ) {
-    if 
-        keyboard_input.pressed(KeyCode::ArrowUp) ||
-        keyboard_input.pressed(KeyCode::KeyW)
-    {
-        if hero_ship_entity.movement_speed < hero_ship_entity.movement_speed_maximum {
-            hero_ship_entity.movement_speed += hero_ship_entity.movement_speed_incrementation;
-        }
-
+    if hero_ship_input.accelerate {
         commands.spawn(AudioBundle {
             source: hero_ship_launching_sound.0.clone(),
             settings: PlaybackSettings {
@@ -363,134 +726,291 @@ fn increase_hero_ship_movement_speed(
     }
 }
 
-fn apply_brake_on_hero_ship_movement_speed(
-    time: &Res<Time>,
-    keyboard_input: &Res<ButtonInput<KeyCode>>,
-    mut hero_ship_entity: Mut<'_, HeroShip>
-) {
-    if
-        keyboard_input.pressed(KeyCode::ArrowDown) ||
-        keyboard_input.pressed(KeyCode::KeyS)
-    {
-        if hero_ship_entity.movement_speed > 0. {
-            hero_ship_entity.movement_speed -= hero_ship_entity.movement_speed_drag * time.delta_seconds();
-        }
-    }
-}
-
-fn apply_drag_on_hero_ship_movement_speed(
-    time: &Res<Time>,
-    keyboard_input: &Res<ButtonInput<KeyCode>>,
-    mut hero_ship_entity: Mut<'_, HeroShip>
-) {
-    if
-        (
-            !keyboard_input.pressed(KeyCode::ArrowUp) ||
-            !keyboard_input.pressed(KeyCode::KeyW)
-        ) && hero_ship_entity.movement_speed > 0.
-    {
-        hero_ship_entity.movement_speed -= hero_ship_entity.movement_speed_drag * time.delta_seconds();
-    }
-}
-
 fn apply_drag_on_hero_ship_rotation_speed(
     time: &Res<Time>,
-    keyboard_input: &Res<ButtonInput<KeyCode>>,
     mut hero_ship_entity: Mut<'_, HeroShip>
 ) {
-    if
-        (
-            !keyboard_input.pressed(KeyCode::ArrowLeft) ||
-            !keyboard_input.pressed(KeyCode::KeyA)
-        ) &&
-        (
-            !keyboard_input.pressed(KeyCode::ArrowRight) ||
-            !keyboard_input.pressed(KeyCode::KeyD)
-        ) && hero_ship_entity.rotation_speed > 0.
-    {
-        hero_ship_entity.rotation_speed -= f32::to_radians(hero_ship_entity.rotation_speed_drag) * time.delta_seconds();
-    }
+    hero_ship_entity.rotation_speed =
+        (hero_ship_entity.rotation_speed - f32::to_radians(hero_ship_entity.rotation_speed_drag) * time.delta_seconds()).max(0.);
 }
 
 pub fn draw_hero_ship_fire(
     asset_server: Res<AssetServer>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut hero_ship_query: Query<(&HeroShip, &mut Handle<Image>)>
+    mut hero_ship_query: Query<(&HeroShipInput, &HeroShipHull, &mut Handle<Image>), With<HeroShip>>
 ) {
-    for (_, mut hero_ship_handle_image) in &mut hero_ship_query {
-        if
-            keyboard_input.pressed(KeyCode::ArrowUp) ||
-            keyboard_input.pressed(KeyCode::KeyW)
-        {
-            *hero_ship_handle_image = asset_server.load(HERO_SHIP_FIRE_HANDLE_IMAGE);
+    for (hero_ship_input, hero_ship_hull, mut hero_ship_handle_image) in &mut hero_ship_query {
+        if hero_ship_input.accelerate {
+            *hero_ship_handle_image = asset_server.load(hero_ship_hull.sprites.fire.clone());
         } else {
-            *hero_ship_handle_image = asset_server.load(HERO_SHIP_HANDLE_IMAGE);
+            *hero_ship_handle_image = asset_server.load(hero_ship_hull.sprites.ship.clone());
         }
     }
 }
 
 pub fn set_hero_ship_position_after_border_outbounds(
-    mut hero_ship_query: Query<(&HeroShip, &mut Transform)>
+    mut hero_ship_query: Query<&mut Transform, With<HeroShip>>
 ) {
-    let (_, mut hero_ship_transform) = hero_ship_query.single_mut();
-    let hero_ship_position_x: f32 = hero_ship_transform.translation.x;
-    let hero_ship_position_y: f32 = hero_ship_transform.translation.y;
-
-    if hero_ship_position_x >= RIGHT_BORDER_POSITION {
-        hero_ship_transform.translation.x = LEFT_BORDER_POSITION;
-        hero_ship_transform.translation.y -= 10.;
-    } else if hero_ship_position_x <= LEFT_BORDER_POSITION {
-        hero_ship_transform.translation.x = RIGHT_BORDER_POSITION;
-        hero_ship_transform.translation.y += 10.;
-    }
+    for mut hero_ship_transform in &mut hero_ship_query {
+        let hero_ship_position_x: f32 = hero_ship_transform.translation.x;
+        let hero_ship_position_y: f32 = hero_ship_transform.translation.y;
+
+        if hero_ship_position_x >= RIGHT_BORDER_POSITION {
+            hero_ship_transform.translation.x = LEFT_BORDER_POSITION;
+            hero_ship_transform.translation.y -= 10.;
+        } else if hero_ship_position_x <= LEFT_BORDER_POSITION {
+            hero_ship_transform.translation.x = RIGHT_BORDER_POSITION;
+            hero_ship_transform.translation.y += 10.;
+        }
 
-    if hero_ship_position_y >= TOP_BORDER_POSITION {
-        hero_ship_transform.translation.y = BOTTOM_BORDER_POSITION;
-        hero_ship_transform.translation.x -= 10.;
-    } else if hero_ship_position_y <= BOTTOM_BORDER_POSITION {
-        hero_ship_transform.translation.y = TOP_BORDER_POSITION;
-        hero_ship_transform.translation.x += 10.;
+        if hero_ship_position_y >= TOP_BORDER_POSITION {
+            hero_ship_transform.translation.y = BOTTOM_BORDER_POSITION;
+            hero_ship_transform.translation.x -= 10.;
+        } else if hero_ship_position_y <= BOTTOM_BORDER_POSITION {
+            hero_ship_transform.translation.y = TOP_BORDER_POSITION;
+            hero_ship_transform.translation.x += 10.;
+        }
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn hero_ship_fire_projectile(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     time: Res<Time>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut projectile_spawn_timer: ResMut<projectile::ProjectileSpawnTimer>,
+    game_config: Res<GameConfig>,
     projectile_spawn_sound: Res<projectile::ProjectileSpawnSound>,
-    hero_ship_query: Query<(&HeroShip, &Transform, &Visibility)>
+    weapon_sounds: Res<WeaponSounds>,
+    mut run_statistics: ResMut<RunStatistics>,
+    mut hero_ship_query: Query<(
+        &PlayerId,
+        &HeroShipInput,
+        &Weapon,
+        &ActivePowerUps,
+        &ShipUpgrades,
+        &mut projectile::ProjectileSpawnTimer,
+        &Transform,
+        &Visibility
+    ), With<HeroShip>>
 ) {
-    if keyboard_input.pressed(KeyCode::Space) {
-        let (_, hero_ship_transform, hero_ship_visibility) = hero_ship_query.single();
-        let mut projectile_entity: Projectile = Projectile::default();
-        projectile_spawn_timer.0.tick(time.delta());
-
-        if projectile_spawn_timer.0.just_finished() && hero_ship_visibility == &Visibility::Visible {
-            projectile_entity.translation = hero_ship_transform.translation;
-            projectile_entity.direction = hero_ship_transform.rotation * Vec3::Y;
-            Projectile::spawn_projectile(projectile_entity, commands.reborrow(), asset_server);
-            commands.spawn(AudioBundle {
-                source: projectile_spawn_sound.clone(),
-                settings: PlaybackSettings {
-                    mode: Despawn,
-                    volume: Volume::new(0.5),
-                    ..default()
+    for (
+        player_id,
+        hero_ship_input,
+        weapon,
+        active_power_ups,
+        ship_upgrades,
+        mut projectile_spawn_timer,
+        hero_ship_transform,
+        hero_ship_visibility
+    ) in &mut hero_ship_query {
+        if *weapon != Weapon::Blaster && *weapon != Weapon::HomingMissile {
+            continue;
+        }
+
+        if hero_ship_input.shoot {
+            projectile_spawn_timer.0.tick(time.delta());
+
+            if projectile_spawn_timer.0.just_finished() && hero_ship_visibility == Visibility::Visible {
+                let projectiles_per_shot: usize = active_power_ups.get_projectiles_per_shot() + ship_upgrades.get_extra_projectiles();
+                let spread_shot_angle: f32 = f32::to_radians(game_config.power_ups.spread_shot_angle);
+                run_statistics.shots_fired += projectiles_per_shot;
+
+                for projectile_index in 0..projectiles_per_shot {
+                    let projectile_angle: f32 = if projectiles_per_shot > 1 {
+                        spread_shot_angle * (projectile_index as f32 / (projectiles_per_shot - 1) as f32 - 0.5)
+                    } else {
+                        0.
+                    };
+                    let projectile_direction: Vec3 = Quat::from_rotation_z(projectile_angle) * hero_ship_transform.rotation * Vec3::Y;
+
+                    if *weapon == Weapon::HomingMissile {
+                        weapon::spawn_homing_missile(
+                            commands.reborrow(),
+                            &asset_server,
+                            &game_config,
+                            &weapon_sounds,
+                            *player_id,
+                            hero_ship_transform.translation,
+                            projectile_direction
+                        );
+                        continue;
+                    }
+
+                    let mut projectile_entity: Projectile = Projectile::from_config(&game_config.projectile);
+                    projectile_entity.translation = hero_ship_transform.translation;
+                    projectile_entity.direction = projectile_direction;
+                    projectile_entity.owner = *player_id;
+                    Projectile::spawn_projectile(projectile_entity, commands.reborrow(), &asset_server);
+                }
+
+                if *weapon == Weapon::HomingMissile {
+                    continue;
                 }
-            });
+                commands.spawn(AudioBundle {
+                    source: projectile_spawn_sound.clone(),
+                    settings: PlaybackSettings {
+                        mode: Despawn,
+                        volume: Volume::new(0.5),
+                        ..default()
+                    }
+                });
+            }
         }
     }
 }
 
 pub fn dynamic_hero_ship_still_alive_check(
     time: Res<Time>,
+    game_config: Res<GameConfig>,
+    hero_ship_remaining_lives: Res<HeroShipRemainingLives>,
     mut hero_ship_still_alive_timer: ResMut<HeroShipStillAliveTimer>,
-    mut scoreboard_score: ResMut<ScoreboardScore>
+    mut scoreboard_score: ResMut<ScoreboardScore>,
+    mut run_statistics: ResMut<RunStatistics>
 ) {
     hero_ship_still_alive_timer.0.tick(time.delta());
+    run_statistics.seconds_survived += time.delta_seconds();
 
     if hero_ship_still_alive_timer.0.just_finished() {
-        scoreboard_score.score += 5;
+        for (player_id, lives_remaining) in &hero_ship_remaining_lives.lives_remaining {
+            if *lives_remaining > 0 {
+                scoreboard_score.increase(*player_id, game_config.scores.still_alive);
+                run_statistics.still_alive_bonuses += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLIGHT_MODELS: [FlightModel; 3] = [FlightModel::Direct, FlightModel::Arcade, FlightModel::Assisted];
+
+    fn get_test_hero_ship(velocity: Vec2) -> HeroShip {
+        return HeroShip {
+            velocity,
+            movement_speed_drag: 100.,
+            movement_speed_incrementation: 192.,
+            movement_speed_maximum: 320.,
+            ..default()
+        };
+    }
+
+    fn simulate_hero_ship_flight(
+        mut hero_ship: HeroShip,
+        flight_model: FlightModel,
+        is_accelerating: bool,
+        ticks_per_second: u32,
+        seconds: u32
+    ) -> HeroShip {
+        let delta_seconds: f32 = 1. / ticks_per_second as f32;
+
+        for _ in 0..ticks_per_second * seconds {
+            hero_ship.velocity = hero_ship.get_next_velocity(flight_model, Vec2::Y, is_accelerating, false, delta_seconds);
+        }
+        return hero_ship;
+    }
+
+    fn get_rotation_input(
+        rotate_left: bool,
+        rotate_right: bool
+    ) -> HeroShipInput {
+        return HeroShipInput {
+            rotate_left,
+            rotate_right,
+            ..default()
+        };
+    }
+
+    #[test]
+    fn rotation_direction_follows_the_input_every_tick() {
+        let mut hero_ship: HeroShip = HeroShip {
+            rotation_speed_incrementation: 15.,
+            rotation_speed_maximum: 360.,
+            ..default()
+        };
+
+        set_hero_ship_rotation_direction(&get_rotation_input(true, false), &mut hero_ship);
+        assert_eq!(hero_ship.rotation_direction, 1.);
+
+        set_hero_ship_rotation_direction(&get_rotation_input(false, true), &mut hero_ship);
+        assert_eq!(hero_ship.rotation_direction, -1.);
+
+        set_hero_ship_rotation_direction(&get_rotation_input(false, false), &mut hero_ship);
+        assert_eq!(hero_ship.rotation_direction, 0.);
+
+        set_hero_ship_rotation_direction(&get_rotation_input(true, true), &mut hero_ship);
+        assert_eq!(hero_ship.rotation_direction, 0.);
+    }
+
+    #[test]
+    fn rotation_speed_only_grows_while_rotating() {
+        let mut hero_ship: HeroShip = HeroShip {
+            rotation_speed_incrementation: 15.,
+            rotation_speed_maximum: 360.,
+            ..default()
+        };
+
+        set_hero_ship_rotation_direction(&get_rotation_input(true, false), &mut hero_ship);
+        let rotation_speed_after_turning: f32 = hero_ship.rotation_speed;
+
+        set_hero_ship_rotation_direction(&get_rotation_input(false, false), &mut hero_ship);
+        set_hero_ship_rotation_direction(&get_rotation_input(true, true), &mut hero_ship);
+
+        assert_eq!(rotation_speed_after_turning, f32::to_radians(15.));
+        assert_eq!(hero_ship.rotation_speed, rotation_speed_after_turning);
+    }
+
+    #[test]
+    fn thrust_adds_the_incrementation_scaled_by_the_tick_duration() {
+        for flight_model in FLIGHT_MODELS {
+            let hero_ship: HeroShip = get_test_hero_ship(Vec2::ZERO);
+            let next_velocity: Vec2 = hero_ship.get_next_velocity(flight_model, Vec2::Y, true, false, 1. / 64.);
+
+            assert!((next_velocity - Vec2::new(0., 3.)).length() < 1e-4, "{:?}: {:?}", flight_model, next_velocity);
+        }
+    }
+
+    #[test]
+    fn thrust_response_does_not_depend_on_the_tick_rate() {
+        for flight_model in FLIGHT_MODELS {
+            let hero_ship_at_64_hz: HeroShip =
+                simulate_hero_ship_flight(get_test_hero_ship(Vec2::ZERO), flight_model, true, 64, 1);
+            let hero_ship_at_32_hz: HeroShip =
+                simulate_hero_ship_flight(get_test_hero_ship(Vec2::ZERO), flight_model, true, 32, 1);
+
+            assert!((hero_ship_at_64_hz.get_movement_speed() - 192.).abs() < 1e-2, "{:?}", flight_model);
+            assert!((hero_ship_at_64_hz.get_movement_speed() - hero_ship_at_32_hz.get_movement_speed()).abs() < 1e-2, "{:?}", flight_model);
+        }
+    }
+
+    #[test]
+    fn thrust_never_goes_past_the_maximum_speed() {
+        for flight_model in FLIGHT_MODELS {
+            let hero_ship: HeroShip =
+                simulate_hero_ship_flight(get_test_hero_ship(Vec2::new(0., 310.)), flight_model, true, 64, 3);
+
+            assert!((hero_ship.get_movement_speed() - 320.).abs() < 1e-3, "{:?}", flight_model);
+        }
+    }
+
+    #[test]
+    fn coasting_decays_the_speed_by_the_scaled_drag() {
+        for flight_model in FLIGHT_MODELS {
+            let hero_ship: HeroShip =
+                simulate_hero_ship_flight(get_test_hero_ship(Vec2::new(0., 300.)), flight_model, false, 64, 1);
+            let expected_movement_speed: f32 = 300. - 100. * flight_model.get_coasting_drag_factor();
+
+            assert!((hero_ship.get_movement_speed() - expected_movement_speed).abs() < 1e-2, "{:?}", flight_model);
+        }
+    }
+
+    #[test]
+    fn coasting_never_drops_below_zero_speed() {
+        for flight_model in FLIGHT_MODELS {
+            let hero_ship: HeroShip =
+                simulate_hero_ship_flight(get_test_hero_ship(Vec2::new(0., 50.)), flight_model, false, 64, 60);
+
+            assert_eq!(hero_ship.velocity, Vec2::ZERO, "{:?}", flight_model);
+        }
     }
 }
@@ -0,0 +1,619 @@
+mod constants;
+mod config;
+mod hero_ship;
+mod asteroid;
+mod projectile;
+mod collision;
+mod ui;
+mod versus;
+mod network;
+mod autopilot;
+mod power_up;
+mod weapon;
+mod shop;
+mod ship_select;
+mod settings;
+mod energy_shield;
+mod statistics;
+mod achievements;
+mod combo;
+mod popup;
+mod menu;
+mod save;
+pub mod env;
+
+use bevy::{
+    prelude::*,
+    app::AppExit,
+    ecs::schedule::ScheduleLabel,
+    window::{
+        EnabledButtons,
+        PrimaryWindow,
+        WindowFocused,
+        WindowPosition
+    },
+    winit::WinitWindows
+};
+
+use bevy_fps_counter::{
+    FpsCounter,
+    FpsCounterText,
+    FpsCounterPlugin
+};
+
+use bevy_rapier2d::prelude::*;
+use std::io::Cursor;
+use winit::window::Icon;
+
+use rand::{
+    rngs::StdRng,
+    SeedableRng
+};
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum GameState {
+    #[default]
+    StartScreen,
+    ShipSelect,
+    InGame,
+    Shop,
+    EndGame
+}
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum PausingState {
+    Paused,
+    #[default]
+    Running
+}
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum RoundState {
+    #[default]
+    Playing,
+    RoundOver,
+    MatchOver
+}
+
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    #[default]
+    SinglePlayer,
+    Cooperative,
+    Versus
+}
+
+impl GameMode {
+    fn players(&self) -> Vec<hero_ship::PlayerId> {
+        match self {
+            GameMode::SinglePlayer => return vec![hero_ship::PlayerId::One],
+            GameMode::Cooperative | GameMode::Versus => return vec![hero_ship::PlayerId::One, hero_ship::PlayerId::Two]
+        }
+    }
+}
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct InputSet;
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct GameplaySet;
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct ResetRun;
+
+#[derive(Component)]
+struct RunScoped;
+
+#[derive(Event, Clone, Copy)]
+struct StartGame {
+    game_mode: GameMode,
+    seed: Option<u64>
+}
+
+#[derive(Event, Clone, Copy, Default)]
+struct RestartGame {
+    seed: Option<u64>
+}
+
+#[derive(Resource, Deref, DerefMut)]
+struct GameRng(StdRng);
+
+impl Default for GameRng {
+    fn default() -> Self {
+        return Self(StdRng::from_entropy());
+    }
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct BackgroundMusic(Handle<AudioSource>);
+
+pub fn run() {
+    match env::run_environment_rollout_from_args(std::env::args().skip(1).collect()) {
+        Ok(true) => return,
+        Ok(false) => {},
+        Err(environment_rollout_error) => {
+            eprintln!("Could not run the environment rollout, {}.", environment_rollout_error);
+            std::process::exit(1);
+        }
+    }
+
+    App::new()
+        .add_plugins(
+            DefaultPlugins.set(WindowPlugin {
+                primary_window: Some(Window {
+                    title: "Asteroids".into(),
+                    resizable: false,
+                    enabled_buttons: EnabledButtons{ minimize: true, maximize: false, close: true },
+                    focused: true,
+                    position: WindowPosition::Centered(MonitorSelection::Primary),
+                    resolution: (800., 500.).into(),
+                    ..default()
+                }),
+                ..default()
+            })
+        )
+        .add_plugins(FpsCounterPlugin)
+        .add_plugins(GamePlugin)
+        .insert_resource(GlobalVolume::new(0.50))
+        .add_systems(Startup, setup)
+        .add_systems(PostStartup, (set_fps_counter, setup_main_entities))
+        .run();
+}
+
+struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.insert_resource(RapierConfiguration {
+            timestep_mode: TimestepMode::Fixed { dt: 1. / 64., substeps: 1 },
+            ..default()
+        });
+        app.add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.).in_fixed_schedule());
+        app.add_plugins(config::ConfigPlugin);
+        app.add_plugins(ui::UiPlugin);
+        app.add_plugins(hero_ship::HeroShipPlugin);
+        app.add_plugins(asteroid::AsteroidPlugin);
+        app.add_plugins(projectile::ProjectilePlugin);
+        app.add_plugins(power_up::PowerUpPlugin);
+        app.add_plugins(weapon::WeaponPlugin);
+        app.add_plugins(shop::ShopPlugin);
+        app.add_plugins(ship_select::ShipSelectPlugin);
+        app.add_plugins(settings::SettingsPlugin);
+        app.add_plugins(energy_shield::EnergyShieldPlugin);
+        app.add_plugins(statistics::StatisticsPlugin);
+        app.add_plugins(achievements::AchievementsPlugin);
+        app.add_plugins(combo::ComboPlugin);
+        app.add_plugins(popup::PopupPlugin);
+        app.add_plugins(menu::MenuPlugin);
+        app.add_plugins(save::SavePlugin);
+        app.add_plugins(collision::CollisionPlugin);
+        app.add_plugins(versus::VersusPlugin);
+        app.add_plugins(network::NetworkPlugin);
+        app.add_plugins(autopilot::AutopilotPlugin);
+        app.init_state::<GameState>();
+        app.init_state::<PausingState>();
+        app.init_state::<RoundState>();
+        app.init_resource::<GameMode>();
+        app.init_resource::<GameRng>();
+        app.add_event::<StartGame>();
+        app.add_event::<RestartGame>();
+        app.init_schedule(ResetRun);
+        app.add_systems(ResetRun, despawn_run_scoped_entities);
+        app.configure_sets(FixedUpdate, (
+            InputSet,
+            GameplaySet
+        ).chain().before(PhysicsSet::SyncBackend).run_if(in_state(PausingState::Running).and_then(in_state(GameState::InGame))));
+        app.add_systems(Update, (
+            check_for_restarting_by_menu.after(menu::navigate_menus).run_if(not(resource_exists::<network::NetworkSession>)),
+            restart_game
+        ).chain().run_if(in_state(GameState::EndGame).or_else(in_state(GameState::InGame))));
+        app.add_systems(Update, (
+            check_for_starting_by_menu.after(menu::navigate_menus).run_if(not(resource_exists::<network::NetworkSession>))
+        ).run_if(in_state(GameState::StartScreen)));
+        app.add_systems(Update, (
+            start_game.after(check_for_starting_by_menu).after(ship_select::navigate_ship_select_menu)
+        ).run_if(in_state(GameState::StartScreen).or_else(in_state(GameState::ShipSelect))));
+        app.add_systems(Update, (
+            check_for_pausing_by_keyboard,
+            check_for_pausing_on_focus_lost.run_if(not(resource_exists::<autopilot::AutopilotMode>)),
+            check_for_resuming_by_menu.after(menu::navigate_menus),
+            check_for_quitting_to_title_by_menu.after(menu::navigate_menus)
+        ).run_if(in_state(GameState::InGame).and_then(not(resource_exists::<network::NetworkSession>))));
+        app.add_systems(OnTransition { from: GameState::InGame, to: GameState::StartScreen }, (
+            ui::erase_player_two_scoreboard,
+            reset_after_quitting_to_title
+        ).chain().run_if(not(resource_exists::<autopilot::AutopilotMode>)));
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    windows: NonSend<WinitWindows>,
+    primary_window_query: Query<Entity, With<PrimaryWindow>>
+) {
+    set_game_camera(commands.reborrow());
+    set_game_window_icon(windows, primary_window_query);
+    set_game_sounds(commands.reborrow(), &asset_server);
+    hero_ship::spawn_hero_ship_lives(commands.reborrow(), &asset_server, hero_ship::PlayerId::One);
+}
+
+fn set_game_camera(mut commands: Commands) {
+    let camera_2d_bundle: Camera2dBundle = Camera2dBundle {
+        camera: Camera { clear_color: ClearColorConfig::Custom(Color::BLACK), ..default() },
+        ..default()
+    };
+    commands.spawn(camera_2d_bundle);
+}
+
+fn set_game_window_icon(
+    windows: NonSend<WinitWindows>,
+    primary_window_query: Query<Entity, With<PrimaryWindow>>
+) {
+    let primary_window_entity: Entity = primary_window_query.single();
+    let Some(primary_window) = windows.get_window(primary_window_entity) else {
+        return;
+    };
+    let icon_buffer = Cursor::new(include_bytes!(
+        "../assets/icons/asteroids_icon.png"
+    ));
+
+    if let Ok(image) = image::load(icon_buffer, image::ImageFormat::Png) {
+        let icon_image = image.into_rgba8();
+        let (icon_width, icon_height) = icon_image.dimensions();
+        let icon_rgba: Vec<u8> = icon_image.into_raw();
+        let asteroids_game_icon: Icon = Icon::from_rgba(icon_rgba, icon_width, icon_height).unwrap();
+        primary_window.set_window_icon(Some(asteroids_game_icon));
+    };
+}
+
+fn set_game_sounds(
+    mut commands: Commands,
+    asset_server: &Res<AssetServer>
+) {
+    let background_music: Handle<AudioSource> =
+        asset_server.load(constants::audio_source_handles::BACKGROUND_MUSIC_HANDLE_AUDIO_SOURCE);
+    commands.insert_resource(BackgroundMusic(background_music));
+
+    let hero_ship_launching_sound: Handle<AudioSource> =
+        asset_server.load(constants::audio_source_handles::HERO_SHIP_LAUNCHING_SOUND_HANDLE_AUDIO_SOURCE);
+    commands.insert_resource(hero_ship::HeroShipLaunchingSound(hero_ship_launching_sound));
+
+    let hero_ship_destroyed_sound: Handle<AudioSource> =
+        asset_server.load(constants::audio_source_handles::HERO_SHIP_DESTROYED_SOUND_HANDLE_AUDIO_SOURCE);
+    commands.insert_resource(hero_ship::HeroShipDestroyedSound(hero_ship_destroyed_sound));
+
+    let projectile_spawn_sound: Handle<AudioSource> =
+        asset_server.load(constants::audio_source_handles::PROJECTILE_SPAWN_SOUND_HANDLE_AUDIO_SOURCE);
+    commands.insert_resource(projectile::ProjectileSpawnSound(projectile_spawn_sound));
+
+    let asteroid_destroyed_sound: Handle<AudioSource> =
+        asset_server.load(constants::audio_source_handles::ASTEROID_DESTROYED_SOUND_HANDLE_AUDIO_SOURCE);
+    commands.insert_resource(asteroid::AsteroidDestroyedSound(asteroid_destroyed_sound));
+
+    let asteroid_hit_sound: Handle<AudioSource> =
+        asset_server.load(constants::audio_source_handles::ASTEROID_HIT_SOUND_HANDLE_AUDIO_SOURCE);
+    commands.insert_resource(asteroid::AsteroidHitSound(asteroid_hit_sound));
+
+    commands.insert_resource(weapon::WeaponSounds {
+        charged_shot: asset_server.load(constants::audio_source_handles::CHARGED_SHOT_SOUND_HANDLE_AUDIO_SOURCE),
+        laser_beam: asset_server.load(constants::audio_source_handles::LASER_BEAM_SOUND_HANDLE_AUDIO_SOURCE),
+        homing_missile: asset_server.load(constants::audio_source_handles::HOMING_MISSILE_SOUND_HANDLE_AUDIO_SOURCE)
+    });
+}
+
+fn setup_main_entities(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_config: Res<config::GameConfig>,
+    game_mode: Res<GameMode>,
+    mut game_rng: ResMut<GameRng>,
+    selected_hulls: Res<ship_select::SelectedHulls>
+) {
+    hero_ship::spawn_hero_ships(commands.reborrow(), &asset_server, &game_config, *game_mode, &selected_hulls);
+    asteroid::spawn_initial_asteroids(commands.reborrow(), &asset_server, &game_config, &mut game_rng);
+}
+
+fn reset_run(commands: &mut Commands) {
+    commands.add(|world: &mut World| world.run_schedule(ResetRun));
+}
+
+fn despawn_run_scoped_entities(
+    mut commands: Commands,
+    run_scoped_query: Query<Entity, With<RunScoped>>
+) {
+    for run_scoped_entity in &run_scoped_query {
+        commands.entity(run_scoped_entity).despawn_recursive();
+    }
+}
+
+fn set_fps_counter(
+    mut fps_counter_state: ResMut<FpsCounter>,
+    mut fps_counter_text_query: Query<&mut Text, With<FpsCounterText>>
+) {
+    let mut fps_counter_text: Mut<'_, Text> = fps_counter_text_query.single_mut();
+    fps_counter_text.sections[0].style.font_size = 15.;
+    fps_counter_state.enable();
+}
+
+fn check_for_starting_by_menu(
+    mut menu_action_selected_events: EventReader<menu::MenuActionSelected>,
+    mut ship_selection: ResMut<ship_select::ShipSelection>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut app_exit_events: EventWriter<AppExit>
+) {
+    for menu_action_selected_event in menu_action_selected_events.read() {
+        match menu_action_selected_event.action {
+            menu::MenuAction::Play => ship_select::open_ship_select(&mut ship_selection, &mut next_state, GameMode::SinglePlayer),
+            menu::MenuAction::Cooperative => ship_select::open_ship_select(&mut ship_selection, &mut next_state, GameMode::Cooperative),
+            menu::MenuAction::Versus => ship_select::open_ship_select(&mut ship_selection, &mut next_state, GameMode::Versus),
+            menu::MenuAction::Quit => { app_exit_events.send(AppExit); },
+            _ => ()
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn start_game(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    background_music: Res<BackgroundMusic>,
+    mut start_game_events: EventReader<StartGame>,
+    game_config: Res<config::GameConfig>,
+    mut game_mode: ResMut<GameMode>,
+    mut game_rng: ResMut<GameRng>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut versus_rounds: ResMut<versus::VersusRounds>,
+    selected_hulls: Res<ship_select::SelectedHulls>
+) {
+    let Some(start_game_event) = start_game_events.read().last().copied() else {
+        return;
+    };
+
+    if let Some(seed) = start_game_event.seed {
+        game_rng.0 = StdRng::seed_from_u64(seed);
+    }
+
+    *game_mode = start_game_event.game_mode;
+    reset_run(&mut commands);
+
+    if *game_mode != GameMode::SinglePlayer {
+        *versus_rounds = versus::VersusRounds::default();
+        ui::spawn_scoreboard(commands.reborrow(), hero_ship::PlayerId::Two);
+    }
+
+    next_state.set(GameState::InGame);
+    commands.spawn(AudioBundle {
+        source: background_music.clone(),
+        settings: PlaybackSettings::LOOP
+    });
+    hero_ship::spawn_hero_ships_lives(commands.reborrow(), &asset_server, *game_mode);
+    setup_main_entities(commands, asset_server, game_config, game_mode.into(), game_rng, selected_hulls);
+}
+
+fn check_for_restarting_by_menu(
+    mut menu_action_selected_events: EventReader<menu::MenuActionSelected>,
+    mut restart_game_events: EventWriter<RestartGame>
+) {
+    if menu_action_selected_events.read().any(|menu_action_selected_event| menu_action_selected_event.action == menu::MenuAction::Restart) {
+        restart_game_events.send(RestartGame::default());
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn restart_game(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut restart_game_events: EventReader<RestartGame>,
+    game_config: Res<config::GameConfig>,
+    game_mode: Res<GameMode>,
+    mut game_rng: ResMut<GameRng>,
+    mut next_state_game_state: ResMut<NextState<GameState>>,
+    mut next_state_pausing_state: ResMut<NextState<PausingState>>,
+    selected_hulls: Res<ship_select::SelectedHulls>
+) {
+    let Some(restart_game_event) = restart_game_events.read().last().copied() else {
+        return;
+    };
+
+    if let Some(seed) = restart_game_event.seed {
+        game_rng.0 = StdRng::seed_from_u64(seed);
+    }
+
+    reset_run(&mut commands);
+    hero_ship::spawn_hero_ships_lives(commands.reborrow(), &asset_server, *game_mode);
+    setup_main_entities(commands, asset_server, game_config, game_mode, game_rng, selected_hulls);
+    next_state_game_state.set(GameState::InGame);
+    next_state_pausing_state.set(PausingState::Running);
+}
+
+fn check_for_resuming_by_menu(
+    mut menu_action_selected_events: EventReader<menu::MenuActionSelected>,
+    mut next_state: ResMut<NextState<PausingState>>
+) {
+    if menu_action_selected_events.read().any(|menu_action_selected_event| menu_action_selected_event.action == menu::MenuAction::Resume) {
+        next_state.set(PausingState::Running);
+    }
+}
+
+fn check_for_quitting_to_title_by_menu(
+    mut menu_action_selected_events: EventReader<menu::MenuActionSelected>,
+    mut next_state_game_state: ResMut<NextState<GameState>>,
+    mut next_state_pausing_state: ResMut<NextState<PausingState>>
+) {
+    if menu_action_selected_events.read().any(|menu_action_selected_event| menu_action_selected_event.action == menu::MenuAction::QuitToTitle) {
+        next_state_game_state.set(GameState::StartScreen);
+        next_state_pausing_state.set(PausingState::Running);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn reset_after_quitting_to_title(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    background_music: Res<BackgroundMusic>,
+    game_config: Res<config::GameConfig>,
+    mut game_mode: ResMut<GameMode>,
+    game_rng: ResMut<GameRng>,
+    selected_hulls: Res<ship_select::SelectedHulls>,
+    mut versus_rounds: ResMut<versus::VersusRounds>,
+    audio_query: Query<(Entity, &Handle<AudioSource>)>
+) {
+    for (audio_entity, audio_source_handle) in &audio_query {
+        if *audio_source_handle == background_music.0 {
+            commands.entity(audio_entity).despawn_recursive();
+        }
+    }
+
+    *game_mode = GameMode::SinglePlayer;
+    *versus_rounds = versus::VersusRounds::default();
+    reset_run(&mut commands);
+    hero_ship::spawn_hero_ship_lives(commands.reborrow(), &asset_server, hero_ship::PlayerId::One);
+    setup_main_entities(commands, asset_server, game_config, game_mode.into(), game_rng, selected_hulls);
+}
+
+fn check_for_pausing_on_focus_lost(
+    mut window_focused_events: EventReader<WindowFocused>,
+    states: Res<State<PausingState>>,
+    mut next_state: ResMut<NextState<PausingState>>
+) {
+    let is_focus_lost: bool = window_focused_events.read().any(|window_focused_event| !window_focused_event.focused);
+
+    if is_focus_lost && *states.get() == PausingState::Running {
+        next_state.set(PausingState::Paused);
+    }
+}
+
+fn check_for_pausing_by_keyboard(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    states: ResMut<State<PausingState>>,
+    mut next_state: ResMut<NextState<PausingState>>
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        match states.get() {
+            PausingState::Paused => next_state.set(PausingState::Running),
+            PausingState::Running => next_state.set(PausingState::Paused)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq)]
+    struct RunSnapshot {
+        game_state: GameState,
+        pausing_state: PausingState,
+        scoreboard_score: usize,
+        remaining_lives: usize,
+        hero_ship_still_alive_timer_elapsed: Duration,
+        asteroid_check_for_types_timer_elapsed: Duration,
+        run_statistics: String,
+        game_rng_sample: u64,
+        hero_ships: Vec<String>,
+        asteroids: Vec<String>,
+        entity_signatures: Vec<String>
+    }
+
+    fn take_run_snapshot(world: &mut World) -> RunSnapshot {
+        let mut hero_ships: Vec<String> = world
+            .query::<(
+                &hero_ship::PlayerId,
+                &hero_ship::HeroShip,
+                &Transform,
+                &Visibility,
+                &hero_ship::HeroShipRespawnTimer,
+                &projectile::ProjectileSpawnTimer
+            )>()
+            .iter(world)
+            .map(|(player_id, hero_ship, hero_ship_transform, hero_ship_visibility, hero_ship_respawn_timer, projectile_spawn_timer)| format!(
+                "{:?} {:?} {:?} {} {:?} {:?} {:?}",
+                player_id,
+                hero_ship_transform.translation,
+                hero_ship.velocity,
+                hero_ship.rotation_speed,
+                hero_ship_visibility,
+                hero_ship_respawn_timer.elapsed(),
+                projectile_spawn_timer.0.elapsed()
+            ))
+            .collect();
+        let mut asteroids: Vec<String> = world
+            .query::<(&asteroid::Asteroid, &Transform)>()
+            .iter(world)
+            .map(|(asteroid, asteroid_transform)| format!("{} {:?}", asteroid.archetype, asteroid_transform.translation))
+            .collect();
+        let all_entities: Vec<Entity> = world.iter_entities().map(|entity_ref| entity_ref.id()).collect();
+        let mut entity_signatures: Vec<String> = all_entities.into_iter()
+            .map(|entity| {
+                let mut component_names: Vec<&str> = world.inspect_entity(entity).iter().map(|component_info| component_info.name()).collect();
+                component_names.sort();
+                return component_names.join(", ");
+            })
+            .collect();
+
+        hero_ships.sort();
+        asteroids.sort();
+        entity_signatures.sort();
+
+        return RunSnapshot {
+            game_state: world.resource::<State<GameState>>().get().clone(),
+            pausing_state: world.resource::<State<PausingState>>().get().clone(),
+            scoreboard_score: world.resource::<ui::ScoreboardScore>().get(hero_ship::PlayerId::One),
+            remaining_lives: world.resource::<hero_ship::HeroShipRemainingLives>().get(hero_ship::PlayerId::One),
+            hero_ship_still_alive_timer_elapsed: world.resource::<hero_ship::HeroShipStillAliveTimer>().elapsed(),
+            asteroid_check_for_types_timer_elapsed: world.resource::<asteroid::AsteroidCheckForTypesTimer>().elapsed(),
+            run_statistics: format!("{:?}", world.resource::<statistics::RunStatistics>()),
+            game_rng_sample: world.resource::<GameRng>().0.clone().gen(),
+            hero_ships,
+            asteroids,
+            entity_signatures
+        };
+    }
+
+    fn update_app(
+        app: &mut App,
+        updates: usize
+    ) {
+        for _ in 0..updates {
+            app.update();
+        }
+    }
+
+    #[test]
+    fn restarting_a_run_matches_a_fresh_start() {
+        let mut app: App = env::build_headless_app();
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        app.world.send_event(StartGame { game_mode: GameMode::SinglePlayer, seed: Some(11) });
+        update_app(&mut app, 2);
+        let fresh_start_snapshot: RunSnapshot = take_run_snapshot(&mut app.world);
+        update_app(&mut app, 30);
+        let fresh_start_later_snapshot: RunSnapshot = take_run_snapshot(&mut app.world);
+
+        app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::ArrowUp);
+        app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::ArrowLeft);
+        app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+        update_app(&mut app, 200);
+        app.world.resource_mut::<ButtonInput<KeyCode>>().reset_all();
+
+        let played_snapshot: RunSnapshot = take_run_snapshot(&mut app.world);
+        assert_ne!(played_snapshot, fresh_start_later_snapshot);
+
+        app.world.send_event(RestartGame { seed: Some(11) });
+        update_app(&mut app, 2);
+        let restart_snapshot: RunSnapshot = take_run_snapshot(&mut app.world);
+        update_app(&mut app, 30);
+        let restart_later_snapshot: RunSnapshot = take_run_snapshot(&mut app.world);
+
+        assert_eq!(restart_snapshot, fresh_start_snapshot);
+        assert_eq!(restart_later_snapshot, fresh_start_later_snapshot);
+    }
+}
@@ -0,0 +1,321 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::constants::menu::{
+    MENU_BUTTON_FONT_SIZE,
+    MENU_BUTTON_WIDTH,
+    MENU_BUTTON_HEIGHT,
+    MENU_TRANSITION_SECONDS
+};
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.add_event::<MenuActionSelected>();
+        app.add_systems(Update, (
+            navigate_menus,
+            go_back_from_submenus,
+            despawn_orphaned_submenus,
+            draw_menus
+        ).chain());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuAction {
+    Continue,
+    Play,
+    Cooperative,
+    Versus,
+    Settings,
+    HighScores,
+    Quit,
+    Resume,
+    Restart,
+    Controls,
+    QuitToTitle,
+    SwitchDownKeyAction,
+    Back
+}
+
+#[derive(Component, Default)]
+pub struct Menu {
+    pub focused_button_index: usize
+}
+
+#[derive(Component)]
+pub struct Submenu {
+    pub parent_menu_entity: Entity
+}
+
+#[derive(Component, Clone, Copy)]
+pub struct MenuButton {
+    pub menu_entity: Entity,
+    pub index: usize,
+    pub action: MenuAction
+}
+
+#[derive(Component, Deref, DerefMut)]
+pub struct MenuTransition(pub Timer);
+
+impl Default for MenuTransition {
+    fn default() -> Self {
+        return Self(Timer::from_seconds(MENU_TRANSITION_SECONDS, TimerMode::Once));
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct MenuActionSelected {
+    pub menu_entity: Entity,
+    pub action: MenuAction
+}
+
+pub fn get_menu_root_bundle(background_alpha: f32) -> NodeBundle {
+    return NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(6.),
+            ..default()
+        },
+        background_color: Color::rgba(0., 0., 0., background_alpha).into(),
+        z_index: ZIndex::Global(1),
+        ..default()
+    };
+}
+
+pub fn spawn_menu_title(
+    parent: &mut ChildBuilder,
+    menu_title: &str,
+    font_size: f32
+) {
+    parent.spawn(
+        TextBundle::from_section(
+            menu_title,
+            TextStyle {
+                font_size,
+                color: Color::SALMON,
+                ..default()
+            },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            margin: UiRect::bottom(Val::Px(12.)),
+            ..default()
+        })
+    );
+}
+
+pub fn spawn_menu_buttons(
+    parent: &mut ChildBuilder,
+    menu_entity: Entity,
+    menu_buttons: &[(MenuAction, String)]
+) {
+    for (menu_button_index, (menu_action, menu_button_label)) in menu_buttons.iter().enumerate() {
+        parent.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(MENU_BUTTON_WIDTH),
+                    height: Val::Px(MENU_BUTTON_HEIGHT),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                ..default()
+            },
+            MenuButton {
+                menu_entity,
+                index: menu_button_index,
+                action: *menu_action
+            }
+        )).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                menu_button_label.clone(),
+                TextStyle {
+                    font_size: MENU_BUTTON_FONT_SIZE,
+                    color: Color::WHITE,
+                    ..default()
+                }
+            ));
+        });
+    }
+}
+
+pub fn open_submenu(
+    commands: &mut Commands,
+    parent_menu_entity: Entity,
+    submenu_bundle: impl Bundle
+) -> Entity {
+    commands.entity(parent_menu_entity).insert(Visibility::Hidden);
+
+    return commands.spawn((
+        get_menu_root_bundle(0.9),
+        Menu::default(),
+        Submenu { parent_menu_entity },
+        MenuTransition::default(),
+        submenu_bundle
+    )).id();
+}
+
+pub fn set_menu_button_label(
+    menu_button_query: &Query<(&MenuButton, &Children)>,
+    text_query: &mut Query<&mut Text>,
+    menu_action: MenuAction,
+    menu_button_label: String
+) {
+    for (menu_button, menu_button_children) in menu_button_query.iter() {
+        if menu_button.action != menu_action {
+            continue;
+        }
+
+        for menu_button_child in menu_button_children.iter() {
+            if let Ok(mut menu_button_text) = text_query.get_mut(*menu_button_child) {
+                menu_button_text.sections[0].value = menu_button_label.clone();
+            }
+        }
+    }
+}
+
+pub fn navigate_menus(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_button_input: Res<ButtonInput<GamepadButton>>,
+    mut menu_action_selected_events: EventWriter<MenuActionSelected>,
+    mut menu_query: Query<(Entity, &mut Menu, &Visibility)>,
+    menu_button_query: Query<&MenuButton>,
+    menu_button_interaction_query: Query<(&MenuButton, &Interaction), Changed<Interaction>>
+) {
+    let is_gamepad_button_just_pressed = |gamepad_button_type: GamepadButtonType| -> bool {
+        return gamepads.iter().any(|gamepad| gamepad_button_input.just_pressed(GamepadButton::new(gamepad, gamepad_button_type)));
+    };
+    let is_focus_moving_up: bool = keyboard_input.any_just_pressed([KeyCode::ArrowUp, KeyCode::KeyW]) ||
+        is_gamepad_button_just_pressed(GamepadButtonType::DPadUp);
+    let is_focus_moving_down: bool = keyboard_input.any_just_pressed([KeyCode::ArrowDown, KeyCode::KeyS]) ||
+        is_gamepad_button_just_pressed(GamepadButtonType::DPadDown);
+    let is_focused_button_selected: bool = keyboard_input.any_just_pressed([KeyCode::Enter, KeyCode::Space]) ||
+        is_gamepad_button_just_pressed(GamepadButtonType::South);
+
+    for (menu_entity, mut menu, menu_visibility) in &mut menu_query {
+        if *menu_visibility == Visibility::Hidden {
+            continue;
+        }
+        let menu_buttons: Vec<&MenuButton> = menu_button_query.iter()
+            .filter(|menu_button| menu_button.menu_entity == menu_entity)
+            .collect();
+
+        if menu_buttons.is_empty() {
+            continue;
+        }
+        let menu_buttons_count: usize = menu_buttons.len();
+
+        if is_focus_moving_up {
+            menu.focused_button_index = (menu.focused_button_index + menu_buttons_count - 1) % menu_buttons_count;
+        } else if is_focus_moving_down {
+            menu.focused_button_index = (menu.focused_button_index + 1) % menu_buttons_count;
+        }
+
+        for (menu_button, menu_button_interaction) in &menu_button_interaction_query {
+            if menu_button.menu_entity != menu_entity {
+                continue;
+            }
+
+            match *menu_button_interaction {
+                Interaction::Hovered => menu.focused_button_index = menu_button.index,
+                Interaction::Pressed => {
+                    menu.focused_button_index = menu_button.index;
+                    menu_action_selected_events.send(MenuActionSelected { menu_entity, action: menu_button.action });
+                },
+                Interaction::None => ()
+            }
+        }
+
+        if is_focused_button_selected {
+            if let Some(focused_menu_button) = menu_buttons.iter().find(|menu_button| menu_button.index == menu.focused_button_index) {
+                menu_action_selected_events.send(MenuActionSelected { menu_entity, action: focused_menu_button.action });
+            }
+        }
+    }
+}
+
+pub fn go_back_from_submenus(
+    mut commands: Commands,
+    mut menu_action_selected_events: EventReader<MenuActionSelected>,
+    submenu_query: Query<&Submenu>
+) {
+    for menu_action_selected_event in menu_action_selected_events.read() {
+        if menu_action_selected_event.action != MenuAction::Back {
+            continue;
+        }
+        let Ok(submenu) = submenu_query.get(menu_action_selected_event.menu_entity) else {
+            continue;
+        };
+
+        commands.entity(menu_action_selected_event.menu_entity).despawn_recursive();
+
+        if let Some(mut parent_menu_entity_commands) = commands.get_entity(submenu.parent_menu_entity) {
+            parent_menu_entity_commands.insert((Visibility::Inherited, MenuTransition::default()));
+        }
+    }
+}
+
+pub fn despawn_orphaned_submenus(
+    mut commands: Commands,
+    submenu_query: Query<(Entity, &Submenu)>,
+    menu_query: Query<(), With<Menu>>
+) {
+    for (submenu_entity, submenu) in &submenu_query {
+        if !menu_query.contains(submenu.parent_menu_entity) {
+            commands.entity(submenu_entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn draw_menus(
+    time: Res<Time>,
+    mut menu_query: Query<(Entity, &Menu, Option<&mut MenuTransition>)>,
+    mut menu_button_query: Query<(&MenuButton, &Children, &mut BackgroundColor)>,
+    mut text_query: Query<&mut Text>
+) {
+    let mut menu_states: HashMap<Entity, (usize, f32)> = HashMap::new();
+
+    for (menu_entity, menu, menu_transition) in &mut menu_query {
+        let transition_progress: f32 = match menu_transition {
+            Some(mut menu_transition) => {
+                menu_transition.tick(time.delta());
+                1. - (1. - menu_transition.fraction()).powi(2)
+            },
+            None => 1.
+        };
+        menu_states.insert(menu_entity, (menu.focused_button_index, transition_progress));
+    }
+
+    for (menu_button, menu_button_children, mut menu_button_background_color) in &mut menu_button_query {
+        let Some((focused_button_index, transition_progress)) = menu_states.get(&menu_button.menu_entity).copied() else {
+            continue;
+        };
+        let is_focused: bool = menu_button.index == focused_button_index;
+
+        *menu_button_background_color = if is_focused {
+            Color::rgba(1., 0.55, 0.45, 0.3 * transition_progress).into()
+        } else {
+            Color::NONE.into()
+        };
+
+        for menu_button_child in menu_button_children.iter() {
+            if let Ok(mut menu_button_text) = text_query.get_mut(*menu_button_child) {
+                let menu_button_text_color: Color = if is_focused { Color::SALMON } else { Color::WHITE };
+
+                menu_button_text.sections[0].style.color = menu_button_text_color.with_a(transition_progress);
+            }
+        }
+    }
+}
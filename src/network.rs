@@ -0,0 +1,693 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use rand::random;
+use thiserror::Error;
+
+use std::{
+    collections::BTreeMap,
+    io::ErrorKind,
+    net::{
+        SocketAddr,
+        UdpSocket
+    },
+    time::{
+        Duration,
+        Instant
+    }
+};
+
+use crate::GameState;
+use crate::GameMode;
+use crate::InputSet;
+use crate::GameplaySet;
+use crate::StartGame;
+use crate::RestartGame;
+use crate::asteroid::Asteroid;
+use crate::projectile::Projectile;
+use crate::ui::ScoreboardScore;
+use crate::menu::{self, MenuAction, MenuActionSelected};
+
+use crate::hero_ship::{
+    self,
+    HeroShip,
+    HeroShipControls,
+    HeroShipInput,
+    HeroShipRemainingLives,
+    PlayerId
+};
+
+use crate::constants::network::{
+    INPUT_DELAY_FRAMES,
+    INPUT_REDUNDANCY_FRAMES,
+    CHECKSUM_INTERVAL_FRAMES,
+    DISCONNECT_TIMEOUT_SECONDS,
+    RESEND_INTERVAL_SECONDS
+};
+
+const HELLO_MESSAGE: u8 = 0;
+const WELCOME_MESSAGE: u8 = 1;
+const INPUT_MESSAGE: u8 = 2;
+const CHECKSUM_MESSAGE: u8 = 3;
+const RESTART_MESSAGE: u8 = 4;
+const PROJECTILE_CHECKSUM_SALT: u64 = 0x70726f6a;
+
+pub struct NetworkPlugin;
+
+impl Plugin for NetworkPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        match NetworkSession::from_args(std::env::args().skip(1).collect()) {
+            Ok(Some(network_session)) => {
+                app.insert_resource(NetworkStatus::from(&network_session));
+                app.insert_resource(network_session);
+            },
+            Ok(None) => {},
+            Err(network_session_error) => error!("Could not start the network game, {}. Starting a local game instead.", network_session_error)
+        }
+
+        app.configure_sets(FixedUpdate, GameplaySet.run_if(is_simulation_ready));
+        app.add_systems(PostStartup, spawn_network_status_text.run_if(resource_exists::<NetworkStatus>));
+        app.add_systems(Update, (
+            receive_network_messages,
+            send_network_handshake,
+            check_for_network_disconnect
+        ).chain().run_if(resource_exists::<NetworkSession>));
+        app.add_systems(Update, (
+            check_for_network_restarting_by_menu.after(menu::navigate_menus)
+        ).before(crate::restart_game).run_if(in_state(GameState::EndGame).and_then(resource_exists::<NetworkSession>)));
+        app.add_systems(Update, update_network_status_text.run_if(resource_exists::<NetworkStatus>));
+        app.add_systems(FixedUpdate, (
+            synchronize_network_inputs
+        ).in_set(InputSet).after(hero_ship::read_hero_ship_keyboard_input).run_if(resource_exists::<NetworkSession>));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkRole {
+    Host,
+    Client
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub enum NetworkStatus {
+    WaitingForPeer(String),
+    Connected,
+    Desynced(u32),
+    Disconnected
+}
+
+impl From<&NetworkSession> for NetworkStatus {
+    fn from(network_session: &NetworkSession) -> Self {
+        match network_session.role {
+            NetworkRole::Host => return Self::WaitingForPeer(format!(
+                "Hosting on port {}, waiting for player two...",
+                network_session.socket.local_addr().map(|address| address.port()).unwrap_or_default()
+            )),
+            NetworkRole::Client => return Self::WaitingForPeer("Connecting to the host...".to_string())
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum NetworkSessionError {
+    #[error("invalid host port \"{0}\"")]
+    InvalidHostPort(String),
+    #[error("invalid host address \"{0}\"")]
+    InvalidHostAddress(String),
+    #[error("could not open the socket: {0}")]
+    Socket(#[from] std::io::Error)
+}
+
+#[derive(Component)]
+pub struct NetworkStatusText;
+
+#[derive(Resource)]
+pub struct NetworkSession {
+    socket: UdpSocket,
+    role: NetworkRole,
+    peer_address: Option<SocketAddr>,
+    game_mode: GameMode,
+    seed: u64,
+    epoch: u8,
+    is_connected: bool,
+    is_epoch_acknowledged: bool,
+    is_simulation_ready: bool,
+    frame: u32,
+    local_inputs: BTreeMap<u32, u8>,
+    remote_inputs: BTreeMap<u32, u8>,
+    local_checksums: BTreeMap<u32, u64>,
+    remote_checksums: BTreeMap<u32, u64>,
+    last_received_at: Instant,
+    last_handshake_sent_at: Instant
+}
+
+impl NetworkSession {
+    fn from_args(args: Vec<String>) -> Result<Option<Self>, NetworkSessionError> {
+        let game_mode: GameMode = if args.iter().any(|arg| arg == "--versus") {
+            GameMode::Versus
+        } else {
+            GameMode::Cooperative
+        };
+        let argument_value = |name: &str| -> Option<String> {
+            return args.iter().position(|arg| arg == name).and_then(|index| args.get(index + 1).cloned());
+        };
+
+        if let Some(port) = argument_value("--host") {
+            let port: u16 = port.parse().map_err(|_| NetworkSessionError::InvalidHostPort(port))?;
+            let socket: UdpSocket = UdpSocket::bind(("0.0.0.0", port))?;
+            return Ok(Some(Self::initialize(socket, NetworkRole::Host, None, game_mode)?));
+        }
+
+        if let Some(address) = argument_value("--join") {
+            let peer_address: SocketAddr = address.parse().map_err(|_| NetworkSessionError::InvalidHostAddress(address))?;
+            let socket: UdpSocket = UdpSocket::bind(("0.0.0.0", 0))?;
+            return Ok(Some(Self::initialize(socket, NetworkRole::Client, Some(peer_address), game_mode)?));
+        }
+        return Ok(None);
+    }
+
+    fn initialize(
+        socket: UdpSocket,
+        role: NetworkRole,
+        peer_address: Option<SocketAddr>,
+        game_mode: GameMode
+    ) -> Result<Self, NetworkSessionError> {
+        socket.set_nonblocking(true)?;
+
+        return Ok(Self {
+            socket,
+            role,
+            peer_address,
+            game_mode,
+            seed: random(),
+            epoch: 0,
+            is_connected: false,
+            is_epoch_acknowledged: false,
+            is_simulation_ready: false,
+            frame: 0,
+            local_inputs: BTreeMap::new(),
+            remote_inputs: BTreeMap::new(),
+            local_checksums: BTreeMap::new(),
+            remote_checksums: BTreeMap::new(),
+            last_received_at: Instant::now(),
+            last_handshake_sent_at: Instant::now()
+        });
+    }
+
+    pub fn local_player_id(&self) -> PlayerId {
+        match self.role {
+            NetworkRole::Host => return PlayerId::One,
+            NetworkRole::Client => return PlayerId::Two
+        }
+    }
+
+    fn reset_frames(
+        &mut self,
+        epoch: u8
+    ) {
+        self.epoch = epoch;
+        self.frame = 0;
+        self.is_simulation_ready = false;
+        self.local_inputs.clear();
+        self.remote_inputs.clear();
+        self.local_checksums.clear();
+        self.remote_checksums.clear();
+
+        for frame in 0..INPUT_DELAY_FRAMES {
+            self.local_inputs.insert(frame, 0);
+            self.remote_inputs.insert(frame, 0);
+        }
+    }
+
+    fn send(
+        &mut self,
+        message: &[u8]
+    ) {
+        if let Some(peer_address) = self.peer_address {
+            let _ = self.socket.send_to(message, peer_address);
+        }
+    }
+
+    fn send_welcome(&mut self) {
+        let mut message: Vec<u8> = vec![WELCOME_MESSAGE, self.epoch, (self.game_mode == GameMode::Versus) as u8];
+        message.extend_from_slice(&self.seed.to_le_bytes());
+        self.send(&message);
+    }
+
+    fn send_restart(&mut self) {
+        let mut message: Vec<u8> = vec![RESTART_MESSAGE, self.epoch];
+        message.extend_from_slice(&self.seed.to_le_bytes());
+        self.send(&message);
+    }
+
+    fn send_inputs(&mut self) {
+        let last_frame: u32 = self.frame + INPUT_DELAY_FRAMES;
+        let first_frame: u32 = last_frame.saturating_sub(INPUT_REDUNDANCY_FRAMES - 1);
+        let mut message: Vec<u8> = vec![INPUT_MESSAGE, self.epoch];
+        message.extend_from_slice(&first_frame.to_le_bytes());
+
+        for frame in first_frame..=last_frame {
+            message.push(self.local_inputs.get(&frame).copied().unwrap_or_default());
+        }
+        self.send(&message);
+    }
+
+    fn send_checksum(
+        &mut self,
+        frame: u32,
+        checksum: u64
+    ) {
+        let mut message: Vec<u8> = vec![CHECKSUM_MESSAGE, self.epoch];
+        message.extend_from_slice(&frame.to_le_bytes());
+        message.extend_from_slice(&checksum.to_le_bytes());
+        self.send(&message);
+    }
+
+    fn find_desynced_frame(&self) -> Option<u32> {
+        return self.remote_checksums.iter()
+            .find(|(frame, remote_checksum)| {
+                self.local_checksums.get(frame).is_some_and(|local_checksum| local_checksum != *remote_checksum)
+            })
+            .map(|(frame, _)| *frame);
+    }
+}
+
+pub fn is_simulation_ready(
+    network_session: Option<Res<NetworkSession>>
+) -> bool {
+    return network_session.is_none_or(|network_session| network_session.is_simulation_ready);
+}
+
+fn read_u32(bytes: &[u8]) -> Option<u32> {
+    return bytes.get(..4).and_then(|bytes| bytes.try_into().ok()).map(u32::from_le_bytes);
+}
+
+fn read_u64(bytes: &[u8]) -> Option<u64> {
+    return bytes.get(..8).and_then(|bytes| bytes.try_into().ok()).map(u64::from_le_bytes);
+}
+
+pub fn receive_network_messages(
+    mut network_session: ResMut<NetworkSession>,
+    mut network_status: ResMut<NetworkStatus>,
+    mut start_game_events: EventWriter<StartGame>,
+    mut restart_game_events: EventWriter<RestartGame>
+) {
+    let mut buffer: [u8; 512] = [0; 512];
+
+    loop {
+        let (length, sender_address) = match network_session.socket.recv_from(&mut buffer) {
+            Ok(received) => received,
+            Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+            Err(_) => continue
+        };
+        let message: &[u8] = &buffer[..length];
+
+        if message.len() < 2 {
+            continue;
+        }
+
+        match network_session.role {
+            NetworkRole::Host if network_session.peer_address.is_none() => {
+                if message[0] != HELLO_MESSAGE {
+                    continue;
+                }
+                network_session.peer_address = Some(sender_address);
+            },
+            _ if network_session.peer_address != Some(sender_address) => continue,
+            _ => {}
+        }
+        network_session.last_received_at = Instant::now();
+
+        match message[0] {
+            HELLO_MESSAGE => {
+                if !network_session.is_connected {
+                    network_session.is_connected = true;
+                    network_session.reset_frames(0);
+                    *network_status = NetworkStatus::Connected;
+                    start_game_events.send(StartGame {
+                        game_mode: network_session.game_mode,
+                        seed: Some(network_session.seed)
+                    });
+                }
+                network_session.send_welcome();
+            },
+            WELCOME_MESSAGE if network_session.role == NetworkRole::Client && !network_session.is_connected => {
+                let Some(seed) = read_u64(&message[3..]) else {
+                    continue;
+                };
+                network_session.game_mode = if message[2] == 1 { GameMode::Versus } else { GameMode::Cooperative };
+                network_session.seed = seed;
+                network_session.is_connected = true;
+                network_session.is_epoch_acknowledged = true;
+                network_session.reset_frames(message[1]);
+                *network_status = NetworkStatus::Connected;
+                start_game_events.send(StartGame {
+                    game_mode: network_session.game_mode,
+                    seed: Some(seed)
+                });
+            },
+            RESTART_MESSAGE if network_session.role == NetworkRole::Client && message[1] != network_session.epoch => {
+                let Some(seed) = read_u64(&message[2..]) else {
+                    continue;
+                };
+                network_session.seed = seed;
+                network_session.reset_frames(message[1]);
+                restart_game_events.send(RestartGame { seed: Some(seed) });
+            },
+            INPUT_MESSAGE if message[1] == network_session.epoch => {
+                let Some(first_frame) = read_u32(&message[2..]) else {
+                    continue;
+                };
+                network_session.is_epoch_acknowledged = true;
+
+                for (offset, input_bits) in message[6..].iter().enumerate() {
+                    network_session.remote_inputs.entry(first_frame + offset as u32).or_insert(*input_bits);
+                }
+            },
+            CHECKSUM_MESSAGE if message[1] == network_session.epoch => {
+                let (Some(frame), Some(checksum)) = (read_u32(&message[2..]), read_u64(&message[6..])) else {
+                    continue;
+                };
+                network_session.remote_checksums.insert(frame, checksum);
+
+                if let Some(desynced_frame) = network_session.find_desynced_frame() {
+                    if *network_status != NetworkStatus::Desynced(desynced_frame) {
+                        error!("Desync detected at frame {} of round {}.", desynced_frame, network_session.epoch);
+                        *network_status = NetworkStatus::Desynced(desynced_frame);
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+}
+
+pub fn send_network_handshake(
+    mut network_session: ResMut<NetworkSession>
+) {
+    if network_session.last_handshake_sent_at.elapsed() < Duration::from_secs_f32(RESEND_INTERVAL_SECONDS) {
+        return;
+    }
+    network_session.last_handshake_sent_at = Instant::now();
+
+    match network_session.role {
+        NetworkRole::Client if !network_session.is_connected => {
+            network_session.send(&[HELLO_MESSAGE, 0]);
+        },
+        NetworkRole::Host if network_session.is_connected && !network_session.is_epoch_acknowledged => {
+            network_session.send_restart();
+        },
+        _ => {}
+    }
+}
+
+pub fn check_for_network_restarting_by_menu(
+    mut menu_action_selected_events: EventReader<MenuActionSelected>,
+    mut network_session: ResMut<NetworkSession>,
+    mut restart_game_events: EventWriter<RestartGame>
+) {
+    let is_restart_selected: bool = menu_action_selected_events.read()
+        .any(|menu_action_selected_event| menu_action_selected_event.action == MenuAction::Restart);
+
+    if network_session.role == NetworkRole::Host && is_restart_selected {
+        let next_epoch: u8 = network_session.epoch.wrapping_add(1);
+        network_session.seed = random();
+        network_session.is_epoch_acknowledged = false;
+        network_session.reset_frames(next_epoch);
+        network_session.send_restart();
+        restart_game_events.send(RestartGame { seed: Some(network_session.seed) });
+    }
+}
+
+pub fn check_for_network_disconnect(
+    mut commands: Commands,
+    network_session: Res<NetworkSession>,
+    mut network_status: ResMut<NetworkStatus>,
+    mut rapier_configuration: ResMut<RapierConfiguration>,
+    mut hero_ship_remaining_lives: ResMut<HeroShipRemainingLives>,
+    mut hero_ship_query: Query<(&PlayerId, &mut Visibility, &mut CollisionGroups, &mut HeroShipInput), With<HeroShip>>
+) {
+    if
+        !network_session.is_connected ||
+        network_session.last_received_at.elapsed() < Duration::from_secs_f32(DISCONNECT_TIMEOUT_SECONDS)
+    {
+        return;
+    }
+    let remote_player_id: PlayerId = network_session.local_player_id().opponent();
+
+    warn!("Lost the connection with the other player, continuing locally.");
+    *network_status = NetworkStatus::Disconnected;
+    rapier_configuration.physics_pipeline_active = true;
+
+    if let Some(lives_remaining) = hero_ship_remaining_lives.lives_remaining.get_mut(&remote_player_id) {
+        *lives_remaining = 0;
+    }
+
+    for (player_id, mut hero_ship_visibility, mut hero_ship_collision_groups, mut hero_ship_input) in &mut hero_ship_query {
+        if *player_id == remote_player_id {
+            *hero_ship_visibility = Visibility::Hidden;
+            *hero_ship_collision_groups = CollisionGroups::new(Group::NONE, Group::NONE);
+            *hero_ship_input = HeroShipInput::default();
+        }
+    }
+    commands.remove_resource::<NetworkSession>();
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn synchronize_network_inputs(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut network_session: ResMut<NetworkSession>,
+    mut rapier_configuration: ResMut<RapierConfiguration>,
+    scoreboard_score: Res<ScoreboardScore>,
+    hero_ship_remaining_lives: Res<HeroShipRemainingLives>,
+    mut hero_ship_query: Query<(&PlayerId, &HeroShip, &Transform, &mut HeroShipInput)>,
+    asteroid_query: Query<&Transform, With<Asteroid>>,
+    projectile_query: Query<&Transform, With<Projectile>>
+) {
+    let local_player_id: PlayerId = network_session.local_player_id();
+    let input_frame: u32 = network_session.frame + INPUT_DELAY_FRAMES;
+    let local_input: HeroShipInput = HeroShipInput::from_keyboard(
+        &keyboard_input,
+        &HeroShipControls::initialize(PlayerId::One, GameMode::SinglePlayer)
+    );
+
+    network_session.local_inputs.entry(input_frame).or_insert(local_input.to_bits());
+    network_session.send_inputs();
+
+    let frame: u32 = network_session.frame;
+    let (Some(local_input_bits), Some(remote_input_bits)) = (
+        network_session.local_inputs.get(&frame).copied(),
+        network_session.remote_inputs.get(&frame).copied()
+    ) else {
+        network_session.is_simulation_ready = false;
+        rapier_configuration.physics_pipeline_active = false;
+        return;
+    };
+
+    for (player_id, _, _, mut hero_ship_input) in &mut hero_ship_query {
+        *hero_ship_input = if *player_id == local_player_id {
+            HeroShipInput::from_bits(local_input_bits)
+        } else {
+            HeroShipInput::from_bits(remote_input_bits)
+        };
+    }
+
+    if frame.is_multiple_of(CHECKSUM_INTERVAL_FRAMES) {
+        let checksum: u64 = calculate_state_checksum(
+            &scoreboard_score,
+            &hero_ship_remaining_lives,
+            &hero_ship_query,
+            &asteroid_query,
+            &projectile_query
+        );
+        network_session.local_checksums.insert(frame, checksum);
+        network_session.send_checksum(frame, checksum);
+    }
+
+    network_session.is_simulation_ready = true;
+    rapier_configuration.physics_pipeline_active = true;
+    network_session.frame += 1;
+
+    let oldest_frame: u32 = network_session.frame.saturating_sub(INPUT_REDUNDANCY_FRAMES);
+    network_session.local_inputs.retain(|frame, _| *frame >= oldest_frame);
+    network_session.remote_inputs.retain(|frame, _| *frame >= oldest_frame);
+
+    let oldest_checksum_frame: u32 = network_session.frame.saturating_sub(CHECKSUM_INTERVAL_FRAMES * 8);
+    network_session.local_checksums.retain(|frame, _| *frame >= oldest_checksum_frame);
+    network_session.remote_checksums.retain(|frame, _| *frame >= oldest_checksum_frame);
+}
+
+fn calculate_state_checksum(
+    scoreboard_score: &ScoreboardScore,
+    hero_ship_remaining_lives: &HeroShipRemainingLives,
+    hero_ship_query: &Query<(&PlayerId, &HeroShip, &Transform, &mut HeroShipInput)>,
+    asteroid_query: &Query<&Transform, With<Asteroid>>,
+    projectile_query: &Query<&Transform, With<Projectile>>
+) -> u64 {
+    let hash_values = |values: &[u64]| -> u64 {
+        return values.iter().fold(0xcbf29ce484222325, |hash: u64, value| {
+            (hash ^ value).wrapping_mul(0x100000001b3)
+        });
+    };
+    let hash_transform = |transform: &Transform| -> u64 {
+        return hash_values(&[
+            transform.translation.x.to_bits() as u64,
+            transform.translation.y.to_bits() as u64,
+            transform.rotation.z.to_bits() as u64,
+            transform.rotation.w.to_bits() as u64
+        ]);
+    };
+    let mut checksum: u64 = 0;
+
+    for player_id in [PlayerId::One, PlayerId::Two] {
+        checksum = checksum.wrapping_add(hash_values(&[
+            player_id as u64,
+            scoreboard_score.get(player_id) as u64,
+            hero_ship_remaining_lives.get(player_id) as u64
+        ]));
+    }
+
+    for (player_id, hero_ship, hero_ship_transform, _) in hero_ship_query {
+        checksum = checksum.wrapping_add(hash_values(&[
+            *player_id as u64,
+            hero_ship.velocity.x.to_bits() as u64,
+            hero_ship.velocity.y.to_bits() as u64,
+            hero_ship.rotation_speed.to_bits() as u64,
+            hash_transform(hero_ship_transform)
+        ]));
+    }
+
+    for asteroid_transform in asteroid_query {
+        checksum = checksum.wrapping_add(hash_transform(asteroid_transform));
+    }
+
+    for projectile_transform in projectile_query {
+        checksum = checksum.wrapping_add(hash_values(&[PROJECTILE_CHECKSUM_SALT, hash_transform(projectile_transform)]));
+    }
+    return checksum;
+}
+
+pub fn spawn_network_status_text(
+    mut commands: Commands
+) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 15.,
+                color: Color::SALMON,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(5.),
+            left: Val::Px(5.),
+            ..default()
+        }),
+        NetworkStatusText
+    ));
+}
+
+pub fn update_network_status_text(
+    network_status: Res<NetworkStatus>,
+    mut network_status_text_query: Query<&mut Text, With<NetworkStatusText>>
+) {
+    if !network_status.is_changed() {
+        return;
+    }
+
+    for mut network_status_text in &mut network_status_text_query {
+        network_status_text.sections[0].value = match network_status.as_ref() {
+            NetworkStatus::WaitingForPeer(message) => message.clone(),
+            NetworkStatus::Connected => "Connected to the other player.".to_string(),
+            NetworkStatus::Desynced(frame) => format!("Desync detected at frame {}!", frame),
+            NetworkStatus::Disconnected => "The other player disconnected, continuing locally.".to_string()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env;
+
+    fn build_loopback_app(network_session: NetworkSession) -> App {
+        let mut app: App = env::build_headless_app();
+
+        app.insert_resource(NetworkStatus::from(&network_session));
+        app.insert_resource(network_session);
+        app.finish();
+        app.cleanup();
+        return app;
+    }
+
+    fn collect_local_checksums(
+        app: &App,
+        checksums: &mut BTreeMap<u32, u64>
+    ) {
+        checksums.extend(app.world.resource::<NetworkSession>().local_checksums.iter());
+    }
+
+    #[test]
+    fn from_args_rejects_malformed_arguments() {
+        let to_args = |args: &[&str]| -> Vec<String> {
+            return args.iter().map(|arg| arg.to_string()).collect();
+        };
+
+        assert!(matches!(
+            NetworkSession::from_args(to_args(&["--host", "not-a-port"])),
+            Err(NetworkSessionError::InvalidHostPort(_))
+        ));
+        assert!(matches!(
+            NetworkSession::from_args(to_args(&["--join", "not-an-address"])),
+            Err(NetworkSessionError::InvalidHostAddress(_))
+        ));
+        assert!(matches!(NetworkSession::from_args(Vec::new()), Ok(None)));
+    }
+
+    #[test]
+    fn loopback_host_and_client_checksums_match() {
+        let host_socket: UdpSocket = UdpSocket::bind(("127.0.0.1", 0)).unwrap();
+        let host_address: SocketAddr = host_socket.local_addr().unwrap();
+        let client_socket: UdpSocket = UdpSocket::bind(("127.0.0.1", 0)).unwrap();
+        let host_session: NetworkSession =
+            NetworkSession::initialize(host_socket, NetworkRole::Host, None, GameMode::Cooperative).unwrap();
+        let mut client_session: NetworkSession =
+            NetworkSession::initialize(client_socket, NetworkRole::Client, Some(host_address), GameMode::Cooperative).unwrap();
+        client_session.last_handshake_sent_at -= Duration::from_secs_f32(RESEND_INTERVAL_SECONDS);
+
+        let mut host_app: App = build_loopback_app(host_session);
+        let mut client_app: App = build_loopback_app(client_session);
+        let mut host_checksums: BTreeMap<u32, u64> = BTreeMap::new();
+        let mut client_checksums: BTreeMap<u32, u64> = BTreeMap::new();
+        let mut has_host_fired_projectiles: bool = false;
+
+        host_app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+        client_app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyW);
+        client_app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+
+        for _ in 0..600 {
+            host_app.update();
+            client_app.update();
+            collect_local_checksums(&host_app, &mut host_checksums);
+            collect_local_checksums(&client_app, &mut client_checksums);
+            has_host_fired_projectiles |= host_app.world.query::<&Projectile>().iter(&host_app.world).count() > 0;
+        }
+
+        let compared_frames: Vec<u32> = host_checksums.keys()
+            .filter(|frame| client_checksums.contains_key(frame))
+            .copied()
+            .collect();
+
+        assert!(compared_frames.len() >= 5, "only {} checksum frames were compared", compared_frames.len());
+        for frame in compared_frames {
+            assert_eq!(host_checksums[&frame], client_checksums[&frame], "checksums differ at frame {}", frame);
+        }
+        assert_eq!(*host_app.world.resource::<NetworkStatus>(), NetworkStatus::Connected);
+        assert_eq!(*client_app.world.resource::<NetworkStatus>(), NetworkStatus::Connected);
+        assert!(has_host_fired_projectiles);
+    }
+}
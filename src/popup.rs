@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use crate::RunScoped;
+
+use crate::constants::popups::{
+    POPUP_SECONDS,
+    POPUP_RISE_SPEED,
+    POPUP_FONT_SIZE,
+    POPUP_Z_POSITION
+};
+
+pub struct PopupPlugin;
+
+impl Plugin for PopupPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.add_event::<ShowPopup>();
+        app.add_systems(Update, (spawn_popups, animate_popups).chain());
+    }
+}
+
+#[derive(Event, Clone)]
+pub struct ShowPopup {
+    pub message: String,
+    pub translation: Vec3,
+    pub color: Color
+}
+
+#[derive(Component, Deref, DerefMut)]
+pub struct Popup(pub Timer);
+
+impl Default for Popup {
+    fn default() -> Self {
+        return Self(Timer::from_seconds(POPUP_SECONDS, TimerMode::Once));
+    }
+}
+
+pub fn spawn_popups(
+    mut commands: Commands,
+    mut show_popup_events: EventReader<ShowPopup>
+) {
+    for show_popup_event in show_popup_events.read() {
+        commands.spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    show_popup_event.message.clone(),
+                    TextStyle {
+                        font_size: POPUP_FONT_SIZE,
+                        color: show_popup_event.color,
+                        ..default()
+                    }
+                ),
+                transform: Transform::from_xyz(show_popup_event.translation.x, show_popup_event.translation.y, POPUP_Z_POSITION),
+                ..default()
+            },
+            Popup::default(),
+            RunScoped
+        ));
+    }
+}
+
+pub fn animate_popups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut popup_query: Query<(Entity, &mut Popup, &mut Transform, &mut Text)>
+) {
+    for (popup_entity, mut popup, mut popup_transform, mut popup_text) in &mut popup_query {
+        popup.tick(time.delta());
+
+        if popup.finished() {
+            commands.entity(popup_entity).despawn_recursive();
+            continue;
+        }
+        popup_transform.translation.y += POPUP_RISE_SPEED * time.delta_seconds();
+
+        for popup_text_section in popup_text.sections.iter_mut() {
+            popup_text_section.style.color.set_a(popup.fraction_remaining());
+        }
+    }
+}
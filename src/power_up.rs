@@ -0,0 +1,539 @@
+use bevy::{
+    prelude::*,
+    math::vec3
+};
+
+use bevy_rapier2d::prelude::*;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use crate::GameRng;
+use crate::GameplaySet;
+use crate::RunScoped;
+use crate::collision;
+use crate::asteroid::AsteroidDestroyed;
+use crate::projectile::ProjectileSpawnTimer;
+use crate::weapon::Weapon;
+use crate::shop::ShipUpgrades;
+
+use crate::config::{
+    GameConfig,
+    PowerUpsConfig
+};
+
+use crate::hero_ship::{
+    HeroShip,
+    HeroShipRemainingLives,
+    PlayerId
+};
+
+use crate::constants::power_ups::{
+    POWER_UP_ROTATION_SPEED,
+    POWER_UP_SPRITE_SIZE,
+    POWER_UP_LABEL_FONT_SIZE,
+    EXTRA_LIFE_MAXIMUM_LIVES
+};
+
+use crate::constants::borders::{
+    RIGHT_BORDER_OFFSCREEN_POSITION,
+    LEFT_BORDER_OFFSCREEN_POSITION,
+    TOP_BORDER_OFFSCREEN_POSITION,
+    BOTTOM_BORDER_OFFSCREEN_POSITION
+};
+
+pub struct PowerUpPlugin;
+
+impl Plugin for PowerUpPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.add_systems(FixedUpdate, (
+            spawn_power_up_drops.after(collision::apply_asteroid_hits),
+            set_power_up_movement_and_rotation,
+            despawn_expired_power_ups,
+            collect_power_ups,
+            tick_active_power_ups
+        ).in_set(GameplaySet));
+    }
+}
+
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerUpKind {
+    #[default]
+    Shield,
+    RapidFire,
+    SpreadShot,
+    ExtraLife
+}
+
+impl PowerUpKind {
+    pub const TIMED: [PowerUpKind; 3] = [PowerUpKind::Shield, PowerUpKind::RapidFire, PowerUpKind::SpreadShot];
+
+    fn get_randomic_power_up_kind(
+        game_rng: &mut GameRng
+    ) -> Self {
+        let randomic_power_up_kind: u32 = game_rng.gen_range(1..=4);
+
+        match randomic_power_up_kind {
+            1 => return Self::Shield,
+            2 => return Self::RapidFire,
+            3 => return Self::SpreadShot,
+            4 => return Self::ExtraLife,
+            _ => panic!("Invalid randomic number.")
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            PowerUpKind::Shield => return Color::CYAN,
+            PowerUpKind::RapidFire => return Color::ORANGE,
+            PowerUpKind::SpreadShot => return Color::VIOLET,
+            PowerUpKind::ExtraLife => return Color::GREEN
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PowerUpKind::Shield => return "S",
+            PowerUpKind::RapidFire => return "R",
+            PowerUpKind::SpreadShot => return "W",
+            PowerUpKind::ExtraLife => return "+"
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerUpKind::Shield => return "Shield",
+            PowerUpKind::RapidFire => return "Rapid Fire",
+            PowerUpKind::SpreadShot => return "Spread Shot",
+            PowerUpKind::ExtraLife => return "Extra Life"
+        }
+    }
+}
+
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
+    pub movement_direction: Vec3
+}
+
+#[derive(Component, Reflect, Default, Deref, DerefMut)]
+#[reflect(Component)]
+pub struct PowerUpLifetimeTimer(pub Timer);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct ActivePowerUps {
+    pub effects: HashMap<PowerUpKind, Timer>,
+    pub spread_shot_projectiles: usize
+}
+
+impl ActivePowerUps {
+    pub fn is_active(
+        &self,
+        power_up_kind: PowerUpKind
+    ) -> bool {
+        return self.effects.contains_key(&power_up_kind);
+    }
+
+    pub fn get_remaining_seconds(
+        &self,
+        power_up_kind: PowerUpKind
+    ) -> Option<f32> {
+        return self.effects.get(&power_up_kind).map(|effect_timer| effect_timer.remaining_secs());
+    }
+
+    pub fn get_projectiles_per_shot(&self) -> usize {
+        if self.is_active(PowerUpKind::SpreadShot) {
+            return self.spread_shot_projectiles;
+        }
+        return 1;
+    }
+
+    pub fn get_cooldown_factor(
+        &self,
+        power_ups_config: &PowerUpsConfig
+    ) -> f32 {
+        if self.is_active(PowerUpKind::RapidFire) {
+            return power_ups_config.rapid_fire_spawn_seconds_factor;
+        }
+        return 1.;
+    }
+
+    pub fn consume_shield(&mut self) -> bool {
+        return self.effects.remove(&PowerUpKind::Shield).is_some();
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+        self.spread_shot_projectiles = 0;
+    }
+
+    fn grant(
+        &mut self,
+        power_up_kind: PowerUpKind,
+        power_ups_config: &PowerUpsConfig
+    ) {
+        let effect_seconds: f32 = match power_up_kind {
+            PowerUpKind::Shield => power_ups_config.shield_seconds,
+            PowerUpKind::RapidFire => power_ups_config.rapid_fire_seconds,
+            PowerUpKind::SpreadShot => power_ups_config.spread_shot_seconds,
+            PowerUpKind::ExtraLife => return
+        };
+
+        if power_up_kind == PowerUpKind::SpreadShot {
+            self.spread_shot_projectiles = if self.is_active(PowerUpKind::SpreadShot) {
+                (self.spread_shot_projectiles + 1).min(power_ups_config.spread_shot_maximum_projectiles)
+            } else {
+                power_ups_config.spread_shot_projectiles
+            };
+        }
+        self.effects.insert(power_up_kind, Timer::from_seconds(effect_seconds, TimerMode::Once));
+    }
+}
+
+pub fn spawn_power_up_drops(
+    mut commands: Commands,
+    mut asteroid_destroyed_events: EventReader<AsteroidDestroyed>,
+    game_config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>
+) {
+    for asteroid_destroyed_event in asteroid_destroyed_events.read() {
+        if game_rng.gen::<f32>() >= game_config.power_ups.drop_chance {
+            continue;
+        }
+
+        spawn_randomic_power_up(
+            commands.reborrow(),
+            &game_config.power_ups,
+            &mut game_rng,
+            asteroid_destroyed_event.position
+        );
+    }
+}
+
+fn spawn_randomic_power_up(
+    commands: Commands,
+    power_ups_config: &PowerUpsConfig,
+    game_rng: &mut GameRng,
+    position: Vec3
+) {
+    let power_up_kind: PowerUpKind = PowerUpKind::get_randomic_power_up_kind(game_rng);
+    let movement_direction: Vec3 = vec3(game_rng.gen_range(-1.0..=1.0), game_rng.gen_range(-1.0..=1.0), 0.).normalize_or_zero();
+
+    spawn_power_up(
+        commands,
+        power_ups_config,
+        PowerUp {
+            kind: power_up_kind,
+            movement_direction
+        },
+        Transform {
+            translation: position,
+            ..default()
+        }
+    );
+}
+
+pub fn spawn_power_up(
+    mut commands: Commands,
+    power_ups_config: &PowerUpsConfig,
+    power_up_struct: PowerUp,
+    power_up_transform: Transform
+) -> Entity {
+    let power_up_kind: PowerUpKind = power_up_struct.kind;
+
+    return commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: power_up_kind.color(),
+                custom_size: Some(Vec2::splat(POWER_UP_SPRITE_SIZE)),
+                ..default()
+            },
+            transform: power_up_transform,
+            ..default()
+        },
+        power_up_struct,
+        PowerUpLifetimeTimer(Timer::from_seconds(power_ups_config.lifetime_seconds, TimerMode::Once))
+    ))
+    .insert(Name::new("Power Up"))
+    .insert(RunScoped)
+    .insert(RigidBody::Dynamic)
+    .insert(Collider::ball(power_ups_config.collider_ball_size))
+    .insert(Sensor)
+    .insert(GravityScale(0.))
+    .insert(CollisionGroups::new(Group::GROUP_2, Group::GROUP_10))
+    .with_children(|parent| {
+        parent.spawn(Text2dBundle {
+            text: Text::from_section(
+                power_up_kind.label(),
+                TextStyle {
+                    font_size: POWER_UP_LABEL_FONT_SIZE,
+                    color: Color::BLACK,
+                    ..default()
+                }
+            ),
+            transform: Transform::from_xyz(0., 0., 1.),
+            ..default()
+        });
+    })
+    .id();
+}
+
+pub fn set_power_up_movement_and_rotation(
+    time: Res<Time>,
+    game_config: Res<GameConfig>,
+    mut power_up_query: Query<(&PowerUp, &mut Transform)>
+) {
+    for (power_up_entity, mut power_up_transform) in &mut power_up_query {
+        power_up_transform.rotate_z(f32::to_radians(POWER_UP_ROTATION_SPEED) * time.delta_seconds());
+        power_up_transform.translation +=
+            power_up_entity.movement_direction * game_config.power_ups.movement_speed * time.delta_seconds();
+    }
+}
+
+pub fn despawn_expired_power_ups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut power_up_query: Query<(Entity, &mut PowerUpLifetimeTimer, &Transform), With<PowerUp>>
+) {
+    for (power_up_entity, mut power_up_lifetime_timer, power_up_transform) in &mut power_up_query {
+        let power_up_position_x: f32 = power_up_transform.translation.x;
+        let power_up_position_y: f32 = power_up_transform.translation.y;
+        power_up_lifetime_timer.tick(time.delta());
+
+        if
+            power_up_lifetime_timer.finished() ||
+            power_up_position_x >= RIGHT_BORDER_OFFSCREEN_POSITION ||
+            power_up_position_x <= LEFT_BORDER_OFFSCREEN_POSITION ||
+            power_up_position_y >= TOP_BORDER_OFFSCREEN_POSITION ||
+            power_up_position_y <= BOTTOM_BORDER_OFFSCREEN_POSITION
+        {
+            commands.entity(power_up_entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn collect_power_ups(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    game_config: Res<GameConfig>,
+    mut hero_ship_remaining_lives: ResMut<HeroShipRemainingLives>,
+    power_up_query: Query<&PowerUp>,
+    mut hero_ship_query: Query<(&PlayerId, &Visibility, &mut ActivePowerUps), With<HeroShip>>
+) {
+    let mut collected_power_up_entities: HashSet<Entity> = HashSet::new();
+
+    for collision_event in collision_events.read() {
+        let CollisionEvent::Started(first_entity, second_entity, _) = *collision_event else {
+            continue;
+        };
+
+        for (power_up_entity, hero_ship_entity) in [(first_entity, second_entity), (second_entity, first_entity)] {
+            if collected_power_up_entities.contains(&power_up_entity) {
+                continue;
+            }
+
+            let (Ok(power_up_component), Ok((player_id, hero_ship_visibility, mut active_power_ups))) =
+                (power_up_query.get(power_up_entity), hero_ship_query.get_mut(hero_ship_entity))
+            else {
+                continue;
+            };
+
+            if *hero_ship_visibility == Visibility::Hidden {
+                continue;
+            }
+
+            if power_up_component.kind == PowerUpKind::ExtraLife {
+                let Some(lives_remaining) = hero_ship_remaining_lives.lives_remaining.get_mut(player_id) else {
+                    continue;
+                };
+
+                if *lives_remaining >= EXTRA_LIFE_MAXIMUM_LIVES {
+                    continue;
+                }
+                *lives_remaining += 1;
+            } else {
+                active_power_ups.grant(power_up_component.kind, &game_config.power_ups);
+            }
+            collected_power_up_entities.insert(power_up_entity);
+            commands.entity(power_up_entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn tick_active_power_ups(
+    time: Res<Time>,
+    game_config: Res<GameConfig>,
+    mut hero_ship_query: Query<(&mut ActivePowerUps, &Weapon, &ShipUpgrades, &mut ProjectileSpawnTimer), With<HeroShip>>
+) {
+    for (mut active_power_ups, weapon, ship_upgrades, mut projectile_spawn_timer) in &mut hero_ship_query {
+        active_power_ups.effects.retain(|_, effect_timer| {
+            effect_timer.tick(time.delta());
+            return !effect_timer.finished();
+        });
+
+        let projectile_spawn_seconds: f32 = weapon.cooldown_seconds(&game_config) *
+            active_power_ups.get_cooldown_factor(&game_config.power_ups) *
+            ship_upgrades.get_fire_rate_factor(&game_config.shop);
+
+        if projectile_spawn_timer.0.duration().as_secs_f32() != projectile_spawn_seconds {
+            projectile_spawn_timer.0.set_duration(Duration::from_secs_f32(projectile_spawn_seconds));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_rapier2d::rapier::geometry::CollisionEventFlags;
+    use crate::env;
+    use crate::GameMode;
+    use crate::StartGame;
+    use crate::asteroid::Asteroid;
+    use crate::projectile::Projectile;
+
+    #[test]
+    fn shield_absorbs_a_single_hit() {
+        let mut active_power_ups: ActivePowerUps = ActivePowerUps::default();
+        active_power_ups.grant(PowerUpKind::Shield, &GameConfig::default().power_ups);
+
+        assert!(active_power_ups.is_active(PowerUpKind::Shield));
+        assert!(active_power_ups.consume_shield());
+        assert!(!active_power_ups.is_active(PowerUpKind::Shield));
+        assert!(!active_power_ups.consume_shield());
+    }
+
+    #[test]
+    fn rapid_fire_shortens_the_projectile_spawn_timer_until_it_runs_out() {
+        let mut game_config: GameConfig = GameConfig::default();
+        game_config.power_ups.rapid_fire_seconds = 0.25;
+        let spawn_seconds: f32 = game_config.projectile.spawn_seconds;
+        let rapid_fire_spawn_seconds: f32 = spawn_seconds * game_config.power_ups.rapid_fire_spawn_seconds_factor;
+
+        let mut active_power_ups: ActivePowerUps = ActivePowerUps::default();
+        active_power_ups.grant(PowerUpKind::RapidFire, &game_config.power_ups);
+        assert_eq!(active_power_ups.get_remaining_seconds(PowerUpKind::RapidFire), Some(0.25));
+
+        let mut app: App = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(1. / 64.)));
+        app.insert_resource(game_config);
+        app.add_systems(Update, tick_active_power_ups);
+
+        let hero_ship_entity: Entity = app.world.spawn((
+            HeroShip::from_config(&GameConfig::default().hero_ship.hulls[0]),
+            active_power_ups,
+            Weapon::Blaster,
+            ShipUpgrades::default(),
+            ProjectileSpawnTimer::default()
+        )).id();
+        let get_spawn_seconds = |app: &App| -> f32 {
+            return app.world.get::<ProjectileSpawnTimer>(hero_ship_entity).unwrap().0.duration().as_secs_f32();
+        };
+
+        app.update();
+        assert_eq!(get_spawn_seconds(&app), rapid_fire_spawn_seconds);
+
+        for _ in 0..16 {
+            app.update();
+        }
+        assert!(!app.world.get::<ActivePowerUps>(hero_ship_entity).unwrap().is_active(PowerUpKind::RapidFire));
+        assert_eq!(get_spawn_seconds(&app), spawn_seconds);
+    }
+
+    #[test]
+    fn spread_shot_grows_by_one_projectile_per_pickup_up_to_the_maximum() {
+        let power_ups_config: PowerUpsConfig = GameConfig::default().power_ups;
+        let mut active_power_ups: ActivePowerUps = ActivePowerUps::default();
+        assert_eq!(active_power_ups.get_projectiles_per_shot(), 1);
+
+        active_power_ups.grant(PowerUpKind::SpreadShot, &power_ups_config);
+        assert_eq!(active_power_ups.get_projectiles_per_shot(), power_ups_config.spread_shot_projectiles);
+
+        for _ in 0..power_ups_config.spread_shot_maximum_projectiles {
+            active_power_ups.grant(PowerUpKind::SpreadShot, &power_ups_config);
+        }
+        assert_eq!(active_power_ups.get_projectiles_per_shot(), power_ups_config.spread_shot_maximum_projectiles);
+    }
+
+    #[test]
+    fn spread_shot_fires_a_fan_of_projectiles() {
+        let mut app: App = env::build_headless_app();
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        app.world.send_event(StartGame { game_mode: GameMode::Cooperative, seed: Some(5) });
+        app.update();
+        app.update();
+
+        let asteroid_entities: Vec<Entity> = app.world.query_filtered::<Entity, With<Asteroid>>().iter(&app.world).collect();
+        for asteroid_entity in asteroid_entities {
+            app.world.despawn(asteroid_entity);
+        }
+        let power_ups_config: PowerUpsConfig = app.world.resource::<GameConfig>().power_ups.clone();
+        let (_, mut active_power_ups) = app.world.query::<(&PlayerId, &mut ActivePowerUps)>().iter_mut(&mut app.world)
+            .find(|(player_id, _)| **player_id == PlayerId::One)
+            .unwrap();
+        active_power_ups.grant(PowerUpKind::SpreadShot, &power_ups_config);
+
+        app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+        for _ in 0..64 {
+            app.update();
+
+            if app.world.query::<&Projectile>().iter(&app.world).count() > 0 {
+                break;
+            }
+        }
+
+        let (_, hero_ship_transform) = app.world.query_filtered::<(&PlayerId, &Transform), With<HeroShip>>().iter(&app.world)
+            .find(|(player_id, _)| **player_id == PlayerId::One)
+            .unwrap();
+        let hero_ship_direction: Vec2 = (hero_ship_transform.rotation * Vec3::Y).truncate();
+        let mut projectile_angles: Vec<f32> = app.world.query::<&Projectile>().iter(&app.world)
+            .map(|projectile_component| f32::to_degrees(hero_ship_direction.angle_between(projectile_component.direction.truncate())))
+            .collect();
+        projectile_angles.sort_by(f32::total_cmp);
+
+        let half_spread_shot_angle: f32 = power_ups_config.spread_shot_angle / 2.;
+        assert_eq!(projectile_angles.len(), power_ups_config.spread_shot_projectiles);
+        assert!((projectile_angles[0] + half_spread_shot_angle).abs() < 1e-3);
+        assert!(projectile_angles[projectile_angles.len() / 2].abs() < 1e-3);
+        assert!((projectile_angles[projectile_angles.len() - 1] - half_spread_shot_angle).abs() < 1e-3);
+    }
+
+    #[test]
+    fn extra_life_is_left_on_the_field_while_the_lives_are_full() {
+        let mut app: App = App::new();
+        app.init_resource::<GameConfig>();
+        app.init_resource::<HeroShipRemainingLives>();
+        app.add_event::<CollisionEvent>();
+        app.add_systems(Update, collect_power_ups);
+
+        let power_up_entity: Entity = app.world.spawn(PowerUp { kind: PowerUpKind::ExtraLife, movement_direction: Vec3::ZERO }).id();
+        let hero_ship_entity: Entity = app.world.spawn((
+            HeroShip::from_config(&GameConfig::default().hero_ship.hulls[0]),
+            PlayerId::One,
+            Visibility::Visible,
+            ActivePowerUps::default()
+        )).id();
+        let touch_power_up = |app: &mut App| {
+            app.world.send_event(CollisionEvent::Started(power_up_entity, hero_ship_entity, CollisionEventFlags::SENSOR));
+            app.update();
+        };
+
+        touch_power_up(&mut app);
+        assert_eq!(app.world.resource::<HeroShipRemainingLives>().get(PlayerId::One), EXTRA_LIFE_MAXIMUM_LIVES);
+        assert!(app.world.get_entity(power_up_entity).is_some());
+
+        app.world.resource_mut::<HeroShipRemainingLives>().lives_remaining.insert(PlayerId::One, EXTRA_LIFE_MAXIMUM_LIVES - 1);
+        touch_power_up(&mut app);
+        assert_eq!(app.world.resource::<HeroShipRemainingLives>().get(PlayerId::One), EXTRA_LIFE_MAXIMUM_LIVES);
+        assert!(app.world.get_entity(power_up_entity).is_none());
+    }
+}
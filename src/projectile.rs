@@ -8,10 +8,30 @@ use bevy_rapier2d::{
     geometry::Collider
 };
 
-use crate::PausingState;
-use crate::GameState;
+use crate::GameplaySet;
+use crate::RunScoped;
+use crate::hero_ship::PlayerId;
 use crate::constants::image_handles::PROJECTILE_HANDLE_IMAGE;
-use crate::constants::projectile_movement_values::PROJECTILE_MOVEMENT_SPEED;
+use crate::config::ProjectileConfig;
+use crate::constants::timers::PROJECTILE_SPAWN_SECONDS;
+
+use crate::constants::projectile_movement_values::{
+    PROJECTILE_MOVEMENT_SPEED,
+    PROJECTILE_COLLIDER_BALL_SIZE,
+    PROJECTILE_PIERCE_COUNT,
+    PROJECTILE_RICOCHET_COUNT
+};
+
+use crate::constants::borders::{
+    RIGHT_BORDER_POSITION,
+    LEFT_BORDER_POSITION,
+    TOP_BORDER_POSITION,
+    BOTTOM_BORDER_POSITION,
+    RIGHT_BORDER_OFFSCREEN_POSITION,
+    LEFT_BORDER_OFFSCREEN_POSITION,
+    TOP_BORDER_OFFSCREEN_POSITION,
+    BOTTOM_BORDER_OFFSCREEN_POSITION
+};
 
 pub struct ProjectilePlugin;
 
@@ -20,18 +40,28 @@ impl Plugin for ProjectilePlugin {
         &self,
         app: &mut App
     ) {
-        app.init_resource::<ProjectileSpawnTimer>();
+        app.add_event::<ProjectileMissed>();
         app.add_systems(FixedUpdate, (
-            set_projectile_movement
-        ).run_if(in_state(PausingState::Running).and_then(in_state(GameState::InGame))));
+            set_projectile_movement,
+            ricochet_projectile_off_borders,
+            despawn_projectile_after_border_outbounds
+        ).chain().in_set(GameplaySet));
     }
 }
 
-#[derive(Component, Debug)]
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component, Default)]
 pub struct Projectile {
     pub translation: Vec3,
     pub direction: Vec3,
-    pub movement_speed: f32
+    pub movement_speed: f32,
+    pub collider_ball_size: f32,
+    #[reflect(ignore)]
+    pub handle_image_path: &'static str,
+    pub pierce_count: u32,
+    pub ricochet_count: u32,
+    pub owner: PlayerId,
+    pub has_hit_asteroid: bool
 }
 
 impl Default for Projectile {
@@ -39,24 +69,49 @@ impl Default for Projectile {
         return Self {
             translation: vec3(0., 0., 0.),
             direction: vec3(0., 0., 0.),
-            movement_speed: PROJECTILE_MOVEMENT_SPEED
+            movement_speed: PROJECTILE_MOVEMENT_SPEED,
+            collider_ball_size: PROJECTILE_COLLIDER_BALL_SIZE,
+            handle_image_path: PROJECTILE_HANDLE_IMAGE,
+            pierce_count: PROJECTILE_PIERCE_COUNT,
+            ricochet_count: PROJECTILE_RICOCHET_COUNT,
+            owner: PlayerId::One,
+            has_hit_asteroid: false
         };
     }
 }
 
 impl Projectile {
+    pub fn from_config(projectile_config: &ProjectileConfig) -> Self {
+        return Self {
+            movement_speed: projectile_config.movement_speed,
+            collider_ball_size: projectile_config.collider_ball_size,
+            pierce_count: projectile_config.pierce_count,
+            ricochet_count: projectile_config.ricochet_count,
+            ..default()
+        };
+    }
+
     pub fn spawn_projectile(
         self,
         mut commands: Commands,
-        asset_server: Res<AssetServer>,
-    ) {
-        let projectile_handle_image: Handle<Image> = asset_server.load(PROJECTILE_HANDLE_IMAGE);
+        asset_server: &Res<AssetServer>
+    ) -> Entity {
+        let projectile_handle_image: Handle<Image> = asset_server.load(self.handle_image_path);
+        let projectile_rotation: Quat = Quat::from_rotation_arc(Vec3::Y, self.direction.try_normalize().unwrap_or(Vec3::Y));
+        let projectile_collider: Collider = Collider::ball(self.collider_ball_size);
+        let projectile_collision_groups: CollisionGroups =
+            CollisionGroups::new(Group::GROUP_10 | self.owner.projectile_collision_group(), Group::all());
 
-        commands.spawn((
+        return commands.spawn((
             SpriteBundle {
+                sprite: Sprite {
+                    color: self.owner.color(),
+                    ..default()
+                },
                 texture: projectile_handle_image,
                 transform: Transform {
                     translation: self.translation,
+                    rotation: projectile_rotation,
                     ..default()
                 },
                 visibility: Visibility::Hidden,
@@ -65,22 +120,30 @@ impl Projectile {
             self
         ))
         .insert(Name::new("Projectile"))
+        .insert(RunScoped)
         .insert(RigidBody::Dynamic)
-        .insert(Collider::ball(3.5))
-        .insert(CollisionGroups::new(Group::GROUP_10, Group::all()))
-        .insert(GravityScale(0.));
+        .insert(projectile_collider)
+        .insert(projectile_collision_groups)
+        .insert(GravityScale(0.))
+        .id();
     }
 }
 
-#[derive(Resource)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 pub struct ProjectileSpawnTimer(pub Timer);
 
 impl Default for ProjectileSpawnTimer {
     fn default() -> Self {
-        return Self(Timer::from_seconds(0.2, TimerMode::Repeating));
+        return Self(Timer::from_seconds(PROJECTILE_SPAWN_SECONDS, TimerMode::Repeating));
     }
 }
 
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ProjectileMissed {
+    pub owner: PlayerId
+}
+
 #[derive(Resource, Default, Deref, DerefMut)]
 pub struct ProjectileSpawnSound(pub Handle<AudioSource>);
 
@@ -96,3 +159,129 @@ pub fn set_projectile_movement(
         *projectile_visibility = Visibility::Visible;
     }
 }
+
+pub fn ricochet_projectile_off_borders(
+    mut projectile_query: Query<(&mut Projectile, &mut Transform)>
+) {
+    for (mut projectile_entity, mut projectile_transform) in &mut projectile_query {
+        if projectile_entity.ricochet_count == 0 {
+            continue;
+        }
+
+        let projectile_position: Vec3 = projectile_transform.translation;
+        let mut projectile_direction: Vec3 = projectile_entity.direction;
+
+        if
+            (projectile_position.x >= RIGHT_BORDER_POSITION && projectile_direction.x > 0.) ||
+            (projectile_position.x <= LEFT_BORDER_POSITION && projectile_direction.x < 0.)
+        {
+            projectile_direction.x = -projectile_direction.x;
+        }
+
+        if
+            (projectile_position.y >= TOP_BORDER_POSITION && projectile_direction.y > 0.) ||
+            (projectile_position.y <= BOTTOM_BORDER_POSITION && projectile_direction.y < 0.)
+        {
+            projectile_direction.y = -projectile_direction.y;
+        }
+
+        if projectile_direction != projectile_entity.direction {
+            projectile_entity.direction = projectile_direction;
+            projectile_entity.ricochet_count -= 1;
+            projectile_transform.rotation = Quat::from_rotation_arc(Vec3::Y, projectile_direction.normalize_or_zero());
+        }
+    }
+}
+
+pub fn despawn_projectile_after_border_outbounds(
+    mut commands: Commands,
+    mut projectile_missed_events: EventWriter<ProjectileMissed>,
+    projectile_query: Query<(Entity, &Projectile, &Transform)>
+) {
+    for (projectile_entity, projectile_component, projectile_transform) in &projectile_query {
+        let projectile_position_x: f32 = projectile_transform.translation.x;
+        let projectile_position_y: f32 = projectile_transform.translation.y;
+
+        if
+            projectile_position_x >= RIGHT_BORDER_OFFSCREEN_POSITION ||
+            projectile_position_x <= LEFT_BORDER_OFFSCREEN_POSITION ||
+            projectile_position_y >= TOP_BORDER_OFFSCREEN_POSITION ||
+            projectile_position_y <= BOTTOM_BORDER_OFFSCREEN_POSITION
+        {
+            commands.entity(projectile_entity).despawn_recursive();
+
+            if !projectile_component.has_hit_asteroid {
+                projectile_missed_events.send(ProjectileMissed { owner: projectile_component.owner });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ricochet_projectile(
+        translation: Vec3,
+        direction: Vec3,
+        ricochet_count: u32
+    ) -> (Vec3, u32, Quat) {
+        let mut app: App = App::new();
+        app.add_systems(Update, ricochet_projectile_off_borders);
+
+        let projectile_entity: Entity = app.world.spawn((
+            Projectile { direction, ricochet_count, ..default() },
+            Transform::from_translation(translation)
+        )).id();
+        app.update();
+
+        let projectile_component: &Projectile = app.world.get::<Projectile>(projectile_entity).unwrap();
+
+        return (
+            projectile_component.direction,
+            projectile_component.ricochet_count,
+            app.world.get::<Transform>(projectile_entity).unwrap().rotation
+        );
+    }
+
+    #[test]
+    fn projectile_ricochets_off_each_border() {
+        let bounces: [(Vec3, Vec3, Vec3); 4] = [
+            (Vec3::new(RIGHT_BORDER_POSITION, 0., 0.), Vec3::new(1., 0.5, 0.), Vec3::new(-1., 0.5, 0.)),
+            (Vec3::new(LEFT_BORDER_POSITION, 0., 0.), Vec3::new(-1., 0.5, 0.), Vec3::new(1., 0.5, 0.)),
+            (Vec3::new(0., TOP_BORDER_POSITION, 0.), Vec3::new(0.5, 1., 0.), Vec3::new(0.5, -1., 0.)),
+            (Vec3::new(0., BOTTOM_BORDER_POSITION, 0.), Vec3::new(0.5, -1., 0.), Vec3::new(0.5, 1., 0.))
+        ];
+
+        for (translation, direction, ricocheted_direction) in bounces {
+            let (projectile_direction, ricochet_count, projectile_rotation) = ricochet_projectile(translation, direction, 2);
+
+            assert_eq!(projectile_direction, ricocheted_direction);
+            assert_eq!(ricochet_count, 1);
+            assert!((projectile_rotation * Vec3::Y).abs_diff_eq(ricocheted_direction.normalize(), 1e-5));
+        }
+    }
+
+    #[test]
+    fn projectile_ricochets_off_a_corner_as_a_single_bounce() {
+        let (projectile_direction, ricochet_count, _) = ricochet_projectile(
+            Vec3::new(RIGHT_BORDER_POSITION, TOP_BORDER_POSITION, 0.),
+            Vec3::new(1., 1., 0.),
+            2
+        );
+
+        assert_eq!(projectile_direction, Vec3::new(-1., -1., 0.));
+        assert_eq!(ricochet_count, 1);
+    }
+
+    #[test]
+    fn projectile_does_not_ricochet_when_heading_away_or_out_of_ricochets() {
+        let heading_away_projectile: (Vec3, u32, Quat) =
+            ricochet_projectile(Vec3::new(RIGHT_BORDER_POSITION, 0., 0.), Vec3::new(-1., 0., 0.), 2);
+        let spent_projectile: (Vec3, u32, Quat) =
+            ricochet_projectile(Vec3::new(RIGHT_BORDER_POSITION, 0., 0.), Vec3::new(1., 0., 0.), 0);
+
+        assert_eq!((heading_away_projectile.0, heading_away_projectile.1), (Vec3::new(-1., 0., 0.), 2));
+        assert_eq!((spent_projectile.0, spent_projectile.1), (Vec3::new(1., 0., 0.), 0));
+    }
+}
@@ -0,0 +1,658 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{CollisionGroups, Group};
+use serde::de::DeserializeSeed;
+use thiserror::Error;
+use std::path::Path;
+use std::collections::{HashMap, VecDeque};
+
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng
+};
+
+use bevy::time::Stopwatch;
+
+use bevy::ecs::{
+    entity::EntityHashMap,
+    system::RunSystemOnce
+};
+
+use bevy::scene::{
+    DynamicScene,
+    DynamicSceneBuilder,
+    SceneSpawnError,
+    serde::SceneDeserializer
+};
+
+use crate::GameState;
+use crate::GameMode;
+use crate::GameRng;
+use crate::StartGame;
+use crate::BackgroundMusic;
+use crate::RunScoped;
+use crate::statistics::{self, RunStatistics};
+use crate::menu::{self, MenuAction, MenuActionSelected};
+use crate::ui::{ScoreboardScore, ShowToast};
+use crate::ship_select::SelectedHulls;
+use crate::shop::{ShipUpgrades, UpgradeKind};
+use crate::weapon::{Weapon, WeaponState};
+use crate::projectile::{Projectile, ProjectileSpawnTimer};
+use crate::combo::ScoreCombo;
+use crate::energy_shield::EnergyShield;
+use crate::config::{GameConfig, HullConfig};
+use crate::network::NetworkSession;
+use crate::achievements::AchievementProgress;
+
+use crate::asteroid::{
+    self,
+    Asteroid,
+    Health,
+    AsteroidCheckForTypesTimer
+};
+
+use crate::power_up::{
+    self,
+    PowerUp,
+    PowerUpKind,
+    PowerUpLifetimeTimer,
+    ActivePowerUps
+};
+
+use crate::hero_ship::{
+    self,
+    HeroShip,
+    PlayerId,
+    HeroShipRemainingLives,
+    HeroShipRespawnTimer,
+    HeroShipStillAliveTimer
+};
+
+use crate::constants::save::SAVED_RUN_FILE_PATH;
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.register_type::<HeroShip>();
+        app.register_type::<PlayerId>();
+        app.register_type::<Weapon>();
+        app.register_type::<ShipUpgrades>();
+        app.register_type::<UpgradeKind>();
+        app.register_type::<Asteroid>();
+        app.register_type::<Health>();
+        app.register_type::<Projectile>();
+        app.register_type::<HeroShipRespawnTimer>();
+        app.register_type::<ProjectileSpawnTimer>();
+        app.register_type::<WeaponState>();
+        app.register_type::<Visibility>();
+        app.register_type::<CollisionGroups>();
+        app.register_type::<Group>();
+        app.register_type::<ActivePowerUps>();
+        app.register_type::<ScoreCombo>();
+        app.register_type::<EnergyShield>();
+        app.register_type::<PowerUp>();
+        app.register_type::<PowerUpKind>();
+        app.register_type::<PowerUpLifetimeTimer>();
+        app.register_type::<SavedRunRngSeed>();
+        app.register_type::<HashMap<PlayerId, usize>>();
+        app.register_type::<HashMap<PlayerId, String>>();
+        app.register_type::<HashMap<String, usize>>();
+        app.register_type::<HashMap<UpgradeKind, u32>>();
+        app.register_type::<HashMap<PowerUpKind, Timer>>();
+        app.register_type::<VecDeque<f32>>();
+        app.register_type::<Timer>();
+        app.register_type::<TimerMode>();
+        app.register_type::<Stopwatch>();
+        app.register_type::<ScoreboardScore>();
+        app.register_type::<HeroShipRemainingLives>();
+        app.register_type::<HeroShipStillAliveTimer>();
+        app.register_type::<AsteroidCheckForTypesTimer>();
+        app.register_type::<RunStatistics>();
+        app.register_type::<AchievementProgress>();
+        app.register_type::<SelectedHulls>();
+        app.add_systems(OnTransition { from: GameState::InGame, to: GameState::StartScreen }, (
+            save_run.before(crate::reset_after_quitting_to_title)
+        ).run_if(statistics::is_player_run.and_then(resource_equals(GameMode::SinglePlayer))));
+        app.add_systems(Update, (
+            check_for_continuing_by_menu.after(menu::navigate_menus)
+        ).run_if(in_state(GameState::StartScreen).and_then(not(resource_exists::<NetworkSession>))));
+        app.add_systems(Update, delete_saved_run_on_new_game.run_if(statistics::is_player_run));
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SavedRunLoadError {
+    #[error("could not read the saved run: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse the saved run: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("could not deserialize the saved run: {0}")]
+    Deserialize(#[from] ron::Error),
+    #[error("could not restore the saved run: {0}")]
+    Scene(#[from] SceneSpawnError)
+}
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct SavedRunRngSeed(u64);
+
+#[derive(Bundle)]
+struct SavedHeroShip {
+    player_id: PlayerId,
+    hero_ship: HeroShip,
+    transform: Transform,
+    visibility: Visibility,
+    collision_groups: CollisionGroups,
+    respawn_timer: HeroShipRespawnTimer,
+    projectile_spawn_timer: ProjectileSpawnTimer,
+    weapon: Weapon,
+    weapon_state: WeaponState,
+    ship_upgrades: ShipUpgrades,
+    active_power_ups: ActivePowerUps,
+    score_combo: ScoreCombo,
+    energy_shield: EnergyShield
+}
+
+struct SavedRun {
+    game_rng_seed: u64,
+    selected_hulls: SelectedHulls,
+    scoreboard_score: ScoreboardScore,
+    hero_ship_remaining_lives: HeroShipRemainingLives,
+    hero_ship_still_alive_timer: HeroShipStillAliveTimer,
+    asteroid_check_for_types_timer: AsteroidCheckForTypesTimer,
+    run_statistics: RunStatistics,
+    hero_ships: Vec<SavedHeroShip>,
+    asteroids: Vec<(Asteroid, Transform, Option<Health>)>,
+    projectiles: Vec<(Projectile, Transform)>,
+    power_ups: Vec<(PowerUp, PowerUpLifetimeTimer, Transform)>
+}
+
+impl SavedRun {
+    fn take_from_world(saved_run_world: &mut World) -> Self {
+        let hero_ship_entities: Vec<Entity> =
+            saved_run_world.query_filtered::<Entity, With<HeroShip>>().iter(saved_run_world).collect();
+        let asteroid_entities: Vec<Entity> =
+            saved_run_world.query_filtered::<Entity, With<Asteroid>>().iter(saved_run_world).collect();
+        let projectile_entities: Vec<Entity> =
+            saved_run_world.query_filtered::<Entity, With<Projectile>>().iter(saved_run_world).collect();
+        let power_up_entities: Vec<Entity> =
+            saved_run_world.query_filtered::<Entity, With<PowerUp>>().iter(saved_run_world).collect();
+
+        return Self {
+            game_rng_seed: saved_run_world.remove_resource::<SavedRunRngSeed>().unwrap_or_default().0,
+            selected_hulls: saved_run_world.remove_resource().unwrap_or_default(),
+            scoreboard_score: saved_run_world.remove_resource().unwrap_or_default(),
+            hero_ship_remaining_lives: saved_run_world.remove_resource().unwrap_or_default(),
+            hero_ship_still_alive_timer: saved_run_world.remove_resource().unwrap_or_default(),
+            asteroid_check_for_types_timer: saved_run_world.remove_resource().unwrap_or_default(),
+            run_statistics: saved_run_world.remove_resource().unwrap_or_default(),
+            hero_ships: hero_ship_entities.into_iter()
+                .filter_map(|hero_ship_entity| saved_run_world.entity_mut(hero_ship_entity).take())
+                .collect(),
+            asteroids: asteroid_entities.into_iter()
+                .filter_map(|asteroid_entity| {
+                    let mut asteroid_entity_mut = saved_run_world.entity_mut(asteroid_entity);
+                    let asteroid_health: Option<Health> = asteroid_entity_mut.take::<Health>();
+
+                    return asteroid_entity_mut.take::<(Asteroid, Transform)>()
+                        .map(|(asteroid, asteroid_transform)| (asteroid, asteroid_transform, asteroid_health));
+                })
+                .collect(),
+            projectiles: projectile_entities.into_iter()
+                .filter_map(|projectile_entity| saved_run_world.entity_mut(projectile_entity).take())
+                .collect(),
+            power_ups: power_up_entities.into_iter()
+                .filter_map(|power_up_entity| saved_run_world.entity_mut(power_up_entity).take())
+                .collect()
+        };
+    }
+}
+
+pub fn has_saved_run() -> bool {
+    return Path::new(SAVED_RUN_FILE_PATH).exists();
+}
+
+fn delete_saved_run() {
+    if !has_saved_run() {
+        return;
+    }
+
+    if let Err(file_error) = std::fs::remove_file(SAVED_RUN_FILE_PATH) {
+        warn!("Could not delete {}: {}", SAVED_RUN_FILE_PATH, file_error);
+    }
+}
+
+fn serialize_run(world: &mut World) -> Result<String, ron::Error> {
+    let game_rng_seed: u64 = world.resource_mut::<GameRng>().gen();
+    let run_entities: Vec<Entity> = world
+        .query_filtered::<Entity, (With<RunScoped>, Or<(With<HeroShip>, With<Asteroid>, With<Projectile>, With<PowerUp>)>)>()
+        .iter(world)
+        .collect();
+
+    world.insert_resource(SavedRunRngSeed(game_rng_seed));
+
+    let saved_run_scene: DynamicScene = DynamicSceneBuilder::from_world(world)
+        .deny_all()
+        .allow::<Transform>()
+        .allow::<PlayerId>()
+        .allow::<HeroShip>()
+        .allow::<Visibility>()
+        .allow::<CollisionGroups>()
+        .allow::<HeroShipRespawnTimer>()
+        .allow::<ProjectileSpawnTimer>()
+        .allow::<Weapon>()
+        .allow::<WeaponState>()
+        .allow::<ShipUpgrades>()
+        .allow::<Asteroid>()
+        .allow::<Health>()
+        .allow::<Projectile>()
+        .allow::<ActivePowerUps>()
+        .allow::<ScoreCombo>()
+        .allow::<EnergyShield>()
+        .allow::<PowerUp>()
+        .allow::<PowerUpLifetimeTimer>()
+        .extract_entities(run_entities.into_iter())
+        .deny_all_resources()
+        .allow_resource::<SelectedHulls>()
+        .allow_resource::<ScoreboardScore>()
+        .allow_resource::<HeroShipRemainingLives>()
+        .allow_resource::<HeroShipStillAliveTimer>()
+        .allow_resource::<AsteroidCheckForTypesTimer>()
+        .allow_resource::<RunStatistics>()
+        .allow_resource::<SavedRunRngSeed>()
+        .extract_resources()
+        .build();
+
+    world.remove_resource::<SavedRunRngSeed>();
+    return saved_run_scene.serialize_ron(world.resource::<AppTypeRegistry>());
+}
+
+fn save_run(world: &mut World) {
+    let saved_run_text: String = match serialize_run(world) {
+        Ok(saved_run_text) => saved_run_text,
+        Err(saved_run_error) => {
+            warn!("Could not serialize {}: {}", SAVED_RUN_FILE_PATH, saved_run_error);
+            return;
+        }
+    };
+
+    if let Some(saved_run_directory) = Path::new(SAVED_RUN_FILE_PATH).parent() {
+        if let Err(directory_error) = std::fs::create_dir_all(saved_run_directory) {
+            warn!("Could not create the directory {:?}: {}", saved_run_directory, directory_error);
+            return;
+        }
+    }
+
+    if let Err(file_error) = std::fs::write(SAVED_RUN_FILE_PATH, saved_run_text) {
+        warn!("Could not save {}: {}", SAVED_RUN_FILE_PATH, file_error);
+    }
+}
+
+fn deserialize_saved_run(
+    saved_run_text: &str,
+    type_registry: AppTypeRegistry
+) -> Result<SavedRun, SavedRunLoadError> {
+    let mut ron_deserializer = ron::de::Deserializer::from_str(saved_run_text)?;
+    let saved_run_scene: DynamicScene =
+        SceneDeserializer { type_registry: &type_registry.read() }.deserialize(&mut ron_deserializer)?;
+
+    let mut saved_run_world: World = World::new();
+    saved_run_world.insert_resource(type_registry);
+    saved_run_scene.write_to_world(&mut saved_run_world, &mut EntityHashMap::default())?;
+
+    return Ok(SavedRun::take_from_world(&mut saved_run_world));
+}
+
+fn load_saved_run(world: &mut World) -> Result<SavedRun, SavedRunLoadError> {
+    let saved_run_text: String = std::fs::read_to_string(SAVED_RUN_FILE_PATH)?;
+
+    return deserialize_saved_run(&saved_run_text, world.resource::<AppTypeRegistry>().clone());
+}
+
+fn continue_saved_run(world: &mut World) {
+    match load_saved_run(world) {
+        Ok(saved_run) => world.run_system_once_with(saved_run, restore_saved_run),
+        Err(saved_run_error) => {
+            warn!("Could not load {}: {}", SAVED_RUN_FILE_PATH, saved_run_error);
+            world.send_event(ShowToast {
+                message: "The saved run could not be loaded.".to_string(),
+                color: Color::RED
+            });
+        }
+    }
+
+    delete_saved_run();
+}
+
+#[allow(clippy::too_many_arguments)]
+fn restore_saved_run(
+    In(saved_run): In<SavedRun>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    background_music: Res<BackgroundMusic>,
+    game_config: Res<GameConfig>,
+    mut game_mode: ResMut<GameMode>,
+    mut game_rng: ResMut<GameRng>,
+    mut next_state: ResMut<NextState<GameState>>
+) {
+    *game_mode = GameMode::SinglePlayer;
+    game_rng.0 = StdRng::seed_from_u64(saved_run.game_rng_seed);
+    crate::reset_run(&mut commands);
+
+    for saved_hero_ship in saved_run.hero_ships {
+        let player_id: PlayerId = saved_hero_ship.player_id;
+        let hull_config: &HullConfig = saved_run.selected_hulls.get_hull(player_id, &game_config.hero_ship);
+        let hero_ship_entity: Entity =
+            hero_ship::spawn_hero_ship(commands.reborrow(), &asset_server, &game_config, *game_mode, player_id, hull_config);
+
+        commands.entity(hero_ship_entity).insert(saved_hero_ship);
+    }
+
+    for (mut asteroid, asteroid_transform, asteroid_health) in saved_run.asteroids {
+        asteroid.apply_config(&asset_server, &game_config.asteroids);
+        let asteroid_entity: Entity = asteroid::spawn_asteroid(commands.reborrow(), asteroid, asteroid_transform);
+
+        if let Some(asteroid_health) = asteroid_health {
+            commands.entity(asteroid_entity).insert(asteroid_health);
+        }
+    }
+
+    for (projectile, projectile_transform) in saved_run.projectiles {
+        let projectile_entity: Entity = projectile.spawn_projectile(commands.reborrow(), &asset_server);
+
+        commands.entity(projectile_entity).insert(projectile_transform);
+    }
+
+    for (power_up, power_up_lifetime_timer, power_up_transform) in saved_run.power_ups {
+        let power_up_entity: Entity =
+            power_up::spawn_power_up(commands.reborrow(), &game_config.power_ups, power_up, power_up_transform);
+
+        commands.entity(power_up_entity).insert(power_up_lifetime_timer);
+    }
+
+    commands.insert_resource(saved_run.selected_hulls);
+    commands.insert_resource(saved_run.scoreboard_score);
+    commands.insert_resource(saved_run.hero_ship_remaining_lives);
+    commands.insert_resource(saved_run.hero_ship_still_alive_timer);
+    commands.insert_resource(saved_run.asteroid_check_for_types_timer);
+    commands.insert_resource(saved_run.run_statistics);
+
+    hero_ship::spawn_hero_ships_lives(commands.reborrow(), &asset_server, *game_mode);
+    commands.spawn(AudioBundle {
+        source: background_music.clone(),
+        settings: PlaybackSettings::LOOP
+    });
+    next_state.set(GameState::InGame);
+}
+
+fn check_for_continuing_by_menu(
+    mut commands: Commands,
+    mut menu_action_selected_events: EventReader<MenuActionSelected>
+) {
+    if menu_action_selected_events.read().any(|menu_action_selected_event| menu_action_selected_event.action == MenuAction::Continue) {
+        commands.add(continue_saved_run);
+    }
+}
+
+fn delete_saved_run_on_new_game(
+    mut start_game_events: EventReader<StartGame>
+) {
+    if start_game_events.read().count() > 0 {
+        delete_saved_run();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use crate::StartGame;
+    use crate::env;
+
+    fn build_started_run_app() -> App {
+        let mut app: App = env::build_headless_app();
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        app.world.send_event(StartGame { game_mode: GameMode::SinglePlayer, seed: Some(5) });
+        app.update();
+        app.update();
+
+        app.world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+        for _ in 0..20 {
+            app.update();
+        }
+        app.world.resource_mut::<ButtonInput<KeyCode>>().reset_all();
+        return app;
+    }
+
+    fn set_up_run_state(world: &mut World) {
+        world.resource_mut::<ScoreboardScore>().increase(PlayerId::One, 1234);
+        world.resource_mut::<HeroShipRemainingLives>().lives_remaining.insert(PlayerId::One, 2);
+        world.resource_mut::<HeroShipStillAliveTimer>().set_elapsed(Duration::from_secs_f32(1.5));
+        world.resource_mut::<AsteroidCheckForTypesTimer>().set_elapsed(Duration::from_secs_f32(0.25));
+
+        let (mut active_power_ups, mut score_combo, mut energy_shield) = world
+            .query_filtered::<(&mut ActivePowerUps, &mut ScoreCombo, &mut EnergyShield), With<HeroShip>>()
+            .single_mut(world);
+        active_power_ups.effects.insert(PowerUpKind::RapidFire, Timer::from_seconds(4., TimerMode::Once));
+        score_combo.kills = 4;
+        score_combo.window_seconds_remaining = 1.25;
+        energy_shield.energy = 17.5;
+
+        set_up_hero_ship_mid_respawn(world);
+
+        let asteroid_entity: Entity = world.query_filtered::<Entity, With<Asteroid>>().iter(world).next().unwrap();
+        world.entity_mut(asteroid_entity).insert(Health { hit_points: 2, maximum_hit_points: 3 });
+
+        let power_ups_config = world.resource::<GameConfig>().power_ups.clone();
+        let mut power_up_lifetime_timer: PowerUpLifetimeTimer =
+            PowerUpLifetimeTimer(Timer::from_seconds(power_ups_config.lifetime_seconds, TimerMode::Once));
+        power_up_lifetime_timer.set_elapsed(Duration::from_secs_f32(2.));
+
+        world.spawn((
+            PowerUp { kind: PowerUpKind::SpreadShot, movement_direction: Vec3::X },
+            power_up_lifetime_timer,
+            Transform::from_xyz(10., 20., 0.),
+            RunScoped
+        ));
+    }
+
+    fn set_up_hero_ship_mid_respawn(world: &mut World) {
+        let (
+            mut hero_ship_transform,
+            mut hero_ship_visibility,
+            mut hero_ship_collision_groups,
+            mut hero_ship_respawn_timer,
+            mut projectile_spawn_timer,
+            mut weapon_state
+        ) = world
+            .query_filtered::<(
+                &mut Transform,
+                &mut Visibility,
+                &mut CollisionGroups,
+                &mut HeroShipRespawnTimer,
+                &mut ProjectileSpawnTimer,
+                &mut WeaponState
+            ), With<HeroShip>>()
+            .single_mut(world);
+
+        hero_ship_transform.translation = Vec3::new(-50., 60., 0.);
+        *hero_ship_visibility = Visibility::Hidden;
+        *hero_ship_collision_groups = CollisionGroups::new(Group::GROUP_1, Group::all());
+        hero_ship_respawn_timer.set_elapsed(Duration::from_secs_f32(0.75));
+        projectile_spawn_timer.0.set_elapsed(Duration::from_secs_f32(0.125));
+        weapon_state.charge_seconds = 0.5;
+        weapon_state.charged_shot_cooldown_seconds = 0.25;
+    }
+
+    fn get_type_registry(world: &World) -> AppTypeRegistry {
+        return world.resource::<AppTypeRegistry>().clone();
+    }
+
+    fn assert_reflect_eq(
+        first: &dyn Reflect,
+        second: &dyn Reflect
+    ) {
+        assert_eq!(first.reflect_partial_eq(second), Some(true), "{:?} != {:?}", first, second);
+    }
+
+    fn sort_by_translation<T>(
+        saved_entities: &mut [T],
+        get_transform: impl Fn(&T) -> &Transform
+    ) {
+        saved_entities.sort_by(|first, second| {
+            let first_translation: Vec3 = get_transform(first).translation;
+            let second_translation: Vec3 = get_transform(second).translation;
+
+            return first_translation.x.total_cmp(&second_translation.x).then(first_translation.y.total_cmp(&second_translation.y));
+        });
+    }
+
+    fn assert_saved_runs_eq(
+        mut first_saved_run: SavedRun,
+        mut second_saved_run: SavedRun
+    ) {
+        assert_reflect_eq(&first_saved_run.selected_hulls, &second_saved_run.selected_hulls);
+        assert_reflect_eq(&first_saved_run.scoreboard_score, &second_saved_run.scoreboard_score);
+        assert_reflect_eq(&first_saved_run.hero_ship_remaining_lives, &second_saved_run.hero_ship_remaining_lives);
+        assert_reflect_eq(&first_saved_run.hero_ship_still_alive_timer, &second_saved_run.hero_ship_still_alive_timer);
+        assert_reflect_eq(&first_saved_run.asteroid_check_for_types_timer, &second_saved_run.asteroid_check_for_types_timer);
+        assert_reflect_eq(&first_saved_run.run_statistics, &second_saved_run.run_statistics);
+
+        assert_eq!(first_saved_run.hero_ships.len(), second_saved_run.hero_ships.len());
+        for (first_hero_ship, second_hero_ship) in first_saved_run.hero_ships.iter().zip(&second_saved_run.hero_ships) {
+            assert_reflect_eq(&first_hero_ship.player_id, &second_hero_ship.player_id);
+            assert_reflect_eq(&first_hero_ship.hero_ship, &second_hero_ship.hero_ship);
+            assert_reflect_eq(&first_hero_ship.transform, &second_hero_ship.transform);
+            assert_reflect_eq(&first_hero_ship.visibility, &second_hero_ship.visibility);
+            assert_reflect_eq(&first_hero_ship.collision_groups, &second_hero_ship.collision_groups);
+            assert_reflect_eq(&first_hero_ship.respawn_timer, &second_hero_ship.respawn_timer);
+            assert_reflect_eq(&first_hero_ship.projectile_spawn_timer, &second_hero_ship.projectile_spawn_timer);
+            assert_reflect_eq(&first_hero_ship.weapon, &second_hero_ship.weapon);
+            assert_reflect_eq(&first_hero_ship.weapon_state, &second_hero_ship.weapon_state);
+            assert_reflect_eq(&first_hero_ship.ship_upgrades, &second_hero_ship.ship_upgrades);
+            assert_reflect_eq(&first_hero_ship.active_power_ups, &second_hero_ship.active_power_ups);
+            assert_reflect_eq(&first_hero_ship.score_combo, &second_hero_ship.score_combo);
+            assert_reflect_eq(&first_hero_ship.energy_shield, &second_hero_ship.energy_shield);
+        }
+
+        sort_by_translation(&mut first_saved_run.asteroids, |asteroid| &asteroid.1);
+        sort_by_translation(&mut second_saved_run.asteroids, |asteroid| &asteroid.1);
+        assert_eq!(first_saved_run.asteroids.len(), second_saved_run.asteroids.len());
+        for (first_asteroid, second_asteroid) in first_saved_run.asteroids.iter().zip(&second_saved_run.asteroids) {
+            assert_reflect_eq(&first_asteroid.0, &second_asteroid.0);
+            assert_reflect_eq(&first_asteroid.1, &second_asteroid.1);
+            assert_eq!(first_asteroid.2.map(|health| health.hit_points), second_asteroid.2.map(|health| health.hit_points));
+        }
+
+        sort_by_translation(&mut first_saved_run.projectiles, |projectile| &projectile.1);
+        sort_by_translation(&mut second_saved_run.projectiles, |projectile| &projectile.1);
+        assert_eq!(first_saved_run.projectiles.len(), second_saved_run.projectiles.len());
+        for (first_projectile, second_projectile) in first_saved_run.projectiles.iter().zip(&second_saved_run.projectiles) {
+            assert_reflect_eq(&first_projectile.0, &second_projectile.0);
+            assert_reflect_eq(&first_projectile.1, &second_projectile.1);
+        }
+
+        assert_eq!(first_saved_run.power_ups.len(), second_saved_run.power_ups.len());
+        for (first_power_up, second_power_up) in first_saved_run.power_ups.iter().zip(&second_saved_run.power_ups) {
+            assert_reflect_eq(&first_power_up.0, &second_power_up.0);
+            assert_reflect_eq(&first_power_up.1, &second_power_up.1);
+            assert_reflect_eq(&first_power_up.2, &second_power_up.2);
+        }
+    }
+
+    #[test]
+    fn saved_run_round_trips_through_ron() {
+        let mut app: App = build_started_run_app();
+        set_up_run_state(&mut app.world);
+
+        let saved_run_text: String = serialize_run(&mut app.world).unwrap();
+        let saved_run: SavedRun = deserialize_saved_run(&saved_run_text, get_type_registry(&app.world)).unwrap();
+        let saved_hero_ship: &SavedHeroShip = &saved_run.hero_ships[0];
+
+        assert_eq!(saved_run.scoreboard_score.get(PlayerId::One), 1234);
+        assert_eq!(saved_run.hero_ship_remaining_lives.get(PlayerId::One), 2);
+        assert_eq!(saved_run.hero_ship_still_alive_timer.elapsed_secs(), 1.5);
+        assert_eq!(saved_run.asteroid_check_for_types_timer.elapsed_secs(), 0.25);
+        assert_eq!(saved_run.hero_ships.len(), 1);
+        assert_eq!(saved_hero_ship.active_power_ups.get_remaining_seconds(PowerUpKind::RapidFire), Some(4.));
+        assert_eq!((saved_hero_ship.score_combo.kills, saved_hero_ship.score_combo.window_seconds_remaining), (4, 1.25));
+        assert_eq!(saved_hero_ship.energy_shield.energy, 17.5);
+        assert_eq!(saved_hero_ship.transform.translation, Vec3::new(-50., 60., 0.));
+        assert_eq!(saved_hero_ship.visibility, Visibility::Hidden);
+        assert_eq!(saved_hero_ship.collision_groups, CollisionGroups::new(Group::GROUP_1, Group::all()));
+        assert_eq!(saved_hero_ship.respawn_timer.elapsed_secs(), 0.75);
+        assert_eq!(saved_hero_ship.projectile_spawn_timer.0.elapsed_secs(), 0.125);
+        assert_eq!(saved_hero_ship.weapon_state.charge_seconds, 0.5);
+        assert_eq!(saved_hero_ship.weapon_state.charged_shot_cooldown_seconds, 0.25);
+        assert_eq!(saved_run.asteroids.iter().filter_map(|asteroid| asteroid.2).map(|health| health.hit_points).collect::<Vec<_>>(), vec![2]);
+        assert!(!saved_run.asteroids.is_empty());
+        assert!(!saved_run.projectiles.is_empty());
+        assert_eq!(saved_run.power_ups.len(), 1);
+        assert_eq!(saved_run.power_ups[0].0.kind, PowerUpKind::SpreadShot);
+        assert_eq!(saved_run.power_ups[0].1.elapsed_secs(), 2.);
+        assert_eq!(saved_run.power_ups[0].2.translation, Vec3::new(10., 20., 0.));
+    }
+
+    #[test]
+    fn continuing_a_saved_run_restores_the_same_run() {
+        let mut app: App = build_started_run_app();
+        set_up_run_state(&mut app.world);
+
+        let saved_run_text: String = serialize_run(&mut app.world).unwrap();
+        let saved_run: SavedRun = deserialize_saved_run(&saved_run_text, get_type_registry(&app.world)).unwrap();
+        let game_rng_seed: u64 = saved_run.game_rng_seed;
+
+        app.world.run_system_once_with(saved_run, restore_saved_run);
+        let expected_game_rng_sample: u64 = StdRng::seed_from_u64(game_rng_seed).gen();
+        assert_eq!(app.world.resource::<GameRng>().0.clone().gen::<u64>(), expected_game_rng_sample);
+
+        let restored_run_text: String = serialize_run(&mut app.world).unwrap();
+        assert_saved_runs_eq(
+            deserialize_saved_run(&saved_run_text, get_type_registry(&app.world)).unwrap(),
+            deserialize_saved_run(&restored_run_text, get_type_registry(&app.world)).unwrap()
+        );
+    }
+
+    #[test]
+    fn hero_ship_saved_mid_respawn_stays_hidden_until_the_respawn_timer_finishes() {
+        let mut app: App = build_started_run_app();
+        set_up_hero_ship_mid_respawn(&mut app.world);
+
+        let saved_run_text: String = serialize_run(&mut app.world).unwrap();
+        let saved_run: SavedRun = deserialize_saved_run(&saved_run_text, get_type_registry(&app.world)).unwrap();
+        app.world.run_system_once_with(saved_run, restore_saved_run);
+
+        let mut hero_ship_query = app.world.query_filtered::<(&Transform, &Visibility, &CollisionGroups), With<HeroShip>>();
+        let (hero_ship_transform, hero_ship_visibility, hero_ship_collision_groups) = hero_ship_query.single(&app.world);
+        assert_eq!(hero_ship_transform.translation, Vec3::new(-50., 60., 0.));
+        assert_eq!(*hero_ship_visibility, Visibility::Hidden);
+        assert_eq!(*hero_ship_collision_groups, CollisionGroups::new(Group::GROUP_1, Group::all()));
+
+        for _ in 0..78 {
+            app.update();
+        }
+        assert_eq!(*hero_ship_query.single(&app.world).1, Visibility::Hidden);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        let (hero_ship_transform, hero_ship_visibility, _) = hero_ship_query.single(&app.world);
+        assert_eq!(*hero_ship_visibility, Visibility::Visible);
+        assert_eq!(hero_ship_transform.translation, PlayerId::One.spawn_position(GameMode::SinglePlayer));
+    }
+
+    #[test]
+    fn malformed_saved_run_is_rejected() {
+        let app: App = env::build_headless_app();
+
+        assert!(deserialize_saved_run("(resources: {", get_type_registry(&app.world)).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+
+use crate::network::NetworkSession;
+use crate::constants::menu::SUBMENU_TITLE_FONT_SIZE;
+
+use crate::menu::{
+    self,
+    MenuAction,
+    MenuActionSelected,
+    MenuButton
+};
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.init_resource::<GameSettings>();
+        app.add_systems(Update, (
+            open_settings_menu,
+            check_for_switching_down_key_action_by_menu
+        ).after(menu::navigate_menus).run_if(not(resource_exists::<NetworkSession>)));
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DownKeyAction {
+    #[default]
+    Brake,
+    EnergyShield
+}
+
+impl DownKeyAction {
+    pub fn next(&self) -> Self {
+        match self {
+            DownKeyAction::Brake => return DownKeyAction::EnergyShield,
+            DownKeyAction::EnergyShield => return DownKeyAction::Brake
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DownKeyAction::Brake => return "Brake",
+            DownKeyAction::EnergyShield => return "Energy Shield"
+        }
+    }
+}
+
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameSettings {
+    pub down_key_action: DownKeyAction
+}
+
+#[derive(Component)]
+pub struct SettingsMenu;
+
+fn get_down_key_action_label(game_settings: &GameSettings) -> String {
+    return format!("Down key: {}", game_settings.down_key_action.name());
+}
+
+pub fn open_settings_menu(
+    mut commands: Commands,
+    mut menu_action_selected_events: EventReader<MenuActionSelected>,
+    game_settings: Res<GameSettings>
+) {
+    for menu_action_selected_event in menu_action_selected_events.read() {
+        if menu_action_selected_event.action != MenuAction::Settings {
+            continue;
+        }
+        let settings_menu_entity: Entity = menu::open_submenu(&mut commands, menu_action_selected_event.menu_entity, SettingsMenu);
+
+        commands.entity(settings_menu_entity).with_children(|parent| {
+            menu::spawn_menu_title(parent, "Settings", SUBMENU_TITLE_FONT_SIZE);
+            menu::spawn_menu_buttons(parent, settings_menu_entity, &[
+                (MenuAction::SwitchDownKeyAction, get_down_key_action_label(&game_settings)),
+                (MenuAction::Back, "Back".to_string())
+            ]);
+        });
+    }
+}
+
+pub fn check_for_switching_down_key_action_by_menu(
+    mut menu_action_selected_events: EventReader<MenuActionSelected>,
+    mut game_settings: ResMut<GameSettings>,
+    menu_button_query: Query<(&MenuButton, &Children)>,
+    mut text_query: Query<&mut Text>
+) {
+    for menu_action_selected_event in menu_action_selected_events.read() {
+        if menu_action_selected_event.action != MenuAction::SwitchDownKeyAction {
+            continue;
+        }
+        game_settings.down_key_action = game_settings.down_key_action.next();
+        menu::set_menu_button_label(
+            &menu_button_query,
+            &mut text_query,
+            MenuAction::SwitchDownKeyAction,
+            get_down_key_action_label(&game_settings)
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::GameState;
+use crate::GameMode;
+use crate::StartGame;
+
+use crate::config::{
+    GameConfig,
+    HeroShipConfig,
+    HullConfig
+};
+
+use crate::hero_ship::{
+    HeroShip,
+    PlayerId
+};
+
+use crate::constants::ui_values::TOP_TEXT_VAL_PX;
+use crate::constants::ship_select::SHIP_SELECT_MENU_FONT_SIZE;
+
+pub struct ShipSelectPlugin;
+
+impl Plugin for ShipSelectPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.init_resource::<SelectedHulls>();
+        app.init_resource::<ShipSelection>();
+        app.add_systems(OnEnter(GameState::ShipSelect), spawn_ship_select_menu);
+        app.add_systems(Update, (
+            navigate_ship_select_menu,
+            preview_selected_hulls,
+            update_ship_select_menu
+        ).chain().run_if(in_state(GameState::ShipSelect)));
+        app.add_systems(OnExit(GameState::ShipSelect), erase_ship_select_menu);
+    }
+}
+
+#[derive(Resource, Reflect, Default, Debug, Clone)]
+#[reflect(Resource)]
+pub struct SelectedHulls {
+    pub hulls: HashMap<PlayerId, String>
+}
+
+impl SelectedHulls {
+    pub fn get_hull<'a>(
+        &self,
+        player_id: PlayerId,
+        hero_ship_config: &'a HeroShipConfig
+    ) -> &'a HullConfig {
+        let hull_name: &str = self.hulls.get(&player_id).map(String::as_str).unwrap_or_default();
+
+        return hero_ship_config.get_hull_or_default(hull_name);
+    }
+
+    fn cycle_hull(
+        &mut self,
+        player_id: PlayerId,
+        hero_ship_config: &HeroShipConfig,
+        step: isize
+    ) {
+        let hulls_count: isize = hero_ship_config.hulls.len() as isize;
+        let current_hull_name: String = self.get_hull(player_id, hero_ship_config).name.clone();
+        let current_hull_index: isize = hero_ship_config.hulls.iter()
+            .position(|hull| hull.name == current_hull_name)
+            .unwrap_or(0) as isize;
+        let next_hull_index: usize = (current_hull_index + step).rem_euclid(hulls_count) as usize;
+
+        self.hulls.insert(player_id, hero_ship_config.hulls[next_hull_index].name.clone());
+    }
+}
+
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct ShipSelection {
+    pub game_mode: GameMode
+}
+
+#[derive(Component)]
+pub struct ShipSelectMenu;
+
+pub fn open_ship_select(
+    ship_selection: &mut ResMut<ShipSelection>,
+    next_state: &mut ResMut<NextState<GameState>>,
+    game_mode: GameMode
+) {
+    ship_selection.game_mode = game_mode;
+    next_state.set(GameState::ShipSelect);
+}
+
+pub fn spawn_ship_select_menu(
+    mut commands: Commands
+) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: SHIP_SELECT_MENU_FONT_SIZE,
+                color: Color::SALMON,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(TOP_TEXT_VAL_PX - 40.),
+            left: Val::Px(120.),
+            ..default()
+        }),
+        ShipSelectMenu
+    ));
+}
+
+pub fn erase_ship_select_menu(
+    mut commands: Commands,
+    ship_select_menu_query: Query<Entity, With<ShipSelectMenu>>
+) {
+    for ship_select_menu_entity in &ship_select_menu_query {
+        commands.entity(ship_select_menu_entity).despawn_recursive();
+    }
+}
+
+fn get_hull_cycle_keys(
+    player_id: PlayerId,
+    game_mode: GameMode
+) -> (Vec<KeyCode>, Vec<KeyCode>) {
+    match (game_mode, player_id) {
+        (GameMode::SinglePlayer, _) => return (vec![KeyCode::KeyA, KeyCode::ArrowLeft], vec![KeyCode::KeyD, KeyCode::ArrowRight]),
+        (_, PlayerId::One) => return (vec![KeyCode::KeyA], vec![KeyCode::KeyD]),
+        (_, PlayerId::Two) => return (vec![KeyCode::ArrowLeft], vec![KeyCode::ArrowRight])
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn navigate_ship_select_menu(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_button_input: Res<ButtonInput<GamepadButton>>,
+    game_config: Res<GameConfig>,
+    ship_selection: Res<ShipSelection>,
+    mut selected_hulls: ResMut<SelectedHulls>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut start_game_events: EventWriter<StartGame>
+) {
+    let is_gamepad_button_just_pressed = |gamepad_button_type: GamepadButtonType| -> bool {
+        return gamepads.iter().any(|gamepad| gamepad_button_input.just_pressed(GamepadButton::new(gamepad, gamepad_button_type)));
+    };
+
+    for player_id in ship_selection.game_mode.players() {
+        let (previous_hull_keys, next_hull_keys) = get_hull_cycle_keys(player_id, ship_selection.game_mode);
+        let is_player_one: bool = player_id == PlayerId::One;
+
+        if keyboard_input.any_just_pressed(previous_hull_keys) || (is_player_one && is_gamepad_button_just_pressed(GamepadButtonType::DPadLeft)) {
+            selected_hulls.cycle_hull(player_id, &game_config.hero_ship, -1);
+        }
+
+        if keyboard_input.any_just_pressed(next_hull_keys) || (is_player_one && is_gamepad_button_just_pressed(GamepadButtonType::DPadRight)) {
+            selected_hulls.cycle_hull(player_id, &game_config.hero_ship, 1);
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter) || is_gamepad_button_just_pressed(GamepadButtonType::South) {
+        start_game_events.send(StartGame { game_mode: ship_selection.game_mode, seed: None });
+    } else if keyboard_input.just_pressed(KeyCode::Escape) || is_gamepad_button_just_pressed(GamepadButtonType::East) {
+        next_state.set(GameState::StartScreen);
+    }
+}
+
+pub fn preview_selected_hulls(
+    asset_server: Res<AssetServer>,
+    game_config: Res<GameConfig>,
+    selected_hulls: Res<SelectedHulls>,
+    mut hero_ship_query: Query<(&PlayerId, &mut Handle<Image>), With<HeroShip>>
+) {
+    if !selected_hulls.is_changed() {
+        return;
+    }
+
+    for (player_id, mut hero_ship_handle_image) in &mut hero_ship_query {
+        let hull_config: &HullConfig = selected_hulls.get_hull(*player_id, &game_config.hero_ship);
+
+        *hero_ship_handle_image = asset_server.load(hull_config.sprites.ship.clone());
+    }
+}
+
+pub fn update_ship_select_menu(
+    game_config: Res<GameConfig>,
+    ship_selection: Res<ShipSelection>,
+    selected_hulls: Res<SelectedHulls>,
+    mut ship_select_menu_query: Query<&mut Text, With<ShipSelectMenu>>
+) {
+    let Ok(mut ship_select_menu_text) = ship_select_menu_query.get_single_mut() else {
+        return;
+    };
+    let mut ship_select_menu_lines: Vec<String> = vec!["Choose your ship".to_string(), String::new()];
+
+    for player_id in ship_selection.game_mode.players() {
+        let hull_config: &HullConfig = selected_hulls.get_hull(player_id, &game_config.hero_ship);
+        let weapon_names: Vec<&str> = hull_config.weapons.iter().map(|weapon| weapon.name()).collect();
+
+        ship_select_menu_lines.push(format!("Player {:?}: < {} >", player_id, hull_config.name));
+        ship_select_menu_lines.push(hull_config.description.clone());
+        ship_select_menu_lines.push(format!(
+            "Top speed {:.0} | Turning {:.0} | Hull size {:.1}",
+            hull_config.movement_speed_maximum,
+            hull_config.rotation_speed_maximum,
+            hull_config.collider_ball_size
+        ));
+        ship_select_menu_lines.push(format!("Weapons: {}", weapon_names.join(", ")));
+        ship_select_menu_lines.push(String::new());
+    }
+
+    if ship_selection.game_mode == GameMode::SinglePlayer {
+        ship_select_menu_lines.push("A/D or Left/Right to choose".to_string());
+    } else {
+        ship_select_menu_lines.push("A/D (Player One) and Left/Right (Player Two) to choose".to_string());
+    }
+    ship_select_menu_lines.push("Enter to launch, Escape to go back".to_string());
+
+    ship_select_menu_text.sections[0].value = ship_select_menu_lines.join("\n");
+}
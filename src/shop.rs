@@ -0,0 +1,466 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::GameState;
+use crate::GameMode;
+use crate::GameRng;
+use crate::GameplaySet;
+use crate::asteroid::{self, AsteroidWaveCleared};
+use crate::autopilot::AutopilotMode;
+use crate::network::NetworkSession;
+use crate::env::EnvironmentAction;
+use crate::ui::ScoreboardScore;
+
+use crate::config::{
+    GameConfig,
+    ShopConfig
+};
+
+use crate::hero_ship::{
+    HeroShip,
+    HeroShipHull,
+    PlayerId
+};
+
+use crate::constants::ui_values::TOP_TEXT_VAL_PX;
+use crate::constants::shop::SHOP_MENU_FONT_SIZE;
+
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.init_resource::<ShopSelection>();
+        app.add_systems(FixedUpdate, (
+            open_shop_after_asteroid_wave.after(asteroid::maintain_game_loop).run_if(is_shop_available)
+        ).in_set(GameplaySet));
+        app.add_systems(OnEnter(GameState::Shop), spawn_shop_menu);
+        app.add_systems(Update, (
+            navigate_shop_menu,
+            update_shop_menu
+        ).chain().run_if(in_state(GameState::Shop)));
+        app.add_systems(OnExit(GameState::Shop), (erase_shop_menu, spawn_asteroid_wave_after_shop));
+    }
+}
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpgradeKind {
+    Engine,
+    Thrusters,
+    FireRate,
+    ExtraProjectile,
+    ShieldCharge
+}
+
+impl UpgradeKind {
+    pub const ALL: [UpgradeKind; 5] = [
+        UpgradeKind::Engine,
+        UpgradeKind::Thrusters,
+        UpgradeKind::FireRate,
+        UpgradeKind::ExtraProjectile,
+        UpgradeKind::ShieldCharge
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UpgradeKind::Engine => return "Engine (Top speed)",
+            UpgradeKind::Thrusters => return "Thrusters (Turning)",
+            UpgradeKind::FireRate => return "Fire Rate",
+            UpgradeKind::ExtraProjectile => return "Extra Projectile",
+            UpgradeKind::ShieldCharge => return "Shield Charge"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurchaseError {
+    MaximumLevelReached,
+    NotEnoughCredits { cost: usize }
+}
+
+impl PurchaseError {
+    pub fn message(&self) -> String {
+        match self {
+            PurchaseError::MaximumLevelReached => return "This upgrade is already at its maximum level.".to_string(),
+            PurchaseError::NotEnoughCredits { cost } => return format!("Not enough credits, it costs {}.", cost)
+        }
+    }
+}
+
+pub fn get_upgrade_cost(
+    upgrade_level: u32,
+    shop_config: &ShopConfig
+) -> usize {
+    return shop_config.upgrade_base_cost + shop_config.upgrade_cost_increase * upgrade_level as usize;
+}
+
+pub fn validate_purchase(
+    upgrade_level: u32,
+    credits: usize,
+    shop_config: &ShopConfig
+) -> Result<usize, PurchaseError> {
+    if upgrade_level >= shop_config.upgrade_maximum_level {
+        return Err(PurchaseError::MaximumLevelReached);
+    }
+
+    let upgrade_cost: usize = get_upgrade_cost(upgrade_level, shop_config);
+
+    if credits < upgrade_cost {
+        return Err(PurchaseError::NotEnoughCredits { cost: upgrade_cost });
+    }
+    return Ok(upgrade_cost);
+}
+
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component)]
+pub struct ShipUpgrades {
+    pub levels: HashMap<UpgradeKind, u32>,
+    pub shield_charges: usize
+}
+
+impl ShipUpgrades {
+    pub fn get_level(
+        &self,
+        upgrade_kind: UpgradeKind
+    ) -> u32 {
+        return self.levels.get(&upgrade_kind).copied().unwrap_or(0);
+    }
+
+    pub fn get_fire_rate_factor(
+        &self,
+        shop_config: &ShopConfig
+    ) -> f32 {
+        return shop_config.fire_rate_factor.powi(self.get_level(UpgradeKind::FireRate) as i32);
+    }
+
+    pub fn get_extra_projectiles(&self) -> usize {
+        return self.get_level(UpgradeKind::ExtraProjectile) as usize;
+    }
+
+    pub fn consume_shield_charge(&mut self) -> bool {
+        if self.shield_charges == 0 {
+            return false;
+        }
+        self.shield_charges -= 1;
+        return true;
+    }
+
+    pub fn apply_to_hero_ship(
+        &self,
+        hero_ship: &mut HeroShip,
+        shop_config: &ShopConfig
+    ) {
+        hero_ship.movement_speed_maximum *= shop_config.movement_speed_maximum_factor.powi(self.get_level(UpgradeKind::Engine) as i32);
+        hero_ship.rotation_speed_incrementation *=
+            shop_config.rotation_speed_incrementation_factor.powi(self.get_level(UpgradeKind::Thrusters) as i32);
+    }
+
+    fn purchase(
+        &mut self,
+        upgrade_kind: UpgradeKind
+    ) {
+        *self.levels.entry(upgrade_kind).or_insert(0) += 1;
+
+        if upgrade_kind == UpgradeKind::ShieldCharge {
+            self.shield_charges += 1;
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct ShopSelection {
+    pub selected_index: usize,
+    pub buyer: Option<PlayerId>,
+    pub message: String
+}
+
+impl ShopSelection {
+    fn get_buyer(&self) -> PlayerId {
+        return self.buyer.unwrap_or(PlayerId::One);
+    }
+
+    fn get_selected_upgrade(&self) -> Option<UpgradeKind> {
+        return UpgradeKind::ALL.get(self.selected_index).copied();
+    }
+}
+
+#[derive(Component)]
+pub struct ShopMenu;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ShopAction {
+    Previous,
+    Next,
+    SwitchBuyer,
+    Confirm,
+    Leave
+}
+
+pub fn is_shop_available(
+    game_mode: Res<GameMode>,
+    network_session: Option<Res<NetworkSession>>,
+    autopilot_mode: Option<Res<AutopilotMode>>,
+    environment_action: Option<Res<EnvironmentAction>>
+) -> bool {
+    return *game_mode != GameMode::Versus &&
+        network_session.is_none() &&
+        autopilot_mode.is_none() &&
+        environment_action.is_none();
+}
+
+pub fn open_shop_after_asteroid_wave(
+    mut asteroid_wave_cleared_events: EventReader<AsteroidWaveCleared>,
+    mut next_state: ResMut<NextState<GameState>>
+) {
+    if asteroid_wave_cleared_events.read().count() > 0 {
+        next_state.set(GameState::Shop);
+    }
+}
+
+pub fn spawn_shop_menu(
+    mut commands: Commands,
+    mut shop_selection: ResMut<ShopSelection>
+) {
+    *shop_selection = ShopSelection::default();
+
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: SHOP_MENU_FONT_SIZE,
+                color: Color::SALMON,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(TOP_TEXT_VAL_PX - 40.),
+            left: Val::Px(200.),
+            ..default()
+        }),
+        ShopMenu
+    ));
+}
+
+pub fn erase_shop_menu(
+    mut commands: Commands,
+    shop_menu_query: Query<Entity, With<ShopMenu>>
+) {
+    for shop_menu_entity in &shop_menu_query {
+        commands.entity(shop_menu_entity).despawn_recursive();
+    }
+}
+
+fn read_shop_actions(
+    keyboard_input: &ButtonInput<KeyCode>,
+    gamepads: &Gamepads,
+    gamepad_button_input: &ButtonInput<GamepadButton>
+) -> Vec<ShopAction> {
+    let is_gamepad_button_just_pressed = |gamepad_button_type: GamepadButtonType| -> bool {
+        return gamepads.iter().any(|gamepad| gamepad_button_input.just_pressed(GamepadButton::new(gamepad, gamepad_button_type)));
+    };
+    let shop_action_bindings: [(ShopAction, [KeyCode; 2], GamepadButtonType); 5] = [
+        (ShopAction::Previous, [KeyCode::ArrowUp, KeyCode::KeyW], GamepadButtonType::DPadUp),
+        (ShopAction::Next, [KeyCode::ArrowDown, KeyCode::KeyS], GamepadButtonType::DPadDown),
+        (ShopAction::SwitchBuyer, [KeyCode::Tab, KeyCode::ArrowRight], GamepadButtonType::West),
+        (ShopAction::Confirm, [KeyCode::Enter, KeyCode::Space], GamepadButtonType::South),
+        (ShopAction::Leave, [KeyCode::Escape, KeyCode::Backspace], GamepadButtonType::Start)
+    ];
+
+    return shop_action_bindings.into_iter()
+        .filter(|(_, key_codes, gamepad_button_type)| {
+            keyboard_input.any_just_pressed(*key_codes) || is_gamepad_button_just_pressed(*gamepad_button_type)
+        })
+        .map(|(shop_action, _, _)| shop_action)
+        .collect();
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn navigate_shop_menu(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_button_input: Res<ButtonInput<GamepadButton>>,
+    game_config: Res<GameConfig>,
+    game_mode: Res<GameMode>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut shop_selection: ResMut<ShopSelection>,
+    mut scoreboard_score: ResMut<ScoreboardScore>,
+    mut hero_ship_query: Query<(&PlayerId, &HeroShipHull, &mut HeroShip, &mut ShipUpgrades)>
+) {
+    let shop_items_count: usize = UpgradeKind::ALL.len() + 1;
+
+    for shop_action in read_shop_actions(&keyboard_input, &gamepads, &gamepad_button_input) {
+        match shop_action {
+            ShopAction::Previous => {
+                shop_selection.selected_index = (shop_selection.selected_index + shop_items_count - 1) % shop_items_count;
+            },
+            ShopAction::Next => {
+                shop_selection.selected_index = (shop_selection.selected_index + 1) % shop_items_count;
+            },
+            ShopAction::SwitchBuyer => {
+                if *game_mode == GameMode::Cooperative {
+                    shop_selection.buyer = Some(shop_selection.get_buyer().opponent());
+                    shop_selection.message.clear();
+                }
+            },
+            ShopAction::Leave => {
+                next_state.set(GameState::InGame);
+            },
+            ShopAction::Confirm => {
+                let Some(upgrade_kind) = shop_selection.get_selected_upgrade() else {
+                    next_state.set(GameState::InGame);
+                    continue;
+                };
+                let buyer: PlayerId = shop_selection.get_buyer();
+                let Some((_, hero_ship_hull, mut hero_ship, mut ship_upgrades)) =
+                    hero_ship_query.iter_mut().find(|(player_id, _, _, _)| **player_id == buyer)
+                else {
+                    continue;
+                };
+
+                match validate_purchase(ship_upgrades.get_level(upgrade_kind), scoreboard_score.get_credits(buyer), &game_config.shop) {
+                    Ok(upgrade_cost) => {
+                        scoreboard_score.spend_credits(buyer, upgrade_cost);
+                        ship_upgrades.purchase(upgrade_kind);
+                        hero_ship.apply_config(hero_ship_hull);
+                        ship_upgrades.apply_to_hero_ship(&mut hero_ship, &game_config.shop);
+                        shop_selection.message = format!("Bought {}!", upgrade_kind.name());
+                    },
+                    Err(purchase_error) => {
+                        shop_selection.message = purchase_error.message();
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn update_shop_menu(
+    game_config: Res<GameConfig>,
+    game_mode: Res<GameMode>,
+    shop_selection: Res<ShopSelection>,
+    scoreboard_score: Res<ScoreboardScore>,
+    hero_ship_query: Query<(&PlayerId, &ShipUpgrades)>,
+    mut shop_menu_query: Query<&mut Text, With<ShopMenu>>
+) {
+    let Ok(mut shop_menu_text) = shop_menu_query.get_single_mut() else {
+        return;
+    };
+    let buyer: PlayerId = shop_selection.get_buyer();
+    let ship_upgrades: ShipUpgrades = hero_ship_query.iter()
+        .find(|(player_id, _)| **player_id == buyer)
+        .map(|(_, ship_upgrades)| ship_upgrades.clone())
+        .unwrap_or_default();
+
+    let buyer_description: &str = match (*game_mode, buyer) {
+        (GameMode::Cooperative, PlayerId::One) => "P1 (Tab to switch)",
+        (GameMode::Cooperative, PlayerId::Two) => "P2 (Tab to switch)",
+        _ => "Player"
+    };
+    let mut shop_menu_lines: Vec<String> = vec![
+        "Wave cleared! Upgrade your ship".to_string(),
+        format!("{} credits: {}", buyer_description, scoreboard_score.get_credits(buyer)),
+        String::new()
+    ];
+
+    for (upgrade_index, upgrade_kind) in UpgradeKind::ALL.iter().enumerate() {
+        let upgrade_level: u32 = ship_upgrades.get_level(*upgrade_kind);
+        let upgrade_cost_description: String = if upgrade_level >= game_config.shop.upgrade_maximum_level {
+            "MAX".to_string()
+        } else {
+            get_upgrade_cost(upgrade_level, &game_config.shop).to_string()
+        };
+        let selection_marker: &str = if upgrade_index == shop_selection.selected_index { "> " } else { "  " };
+
+        shop_menu_lines.push(format!(
+            "{}{} [{}/{}] - {}",
+            selection_marker,
+            upgrade_kind.name(),
+            upgrade_level,
+            game_config.shop.upgrade_maximum_level,
+            upgrade_cost_description
+        ));
+    }
+
+    let continue_selection_marker: &str = if shop_selection.get_selected_upgrade().is_none() { "> " } else { "  " };
+    shop_menu_lines.push(format!("{}Next wave", continue_selection_marker));
+    shop_menu_lines.push(String::new());
+    shop_menu_lines.push(shop_selection.message.clone());
+
+    shop_menu_text.sections[0].value = shop_menu_lines.join("\n");
+}
+
+pub fn spawn_asteroid_wave_after_shop(
+    commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>
+) {
+    asteroid::spawn_initial_asteroids(commands, &asset_server, &game_config, &mut game_rng);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_shop_config() -> ShopConfig {
+        return ShopConfig {
+            upgrade_base_cost: 100,
+            upgrade_cost_increase: 50,
+            upgrade_maximum_level: 3,
+            movement_speed_maximum_factor: 1.2,
+            rotation_speed_incrementation_factor: 1.1,
+            fire_rate_factor: 0.5
+        };
+    }
+
+    #[test]
+    fn upgrade_cost_grows_with_each_level() {
+        let shop_config: ShopConfig = get_test_shop_config();
+
+        assert_eq!(get_upgrade_cost(0, &shop_config), 100);
+        assert_eq!(get_upgrade_cost(1, &shop_config), 150);
+        assert_eq!(get_upgrade_cost(2, &shop_config), 200);
+    }
+
+    #[test]
+    fn purchase_at_maximum_level_is_rejected() {
+        let shop_config: ShopConfig = get_test_shop_config();
+
+        assert_eq!(validate_purchase(3, 10_000, &shop_config), Err(PurchaseError::MaximumLevelReached));
+        assert_eq!(validate_purchase(4, 10_000, &shop_config), Err(PurchaseError::MaximumLevelReached));
+    }
+
+    #[test]
+    fn purchase_without_enough_credits_reports_the_cost() {
+        let shop_config: ShopConfig = get_test_shop_config();
+
+        assert_eq!(validate_purchase(1, 149, &shop_config), Err(PurchaseError::NotEnoughCredits { cost: 150 }));
+        assert_eq!(validate_purchase(0, 0, &shop_config), Err(PurchaseError::NotEnoughCredits { cost: 100 }));
+    }
+
+    #[test]
+    fn purchase_with_exact_credits_is_accepted() {
+        let shop_config: ShopConfig = get_test_shop_config();
+
+        assert_eq!(validate_purchase(0, 100, &shop_config), Ok(100));
+        assert_eq!(validate_purchase(2, 200, &shop_config), Ok(200));
+    }
+
+    #[test]
+    fn purchases_raise_levels_and_shield_charges() {
+        let shop_config: ShopConfig = get_test_shop_config();
+        let mut ship_upgrades: ShipUpgrades = ShipUpgrades::default();
+
+        ship_upgrades.purchase(UpgradeKind::FireRate);
+        ship_upgrades.purchase(UpgradeKind::FireRate);
+        ship_upgrades.purchase(UpgradeKind::ShieldCharge);
+
+        assert_eq!(ship_upgrades.get_level(UpgradeKind::FireRate), 2);
+        assert_eq!(ship_upgrades.get_fire_rate_factor(&shop_config), 0.25);
+        assert!(ship_upgrades.consume_shield_charge());
+        assert!(!ship_upgrades.consume_shield_charge());
+    }
+}
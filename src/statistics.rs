@@ -0,0 +1,333 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use crate::GameState;
+use crate::GameMode;
+use crate::GameplaySet;
+use crate::ResetRun;
+use crate::collision;
+use crate::ui::{self, ScoreboardScore};
+use crate::asteroid::{AsteroidDestroyed, AsteroidWaveCleared};
+use crate::autopilot::AutopilotMode;
+use crate::env::EnvironmentAction;
+use crate::network::NetworkSession;
+use crate::config::GameConfig;
+
+use crate::achievements::{
+    Achievement,
+    AchievementProgress
+};
+
+use crate::menu::{self, MenuAction, MenuActionSelected};
+use crate::constants::menu::SUBMENU_TITLE_FONT_SIZE;
+
+use crate::constants::statistics::{
+    PERSONAL_BEST_FILE_PATH,
+    LIFETIME_STATISTICS_FILE_PATH,
+    CAREER_MENU_FONT_SIZE
+};
+
+pub struct StatisticsPlugin;
+
+impl Plugin for StatisticsPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.init_resource::<RunStatistics>();
+        app.add_systems(ResetRun, reset_run_statistics);
+        app.insert_resource(load_ron_file::<PersonalBest>(PERSONAL_BEST_FILE_PATH));
+        app.insert_resource(load_ron_file::<LifetimeStatistics>(LIFETIME_STATISTICS_FILE_PATH));
+        app.add_systems(FixedUpdate, (
+            count_destroyed_asteroids.after(collision::apply_asteroid_hits),
+            count_cleared_asteroid_waves
+        ).in_set(GameplaySet));
+        app.add_systems(OnEnter(GameState::EndGame), (
+            record_personal_best
+        ).after(ui::spawn_end_game_menu).run_if(is_player_run.and_then(not(resource_equals(GameMode::Versus)))));
+        app.add_systems(OnEnter(GameState::EndGame), (
+            record_lifetime_statistics
+        ).run_if(is_player_run));
+        app.add_systems(Update, (
+            open_high_scores_menu.after(menu::navigate_menus)
+        ).run_if(not(resource_exists::<NetworkSession>)));
+    }
+}
+
+#[derive(Resource, Reflect, Default, Debug, Clone)]
+#[reflect(Resource)]
+pub struct RunStatistics {
+    pub asteroids_destroyed: HashMap<String, usize>,
+    pub shots_fired: usize,
+    pub shots_hit: usize,
+    pub seconds_survived: f32,
+    pub still_alive_bonuses: usize,
+    pub waves_cleared: usize,
+    pub achievement_progress: AchievementProgress
+}
+
+impl RunStatistics {
+    pub fn get_asteroids_destroyed(
+        &self,
+        archetype_name: &str
+    ) -> usize {
+        return self.asteroids_destroyed.get(archetype_name).copied().unwrap_or(0);
+    }
+
+    pub fn get_total_asteroids_destroyed(&self) -> usize {
+        return self.asteroids_destroyed.values().sum();
+    }
+
+    pub fn get_accuracy(&self) -> f32 {
+        if self.shots_fired == 0 {
+            return 0.;
+        }
+        return (self.shots_hit as f32 / self.shots_fired as f32).min(1.);
+    }
+}
+
+#[derive(Resource, Serialize, Deserialize, Default, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct PersonalBest {
+    pub score: usize,
+    pub seconds_survived: f32,
+    pub waves_cleared: usize
+}
+
+impl PersonalBest {
+    pub fn merged_with(
+        &self,
+        score: usize,
+        run_statistics: &RunStatistics
+    ) -> Self {
+        return Self {
+            score: self.score.max(score),
+            seconds_survived: self.seconds_survived.max(run_statistics.seconds_survived),
+            waves_cleared: self.waves_cleared.max(run_statistics.waves_cleared)
+        };
+    }
+}
+
+#[derive(Resource, Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(default)]
+pub struct LifetimeStatistics {
+    pub asteroids_destroyed: BTreeMap<String, usize>,
+    pub seconds_played: f32,
+    pub games_played: usize,
+    pub best_wave: usize,
+    pub unlocked_achievements: Vec<Achievement>
+}
+
+impl LifetimeStatistics {
+    pub fn record_run(
+        &mut self,
+        run_statistics: &RunStatistics
+    ) {
+        for (archetype_name, asteroids_destroyed) in &run_statistics.asteroids_destroyed {
+            *self.asteroids_destroyed.entry(archetype_name.clone()).or_insert(0) += asteroids_destroyed;
+        }
+        self.seconds_played += run_statistics.seconds_survived;
+        self.games_played += 1;
+        self.best_wave = self.best_wave.max(run_statistics.waves_cleared + 1);
+    }
+
+    pub fn is_unlocked(
+        &self,
+        achievement: Achievement
+    ) -> bool {
+        return self.unlocked_achievements.contains(&achievement);
+    }
+
+    pub fn unlock(
+        &mut self,
+        achievement: Achievement
+    ) -> bool {
+        if self.is_unlocked(achievement) {
+            return false;
+        }
+        self.unlocked_achievements.push(achievement);
+        return true;
+    }
+}
+
+#[derive(Component)]
+pub struct HighScoresMenu;
+
+pub fn reset_run_statistics(
+    mut run_statistics: ResMut<RunStatistics>
+) {
+    *run_statistics = RunStatistics::default();
+}
+
+pub fn is_player_run(
+    autopilot_mode: Option<Res<AutopilotMode>>,
+    environment_action: Option<Res<EnvironmentAction>>
+) -> bool {
+    return autopilot_mode.is_none() && environment_action.is_none();
+}
+
+pub fn load_ron_file<T: DeserializeOwned + Default>(file_path: &str) -> T {
+    let Ok(ron_file_text) = std::fs::read_to_string(file_path) else {
+        return T::default();
+    };
+
+    match ron::from_str(&ron_file_text) {
+        Ok(ron_file_value) => return ron_file_value,
+        Err(ron_file_error) => {
+            warn!("Could not parse {}: {}", file_path, ron_file_error);
+            return T::default();
+        }
+    }
+}
+
+pub fn save_ron_file<T: Serialize>(
+    ron_file_value: &T,
+    file_path: &str
+) {
+    if let Some(ron_file_directory) = Path::new(file_path).parent() {
+        if let Err(directory_error) = std::fs::create_dir_all(ron_file_directory) {
+            warn!("Could not create the directory {:?}: {}", ron_file_directory, directory_error);
+            return;
+        }
+    }
+
+    let ron_file_text: String = match ron::ser::to_string_pretty(ron_file_value, ron::ser::PrettyConfig::default()) {
+        Ok(ron_file_text) => ron_file_text,
+        Err(ron_file_error) => {
+            warn!("Could not serialize {}: {}", file_path, ron_file_error);
+            return;
+        }
+    };
+
+    if let Err(file_error) = std::fs::write(file_path, ron_file_text) {
+        warn!("Could not save {}: {}", file_path, file_error);
+    }
+}
+
+pub fn get_run_score(scoreboard_score: &ScoreboardScore) -> usize {
+    return scoreboard_score.score.values().sum();
+}
+
+pub fn format_seconds_survived(seconds_survived: f32) -> String {
+    let whole_seconds: u32 = seconds_survived.max(0.) as u32;
+
+    return format!("{}m {:02}s", whole_seconds / 60, whole_seconds % 60);
+}
+
+pub fn count_destroyed_asteroids(
+    mut asteroid_destroyed_events: EventReader<AsteroidDestroyed>,
+    mut run_statistics: ResMut<RunStatistics>
+) {
+    for asteroid_destroyed_event in asteroid_destroyed_events.read() {
+        *run_statistics.asteroids_destroyed.entry(asteroid_destroyed_event.archetype.clone()).or_insert(0) += 1;
+    }
+}
+
+pub fn count_cleared_asteroid_waves(
+    mut asteroid_wave_cleared_events: EventReader<AsteroidWaveCleared>,
+    mut run_statistics: ResMut<RunStatistics>
+) {
+    run_statistics.waves_cleared += asteroid_wave_cleared_events.read().count();
+}
+
+pub fn record_personal_best(
+    scoreboard_score: Res<ScoreboardScore>,
+    run_statistics: Res<RunStatistics>,
+    mut personal_best: ResMut<PersonalBest>
+) {
+    let merged_personal_best: PersonalBest = personal_best.merged_with(get_run_score(&scoreboard_score), &run_statistics);
+
+    if merged_personal_best != *personal_best {
+        *personal_best = merged_personal_best;
+        save_ron_file(&*personal_best, PERSONAL_BEST_FILE_PATH);
+    }
+}
+
+pub fn record_lifetime_statistics(
+    run_statistics: Res<RunStatistics>,
+    mut lifetime_statistics: ResMut<LifetimeStatistics>
+) {
+    lifetime_statistics.record_run(&run_statistics);
+    save_ron_file(&*lifetime_statistics, LIFETIME_STATISTICS_FILE_PATH);
+}
+
+pub fn get_career_menu_text(
+    game_config: &GameConfig,
+    personal_best: &PersonalBest,
+    lifetime_statistics: &LifetimeStatistics
+) -> String {
+    let mut career_menu_lines: Vec<String> = vec![format!(
+        "Personal best: {} points | {} survived | {} waves cleared",
+        personal_best.score,
+        format_seconds_survived(personal_best.seconds_survived),
+        personal_best.waves_cleared
+    ), String::new()];
+
+    career_menu_lines.push(format!(
+        "Games played: {} | Playtime: {} | Best wave: {}",
+        lifetime_statistics.games_played,
+        format_seconds_survived(lifetime_statistics.seconds_played),
+        lifetime_statistics.best_wave
+    ));
+
+    for asteroid_archetype in &game_config.asteroids.archetypes {
+        career_menu_lines.push(format!(
+            "{} asteroids destroyed: {}",
+            asteroid_archetype.name,
+            lifetime_statistics.asteroids_destroyed.get(&asteroid_archetype.name).copied().unwrap_or(0)
+        ));
+    }
+
+    career_menu_lines.push(String::new());
+    career_menu_lines.push(format!(
+        "Achievements ({}/{})",
+        lifetime_statistics.unlocked_achievements.len(),
+        Achievement::ALL.len()
+    ));
+
+    for achievement in Achievement::ALL {
+        let achievement_mark: &str = if lifetime_statistics.is_unlocked(achievement) { "[x]" } else { "[ ]" };
+
+        career_menu_lines.push(format!("{} {} - {}", achievement_mark, achievement.name(), achievement.description()));
+    }
+    return career_menu_lines.join("\n");
+}
+
+pub fn open_high_scores_menu(
+    mut commands: Commands,
+    mut menu_action_selected_events: EventReader<MenuActionSelected>,
+    game_config: Res<GameConfig>,
+    personal_best: Res<PersonalBest>,
+    lifetime_statistics: Res<LifetimeStatistics>
+) {
+    for menu_action_selected_event in menu_action_selected_events.read() {
+        if menu_action_selected_event.action != MenuAction::HighScores {
+            continue;
+        }
+        let high_scores_menu_entity: Entity = menu::open_submenu(&mut commands, menu_action_selected_event.menu_entity, HighScoresMenu);
+
+        commands.entity(high_scores_menu_entity).with_children(|parent| {
+            menu::spawn_menu_title(parent, "High Scores", SUBMENU_TITLE_FONT_SIZE);
+            parent.spawn(
+                TextBundle::from_section(
+                    get_career_menu_text(&game_config, &personal_best, &lifetime_statistics),
+                    TextStyle {
+                        font_size: CAREER_MENU_FONT_SIZE,
+                        color: Color::SALMON,
+                        ..default()
+                    },
+                )
+                .with_text_justify(JustifyText::Center)
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(12.)),
+                    ..default()
+                })
+            );
+            menu::spawn_menu_buttons(parent, high_scores_menu_entity, &[
+                (MenuAction::Back, "Back".to_string())
+            ]);
+        });
+    }
+}
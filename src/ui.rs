@@ -1,13 +1,79 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 use crate::PausingState;
 use crate::GameState;
+use crate::GameMode;
+use crate::RoundState;
+use crate::ResetRun;
+use crate::hero_ship::{
+    HeroShip,
+    HeroShipControls,
+    PlayerId
+};
+
+use crate::power_up::{
+    ActivePowerUps,
+    PowerUpKind
+};
+
+use crate::versus::VersusRounds;
+use crate::config::GameConfig;
+use crate::shop::ShipUpgrades;
+
+use crate::energy_shield::EnergyShield;
+
+use crate::network::NetworkSession;
+
+use crate::menu::{
+    self,
+    Menu,
+    MenuAction,
+    MenuActionSelected,
+    MenuButton,
+    MenuTransition
+};
+use crate::combo::ScoreCombo;
+use crate::save;
+
+use crate::statistics::{
+    self,
+    RunStatistics,
+    PersonalBest
+};
+
+use crate::settings::{
+    GameSettings,
+    DownKeyAction
+};
+
+use crate::weapon::{
+    Weapon,
+    WeaponState
+};
+
+use crate::constants::borders::RIGHT_BORDER_POSITION;
+use crate::constants::menu::{
+    MENU_TITLE_FONT_SIZE,
+    SUBMENU_TITLE_FONT_SIZE,
+    MENU_TEXT_FONT_SIZE
+};
 
-use crate::constants::ui_values::{
-    TOP_TEXT_VAL_PX,
-    RIGHT_TEXT_VAL_PX
+use crate::constants::energy_shield::{
+    ENERGY_SHIELD_BAR_WIDTH,
+    ENERGY_SHIELD_BAR_HEIGHT
 };
 
+use crate::constants::statistics::{
+    END_GAME_MENU_FONT_SIZE,
+    END_GAME_MENU_ANIMATION_SECONDS,
+    END_GAME_MENU_HIDDEN_TOP_VAL_PX,
+    END_GAME_MENU_TOP_VAL_PX,
+    END_GAME_MENU_BACKGROUND_ALPHA
+};
+
+use crate::constants::ui_values::TOAST_SECONDS;
+
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
@@ -16,22 +82,31 @@ impl Plugin for UiPlugin {
         app: &mut App
     ) {
         app.init_resource::<ScoreboardScore>();
-        app.add_systems(PostStartup, spawn_scoreboard);
-        app.add_systems(Update, update_scoreboard_score);
-        app.add_systems(Update, (
+        app.add_systems(ResetRun, reset_scoreboard_score);
+        app.add_event::<ShowToast>();
+        app.add_systems(PostStartup, (spawn_initial_scoreboard, spawn_toast_container, spawn_hero_ship_hud, spawn_energy_shield_bars));
+        app.add_systems(Update, (update_scoreboard_score, update_hero_ship_hud, update_energy_shield_bars));
+        app.add_systems(Update, (spawn_toasts, despawn_expired_toasts));
+        app.add_systems(OnEnter(GameState::EndGame), (
             spawn_end_game_menu
-        ).run_if(in_state(GameState::EndGame)));
+        ).run_if(not(resource_equals(GameMode::Versus))));
         app.add_systems(Update, (
-            spawn_start_screen_menu
-        ).run_if(in_state(GameState::StartScreen)));
+            animate_end_game_menu
+        ).run_if(in_state(GameState::EndGame)));
+        app.add_systems(OnEnter(GameState::EndGame), (
+            spawn_round_results_menu
+        ).run_if(resource_equals(GameMode::Versus)));
         app.add_systems(Update, (
-            spawn_pause_menu
-        ).run_if(in_state(PausingState::Paused).and_then(in_state(GameState::InGame))));
+            update_round_results_menu
+        ).run_if(in_state(GameState::EndGame).and_then(resource_equals(GameMode::Versus))));
+        app.add_systems(OnExit(GameState::EndGame), (erase_end_game_menu, erase_round_results_menu));
+        app.add_systems(OnEnter(GameState::StartScreen), spawn_start_screen_menu);
+        app.add_systems(OnExit(GameState::StartScreen), erase_start_screen_menu);
+        app.add_systems(OnEnter(PausingState::Paused), spawn_pause_menu);
+        app.add_systems(OnExit(PausingState::Paused), erase_pause_menu);
         app.add_systems(Update, (
-            erase_end_game_menu,
-            erase_start_screen_menu,
-            erase_pause_menu
-        ).run_if(in_state(PausingState::Running).and_then(in_state(GameState::InGame))));
+            open_controls_menu
+        ).after(menu::navigate_menus).run_if(not(resource_exists::<NetworkSession>)));
     }
 }
 
@@ -41,44 +116,148 @@ pub struct StartScreenMenu;
 #[derive(Component)]
 pub struct PauseMenu;
 
+#[derive(Component)]
+pub struct ControlsMenu;
+
 #[derive(Component)]
 pub struct EndGameMenu;
 
+#[derive(Component, Deref, DerefMut)]
+pub struct EndGameMenuAnimation(pub Timer);
+
+impl Default for EndGameMenuAnimation {
+    fn default() -> Self {
+        return Self(Timer::from_seconds(END_GAME_MENU_ANIMATION_SECONDS, TimerMode::Once));
+    }
+}
+
+#[derive(Component)]
+pub struct EndGameMenuPanel;
+
+#[derive(Component)]
+pub struct RoundResultsMenu;
+
+#[derive(Component)]
+pub struct RoundResultsMenuText;
+
 #[derive(Component, Clone, Copy)]
 pub struct Scoreboard;
 
-#[derive(Resource, Clone, Copy)]
+#[derive(Component)]
+pub struct HeroShipHud;
+
+#[derive(Component)]
+pub struct EnergyShieldBar;
+
+#[derive(Component)]
+pub struct EnergyShieldBarFill;
+
+#[derive(Component)]
+pub struct ToastContainer;
+
+#[derive(Component, Deref, DerefMut)]
+pub struct Toast(pub Timer);
+
+impl Default for Toast {
+    fn default() -> Self {
+        return Self(Timer::from_seconds(TOAST_SECONDS, TimerMode::Once));
+    }
+}
+
+#[derive(Event, Clone)]
+pub struct ShowToast {
+    pub message: String,
+    pub color: Color
+}
+
+#[derive(Resource, Reflect, Clone)]
+#[reflect(Resource)]
 pub struct ScoreboardScore {
-    pub score: usize
+    pub score: HashMap<PlayerId, usize>,
+    pub credits: HashMap<PlayerId, usize>
 }
 
 impl Default for ScoreboardScore {
     fn default() -> Self {
-        return Self { score: 0 };
+        return Self {
+            score: HashMap::from([(PlayerId::One, 0)]),
+            credits: HashMap::from([(PlayerId::One, 0)])
+        };
+    }
+}
+
+impl ScoreboardScore {
+    pub fn reset(
+        &mut self,
+        game_mode: GameMode
+    ) {
+        self.score = game_mode.players().into_iter().map(|player_id| (player_id, 0)).collect();
+        self.credits = self.score.clone();
+    }
+
+    pub fn get(
+        &self,
+        player_id: PlayerId
+    ) -> usize {
+        return self.score.get(&player_id).copied().unwrap_or(0);
+    }
+
+    pub fn increase(
+        &mut self,
+        player_id: PlayerId,
+        points: usize
+    ) {
+        *self.score.entry(player_id).or_insert(0) += points;
+        *self.credits.entry(player_id).or_insert(0) += points;
+    }
+
+    pub fn get_credits(
+        &self,
+        player_id: PlayerId
+    ) -> usize {
+        return self.credits.get(&player_id).copied().unwrap_or(0);
+    }
+
+    pub fn spend_credits(
+        &mut self,
+        player_id: PlayerId,
+        cost: usize
+    ) {
+        if let Some(credits) = self.credits.get_mut(&player_id) {
+            *credits = credits.saturating_sub(cost);
+        }
     }
 }
 
 pub fn spawn_start_screen_menu(
     mut commands: Commands
 ) {
-    commands.spawn((
-        TextBundle::from_section(
-            "Asteroids\nPress Enter to start the game!",
-            TextStyle {
-                font_size: 40.,
-                color: Color::SALMON,
-                ..default()
-            },
-        )
-        .with_text_justify(JustifyText::Center)
-        .with_style(Style {
-            position_type: PositionType::Absolute,
-            top: Val::Px(TOP_TEXT_VAL_PX),
-            right: Val::Px(RIGHT_TEXT_VAL_PX - 220.),
-            ..default()
-        }),
-        StartScreenMenu,
-    ));
+    let start_screen_menu_entity: Entity = commands.spawn((
+        menu::get_menu_root_bundle(0.),
+        Menu::default(),
+        MenuTransition::default(),
+        StartScreenMenu
+    )).id();
+
+    let mut start_screen_menu_buttons: Vec<(MenuAction, String)> = Vec::new();
+
+    if save::has_saved_run() {
+        start_screen_menu_buttons.push((MenuAction::Continue, "Continue".to_string()));
+    }
+
+    start_screen_menu_buttons.extend([
+        (MenuAction::Play, "Play".to_string()),
+        (MenuAction::Cooperative, "Co-op".to_string()),
+        (MenuAction::Versus, "Versus".to_string()),
+        (MenuAction::Settings, "Settings".to_string()),
+        (MenuAction::HighScores, "High Scores".to_string()),
+        (MenuAction::Quit, "Quit".to_string())
+    ]);
+
+    commands.entity(start_screen_menu_entity).with_children(|parent| {
+        menu::spawn_menu_title(parent, "Asteroids", MENU_TITLE_FONT_SIZE);
+        menu::spawn_menu_buttons(parent, start_screen_menu_entity, &start_screen_menu_buttons);
+    });
 }
 
 pub fn erase_start_screen_menu(
@@ -86,31 +265,30 @@ pub fn erase_start_screen_menu(
     start_screen_query: Query<Entity, With<StartScreenMenu>>
 ) {
     for start_screen_entity in &start_screen_query {
-        commands.entity(start_screen_entity).despawn();
+        commands.entity(start_screen_entity).despawn_recursive();
     }
 }
 
 pub fn spawn_pause_menu(
     mut commands: Commands
 ) {
-    commands.spawn((
-        TextBundle::from_section(
-            "Paused!",
-            TextStyle {
-                font_size: 50.,
-                color: Color::SALMON,
-                ..default()
-            },
-        )
-        .with_text_justify(JustifyText::Center)
-        .with_style(Style {
-            position_type: PositionType::Absolute,
-            top: Val::Px(TOP_TEXT_VAL_PX),
-            left: Val::Px(RIGHT_TEXT_VAL_PX),
-            ..default()
-        }),
-        PauseMenu,
-    ));
+    let pause_menu_entity: Entity = commands.spawn((
+        menu::get_menu_root_bundle(0.6),
+        Menu::default(),
+        MenuTransition::default(),
+        PauseMenu
+    )).id();
+
+    commands.entity(pause_menu_entity).with_children(|parent| {
+        menu::spawn_menu_title(parent, "Paused!", MENU_TITLE_FONT_SIZE);
+        menu::spawn_menu_buttons(parent, pause_menu_entity, &[
+            (MenuAction::Resume, "Resume".to_string()),
+            (MenuAction::Restart, "Restart Run".to_string()),
+            (MenuAction::Settings, "Settings".to_string()),
+            (MenuAction::Controls, "Controls".to_string()),
+            (MenuAction::QuitToTitle, "Quit to Title".to_string())
+        ]);
+    });
 }
 
 pub fn erase_pause_menu(
@@ -118,31 +296,238 @@ pub fn erase_pause_menu(
     pause_menu_query: Query<Entity, With<PauseMenu>>
 ) {
     for pause_menu_entity in &pause_menu_query {
-        commands.entity(pause_menu_entity).despawn();
+        commands.entity(pause_menu_entity).despawn_recursive();
+    }
+}
+
+fn get_key_names(key_codes: &[KeyCode]) -> String {
+    return key_codes.iter()
+        .map(|key_code| format!("{:?}", key_code).trim_start_matches("Key").to_string())
+        .collect::<Vec<String>>()
+        .join(" / ");
+}
+
+#[allow(clippy::type_complexity)]
+pub fn get_controls_menu_text(
+    game_mode: GameMode,
+    game_settings: &GameSettings
+) -> String {
+    let hero_ships_controls: Vec<HeroShipControls> = game_mode.players().into_iter()
+        .map(|player_id| HeroShipControls::initialize(player_id, game_mode))
+        .collect();
+    let control_actions: [(&str, fn(&HeroShipControls) -> &Vec<KeyCode>); 6] = [
+        ("Accelerate", |hero_ship_controls| &hero_ship_controls.accelerate),
+        (game_settings.down_key_action.name(), |hero_ship_controls| &hero_ship_controls.brake),
+        ("Rotate left", |hero_ship_controls| &hero_ship_controls.rotate_left),
+        ("Rotate right", |hero_ship_controls| &hero_ship_controls.rotate_right),
+        ("Shoot", |hero_ship_controls| &hero_ship_controls.shoot),
+        ("Switch weapon", |hero_ship_controls| &hero_ship_controls.cycle_weapon)
+    ];
+    let mut controls_menu_lines: Vec<String> = Vec::new();
+
+    if hero_ships_controls.len() > 1 {
+        controls_menu_lines.push("Player One | Player Two".to_string());
+        controls_menu_lines.push(String::new());
+    }
+
+    for (control_action_name, get_control_key_codes) in control_actions {
+        let control_key_names: Vec<String> = hero_ships_controls.iter()
+            .map(|hero_ship_controls| get_key_names(get_control_key_codes(hero_ship_controls)))
+            .collect();
+
+        controls_menu_lines.push(format!("{}: {}", control_action_name, control_key_names.join(" | ")));
     }
+
+    controls_menu_lines.push(String::new());
+    controls_menu_lines.push("Pause: Escape".to_string());
+    return controls_menu_lines.join("\n");
+}
+
+pub fn open_controls_menu(
+    mut commands: Commands,
+    mut menu_action_selected_events: EventReader<MenuActionSelected>,
+    game_mode: Res<GameMode>,
+    game_settings: Res<GameSettings>
+) {
+    for menu_action_selected_event in menu_action_selected_events.read() {
+        if menu_action_selected_event.action != MenuAction::Controls {
+            continue;
+        }
+        let controls_menu_entity: Entity = menu::open_submenu(&mut commands, menu_action_selected_event.menu_entity, ControlsMenu);
+
+        commands.entity(controls_menu_entity).with_children(|parent| {
+            menu::spawn_menu_title(parent, "Controls", SUBMENU_TITLE_FONT_SIZE);
+            parent.spawn(
+                TextBundle::from_section(
+                    get_controls_menu_text(*game_mode, &game_settings),
+                    TextStyle {
+                        font_size: MENU_TEXT_FONT_SIZE,
+                        color: Color::SALMON,
+                        ..default()
+                    },
+                )
+                .with_text_justify(JustifyText::Center)
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(12.)),
+                    ..default()
+                })
+            );
+            menu::spawn_menu_buttons(parent, controls_menu_entity, &[
+                (MenuAction::Back, "Back".to_string())
+            ]);
+        });
+    }
+}
+
+fn format_personal_best_comparison(
+    value: f32,
+    personal_best_value: f32,
+    personal_best_text: String
+) -> String {
+    if value > personal_best_value {
+        return "(New personal best!)".to_string();
+    }
+    return format!("(Best: {})", personal_best_text);
+}
+
+pub fn get_end_game_menu_text(
+    game_config: &GameConfig,
+    run_statistics: &RunStatistics,
+    personal_best: &PersonalBest,
+    run_score: usize
+) -> String {
+    let asteroids_destroyed_by_archetype: Vec<String> = game_config.asteroids.archetypes.iter()
+        .map(|asteroid_archetype| format!(
+            "{} {}",
+            run_statistics.get_asteroids_destroyed(&asteroid_archetype.name),
+            asteroid_archetype.name
+        ))
+        .collect();
+
+    return [
+        "You crashed! :(".to_string(),
+        String::new(),
+        format!(
+            "Final score: {} {}",
+            run_score,
+            format_personal_best_comparison(run_score as f32, personal_best.score as f32, personal_best.score.to_string())
+        ),
+        format!(
+            "Asteroids destroyed: {} ({})",
+            run_statistics.get_total_asteroids_destroyed(),
+            asteroids_destroyed_by_archetype.join(", ")
+        ),
+        format!(
+            "Shots fired: {} | Accuracy: {:.0}%",
+            run_statistics.shots_fired,
+            run_statistics.get_accuracy() * 100.
+        ),
+        format!(
+            "Time survived: {} ({} survival bonuses) {}",
+            statistics::format_seconds_survived(run_statistics.seconds_survived),
+            run_statistics.still_alive_bonuses,
+            format_personal_best_comparison(
+                run_statistics.seconds_survived,
+                personal_best.seconds_survived,
+                statistics::format_seconds_survived(personal_best.seconds_survived)
+            )
+        ),
+        format!(
+            "Waves cleared: {} {}",
+            run_statistics.waves_cleared,
+            format_personal_best_comparison(
+                run_statistics.waves_cleared as f32,
+                personal_best.waves_cleared as f32,
+                personal_best.waves_cleared.to_string()
+            )
+        )
+    ].join("\n");
 }
 
 pub fn spawn_end_game_menu(
-    mut commands: Commands
+    mut commands: Commands,
+    game_config: Res<GameConfig>,
+    scoreboard_score: Res<ScoreboardScore>,
+    run_statistics: Res<RunStatistics>,
+    personal_best: Res<PersonalBest>
 ) {
-    commands.spawn((
-        TextBundle::from_section(
-            "You crashed! :(\nPress Enter to restart the game!",
-            TextStyle {
-                font_size: 40.,
-                color: Color::RED,
+    let end_game_menu_text: String = get_end_game_menu_text(
+        &game_config,
+        &run_statistics,
+        &personal_best,
+        statistics::get_run_score(&scoreboard_score)
+    );
+
+    let end_game_menu_entity: Entity = commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(END_GAME_MENU_HIDDEN_TOP_VAL_PX),
+                width: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
                 ..default()
             },
-        )
-        .with_text_justify(JustifyText::Center)
-        .with_style(Style {
-            position_type: PositionType::Absolute,
-            top: Val::Px(TOP_TEXT_VAL_PX),
-            right: Val::Px(RIGHT_TEXT_VAL_PX - 240.),
             ..default()
-        }),
+        },
+        Menu::default(),
         EndGameMenu,
-    ));
+        EndGameMenuAnimation::default()
+    )).id();
+
+    commands.entity(end_game_menu_entity).with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                style: Style {
+                    padding: UiRect::all(Val::Px(16.)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(12.),
+                    ..default()
+                },
+                background_color: Color::rgba(0., 0., 0., 0.).into(),
+                ..default()
+            },
+            EndGameMenuPanel
+        )).with_children(|parent| {
+            parent.spawn(
+                TextBundle::from_section(
+                    end_game_menu_text,
+                    TextStyle {
+                        font_size: END_GAME_MENU_FONT_SIZE,
+                        color: Color::RED,
+                        ..default()
+                    },
+                )
+                .with_text_justify(JustifyText::Center)
+            );
+            menu::spawn_menu_buttons(parent, end_game_menu_entity, &[
+                (MenuAction::Restart, "Restart".to_string())
+            ]);
+        });
+    });
+}
+
+pub fn animate_end_game_menu(
+    time: Res<Time>,
+    mut end_game_menu_query: Query<(&mut Style, &mut EndGameMenuAnimation), With<EndGameMenu>>,
+    mut end_game_menu_panel_query: Query<&mut BackgroundColor, With<EndGameMenuPanel>>
+) {
+    for (mut end_game_menu_style, mut end_game_menu_animation) in &mut end_game_menu_query {
+        if end_game_menu_animation.finished() {
+            continue;
+        }
+        end_game_menu_animation.tick(time.delta());
+
+        let animation_progress: f32 = 1. - (1. - end_game_menu_animation.fraction()).powi(3);
+
+        end_game_menu_style.top = Val::Px(
+            END_GAME_MENU_HIDDEN_TOP_VAL_PX + (END_GAME_MENU_TOP_VAL_PX - END_GAME_MENU_HIDDEN_TOP_VAL_PX) * animation_progress
+        );
+
+        for mut end_game_menu_panel_background_color in &mut end_game_menu_panel_query {
+            end_game_menu_panel_background_color.0.set_a(END_GAME_MENU_BACKGROUND_ALPHA * animation_progress);
+        }
+    }
 }
 
 pub fn erase_end_game_menu(
@@ -150,20 +535,121 @@ pub fn erase_end_game_menu(
     start_screen_query: Query<Entity, With<EndGameMenu>>
 ) {
     for start_screen_entity in &start_screen_query {
-        commands.entity(start_screen_entity).despawn();
+        commands.entity(start_screen_entity).despawn_recursive();
     }
 }
 
-pub fn spawn_scoreboard(
+fn spawn_initial_scoreboard(
+    commands: Commands
+) {
+    spawn_scoreboard(commands, PlayerId::One);
+}
+
+pub fn get_round_results_menu_text(
+    round_state: &RoundState,
+    versus_rounds: &VersusRounds
+) -> String {
+    let round_or_match: &str = match round_state {
+        RoundState::MatchOver => "match",
+        _ => "round"
+    };
+    let round_winner_text: String = match versus_rounds.last_round_winner {
+        Some(PlayerId::One) => format!("Player One wins the {}!", round_or_match),
+        Some(PlayerId::Two) => format!("Player Two wins the {}!", round_or_match),
+        None => "The round is a draw!".to_string()
+    };
+
+    match round_state {
+        RoundState::MatchOver => return format!(
+            "{}\nP1 {} - {} P2",
+            round_winner_text,
+            versus_rounds.get_wins(PlayerId::One),
+            versus_rounds.get_wins(PlayerId::Two)
+        ),
+        _ => return format!(
+            "Round {}: {}\nP1 {} - {} P2",
+            versus_rounds.round,
+            round_winner_text,
+            versus_rounds.get_wins(PlayerId::One),
+            versus_rounds.get_wins(PlayerId::Two)
+        )
+    }
+}
+
+pub fn spawn_round_results_menu(
     mut commands: Commands
 ) {
+    let round_results_menu_entity: Entity = commands.spawn((
+        menu::get_menu_root_bundle(0.),
+        Menu::default(),
+        MenuTransition::default(),
+        RoundResultsMenu
+    )).id();
+
+    commands.entity(round_results_menu_entity).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 40.,
+                    color: Color::SALMON,
+                    ..default()
+                },
+            )
+            .with_text_justify(JustifyText::Center),
+            RoundResultsMenuText
+        ));
+        menu::spawn_menu_buttons(parent, round_results_menu_entity, &[
+            (MenuAction::Restart, "Next round".to_string())
+        ]);
+    });
+}
+
+pub fn update_round_results_menu(
+    round_state: Res<State<RoundState>>,
+    versus_rounds: Res<VersusRounds>,
+    menu_button_query: Query<(&MenuButton, &Children)>,
+    mut text_query: Query<&mut Text>,
+    round_results_menu_text_query: Query<Entity, With<RoundResultsMenuText>>
+) {
+    for round_results_menu_text_entity in &round_results_menu_text_query {
+        if let Ok(mut round_results_menu_text) = text_query.get_mut(round_results_menu_text_entity) {
+            round_results_menu_text.sections[0].value = get_round_results_menu_text(round_state.get(), &versus_rounds);
+        }
+    }
+
+    let restart_button_label: &str = match round_state.get() {
+        RoundState::MatchOver => "New match",
+        _ => "Next round"
+    };
+    menu::set_menu_button_label(&menu_button_query, &mut text_query, MenuAction::Restart, restart_button_label.to_string());
+}
+
+pub fn erase_round_results_menu(
+    mut commands: Commands,
+    round_results_menu_query: Query<Entity, With<RoundResultsMenu>>
+) {
+    for round_results_menu_entity in &round_results_menu_query {
+        commands.entity(round_results_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn spawn_scoreboard(
+    mut commands: Commands,
+    player_id: PlayerId
+) {
+    let scoreboard_top_offset: f32 = match player_id {
+        PlayerId::One => 3.,
+        PlayerId::Two => 33.
+    };
+
     commands.spawn((
         TextBundle::from_sections([
             TextSection::new(
                 "Score: ",
                 TextStyle {
                     font_size: 30.,
-                    color: Color::WHITE,
+                    color: player_id.color(),
                     ..default()
                 },
             ),
@@ -176,18 +662,251 @@ pub fn spawn_scoreboard(
         .with_text_justify(JustifyText::Center)
         .with_style(Style {
             position_type: PositionType::Absolute,
-            top: Val::Px(3.),
+            top: Val::Px(scoreboard_top_offset),
             right: Val::Px(350.),
             ..default()
         }),
-        Scoreboard
+        Scoreboard,
+        player_id
     ));
 }
 
+pub fn erase_player_two_scoreboard(
+    mut commands: Commands,
+    scoreboard_query: Query<(Entity, &PlayerId), With<Scoreboard>>
+) {
+    for (scoreboard_entity, player_id) in &scoreboard_query {
+        if *player_id == PlayerId::Two {
+            commands.entity(scoreboard_entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn reset_scoreboard_score(
+    game_mode: Res<GameMode>,
+    mut scoreboard_score: ResMut<ScoreboardScore>
+) {
+    scoreboard_score.reset(*game_mode);
+}
+
 pub fn update_scoreboard_score(
+    game_mode: Res<GameMode>,
     scoreboard_score: Res<ScoreboardScore>,
-    mut scoreboard_query: Query<&mut Text, With<Scoreboard>>
+    mut scoreboard_query: Query<(&PlayerId, &mut Text), With<Scoreboard>>
 ) {
-    let mut scoreboard_text: Mut<Text> = scoreboard_query.single_mut();
-    scoreboard_text.sections[1].value = scoreboard_score.score.to_string();
+    for (player_id, mut scoreboard_text) in &mut scoreboard_query {
+        scoreboard_text.sections[0].value = match (*game_mode, player_id) {
+            (GameMode::SinglePlayer, _) => "Score: ".to_string(),
+            (_, PlayerId::One) => "P1: ".to_string(),
+            (_, PlayerId::Two) => "P2: ".to_string()
+        };
+        scoreboard_text.sections[1].value = scoreboard_score.get(*player_id).to_string();
+    }
+}
+
+fn spawn_toast_container(
+    mut commands: Commands
+) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.),
+                right: Val::Px(10.),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexEnd,
+                row_gap: Val::Px(5.),
+                ..default()
+            },
+            ..default()
+        },
+        ToastContainer
+    ));
+}
+
+pub fn spawn_toasts(
+    mut commands: Commands,
+    mut show_toast_events: EventReader<ShowToast>,
+    toast_container_query: Query<Entity, With<ToastContainer>>
+) {
+    let Ok(toast_container_entity) = toast_container_query.get_single() else {
+        return;
+    };
+
+    for show_toast_event in show_toast_events.read() {
+        let toast_entity: Entity = commands.spawn((
+            TextBundle::from_section(
+                show_toast_event.message.clone(),
+                TextStyle {
+                    font_size: 18.,
+                    color: show_toast_event.color,
+                    ..default()
+                },
+            )
+            .with_text_justify(JustifyText::Right)
+            .with_style(Style {
+                max_width: Val::Px(500.),
+                ..default()
+            }),
+            Toast::default()
+        )).id();
+        commands.entity(toast_container_entity).add_child(toast_entity);
+    }
+}
+
+pub fn despawn_expired_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toast_query: Query<(Entity, &mut Toast)>
+) {
+    for (toast_entity, mut toast_timer) in &mut toast_query {
+        toast_timer.tick(time.delta());
+
+        if toast_timer.finished() {
+            commands.entity(toast_entity).despawn_recursive();
+        }
+    }
+}
+
+fn spawn_hero_ship_hud(
+    mut commands: Commands
+) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 18.,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.),
+            left: Val::Px(10.),
+            ..default()
+        }),
+        HeroShipHud
+    ));
+}
+
+fn spawn_energy_shield_bars(
+    mut commands: Commands
+) {
+    for (player_index, player_id) in [PlayerId::One, PlayerId::Two].into_iter().enumerate() {
+        commands.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(10. + player_index as f32 * (ENERGY_SHIELD_BAR_HEIGHT + 6.)),
+                    left: Val::Px(RIGHT_BORDER_POSITION - ENERGY_SHIELD_BAR_WIDTH / 2.),
+                    width: Val::Px(ENERGY_SHIELD_BAR_WIDTH),
+                    height: Val::Px(ENERGY_SHIELD_BAR_HEIGHT),
+                    display: Display::None,
+                    ..default()
+                },
+                background_color: Color::rgba(1., 1., 1., 0.15).into(),
+                ..default()
+            },
+            EnergyShieldBar,
+            player_id
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.),
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    background_color: player_id.color().into(),
+                    ..default()
+                },
+                EnergyShieldBarFill,
+                player_id
+            ));
+        });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_energy_shield_bars(
+    game_config: Res<GameConfig>,
+    game_settings: Res<GameSettings>,
+    hero_ship_query: Query<(&PlayerId, &EnergyShield), With<HeroShip>>,
+    mut energy_shield_bar_query: Query<(&PlayerId, &mut Style), (With<EnergyShieldBar>, Without<EnergyShieldBarFill>)>,
+    mut energy_shield_bar_fill_query: Query<(&PlayerId, &mut Style, &mut BackgroundColor), With<EnergyShieldBarFill>>
+) {
+    let get_energy_shield = |player_id: &PlayerId| -> Option<&EnergyShield> {
+        return hero_ship_query.iter()
+            .find(|(hero_ship_player_id, _)| *hero_ship_player_id == player_id)
+            .map(|(_, energy_shield)| energy_shield);
+    };
+
+    for (player_id, mut energy_shield_bar_style) in &mut energy_shield_bar_query {
+        let is_energy_shield_bar_shown: bool =
+            game_settings.down_key_action == DownKeyAction::EnergyShield && get_energy_shield(player_id).is_some();
+
+        energy_shield_bar_style.display = if is_energy_shield_bar_shown { Display::Flex } else { Display::None };
+    }
+
+    for (player_id, mut energy_shield_bar_fill_style, mut energy_shield_bar_fill_color) in &mut energy_shield_bar_fill_query {
+        let Some(energy_shield) = get_energy_shield(player_id) else {
+            continue;
+        };
+        let is_energy_shield_ready: bool =
+            energy_shield.is_raised || energy_shield.energy >= game_config.energy_shield.minimum_raise_energy;
+
+        energy_shield_bar_fill_style.width = Val::Percent(energy_shield.get_energy_ratio(&game_config.energy_shield) * 100.);
+        *energy_shield_bar_fill_color = if is_energy_shield_ready {
+            player_id.color().into()
+        } else {
+            player_id.color().with_a(0.35).into()
+        };
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_hero_ship_hud(
+    game_mode: Res<GameMode>,
+    game_config: Res<GameConfig>,
+    hero_ship_query: Query<(&PlayerId, &ActivePowerUps, &Weapon, &WeaponState, &ShipUpgrades, &ScoreCombo), With<HeroShip>>,
+    mut hero_ship_hud_query: Query<&mut Text, With<HeroShipHud>>
+) {
+    let Ok(mut hero_ship_hud_text) = hero_ship_hud_query.get_single_mut() else {
+        return;
+    };
+    let mut hero_ship_descriptions: Vec<(PlayerId, String)> = Vec::new();
+
+    for (player_id, active_power_ups, weapon, weapon_state, ship_upgrades, score_combo) in &hero_ship_query {
+        let weapon_description: String = if *weapon == Weapon::ChargedShot && weapon_state.charge_seconds > 0. {
+            format!("{} {:.0}%", weapon.name(), weapon_state.get_charge_ratio(&game_config) * 100.)
+        } else {
+            weapon.name().to_string()
+        };
+        let hero_ship_status_descriptions: Vec<String> = std::iter::once(weapon_description)
+            .chain(PowerUpKind::TIMED.iter()
+                .filter_map(|power_up_kind| active_power_ups.get_remaining_seconds(*power_up_kind)
+                    .map(|remaining_seconds| format!("{} {:.1}s", power_up_kind.name(), remaining_seconds))))
+            .chain((ship_upgrades.shield_charges > 0).then(|| format!("Shield Charge x{}", ship_upgrades.shield_charges)))
+            .chain((score_combo.kills > 0).then(|| format!(
+                "Combo x{} {:.1}s",
+                score_combo.get_multiplier(&game_config.combo),
+                score_combo.window_seconds_remaining
+            )))
+            .collect();
+
+        let player_prefix: &str = match (*game_mode, player_id) {
+            (GameMode::SinglePlayer, _) => "",
+            (_, PlayerId::One) => "P1: ",
+            (_, PlayerId::Two) => "P2: "
+        };
+        hero_ship_descriptions.push((*player_id, format!("{}{}", player_prefix, hero_ship_status_descriptions.join(" | "))));
+    }
+
+    hero_ship_descriptions.sort_by_key(|(player_id, _)| *player_id == PlayerId::Two);
+    hero_ship_hud_text.sections[0].value = hero_ship_descriptions.into_iter()
+        .map(|(_, hero_ship_description)| hero_ship_description)
+        .collect::<Vec<String>>()
+        .join("\n");
 }
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::GameState;
+use crate::GameMode;
+use crate::RoundState;
+use crate::GameplaySet;
+use crate::RestartGame;
+use crate::collision;
+use crate::hero_ship::{HeroShipRemainingLives, PlayerId};
+use crate::ui::ScoreboardScore;
+
+use crate::constants::versus::{
+    ROUND_WINNING_SCORE,
+    ROUNDS_TO_WIN_MATCH
+};
+
+pub struct VersusPlugin;
+
+impl Plugin for VersusPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.init_resource::<VersusRounds>();
+        app.add_systems(FixedUpdate, (
+            check_for_round_winner
+        )
+            .in_set(GameplaySet)
+            .after(collision::detect_asteroid_hero_ship_collision)
+            .after(collision::detect_projectile_hero_ship_collision)
+            .run_if(in_state(RoundState::Playing).and_then(resource_equals(GameMode::Versus))));
+        app.add_systems(Update, (
+            advance_round_on_restart
+        ).run_if(in_state(GameState::EndGame).or_else(in_state(GameState::InGame)).and_then(resource_equals(GameMode::Versus))));
+    }
+}
+
+#[derive(Resource, Clone)]
+pub struct VersusRounds {
+    pub round: usize,
+    pub wins: HashMap<PlayerId, usize>,
+    pub last_round_winner: Option<PlayerId>
+}
+
+impl Default for VersusRounds {
+    fn default() -> Self {
+        return Self {
+            round: 1,
+            wins: HashMap::from([(PlayerId::One, 0), (PlayerId::Two, 0)]),
+            last_round_winner: None
+        };
+    }
+}
+
+impl VersusRounds {
+    pub fn get_wins(
+        &self,
+        player_id: PlayerId
+    ) -> usize {
+        return self.wins.get(&player_id).copied().unwrap_or(0);
+    }
+
+    pub fn get_match_winner(&self) -> Option<PlayerId> {
+        return self.wins.iter()
+            .find(|(_, wins)| **wins >= ROUNDS_TO_WIN_MATCH)
+            .map(|(player_id, _)| *player_id);
+    }
+}
+
+fn get_round_winner(
+    hero_ship_remaining_lives: &HeroShipRemainingLives,
+    scoreboard_score: &ScoreboardScore
+) -> Option<Option<PlayerId>> {
+    let players_still_alive: Vec<PlayerId> = [PlayerId::One, PlayerId::Two].into_iter()
+        .filter(|player_id| hero_ship_remaining_lives.get(*player_id) > 0)
+        .collect();
+
+    if players_still_alive.len() <= 1 {
+        return Some(players_still_alive.first().copied());
+    }
+
+    return [PlayerId::One, PlayerId::Two].into_iter()
+        .filter(|player_id| scoreboard_score.get(*player_id) >= ROUND_WINNING_SCORE)
+        .max_by_key(|player_id| scoreboard_score.get(*player_id))
+        .map(Some);
+}
+
+pub fn check_for_round_winner(
+    hero_ship_remaining_lives: Res<HeroShipRemainingLives>,
+    scoreboard_score: Res<ScoreboardScore>,
+    mut versus_rounds: ResMut<VersusRounds>,
+    mut next_state_game_state: ResMut<NextState<GameState>>,
+    mut next_state_round_state: ResMut<NextState<RoundState>>
+) {
+    if next_state_round_state.0.is_some() {
+        return;
+    }
+
+    if let Some(round_winner) = get_round_winner(&hero_ship_remaining_lives, &scoreboard_score) {
+        versus_rounds.last_round_winner = round_winner;
+
+        if let Some(player_id) = round_winner {
+            *versus_rounds.wins.entry(player_id).or_insert(0) += 1;
+        }
+
+        if versus_rounds.get_match_winner().is_some() {
+            next_state_round_state.set(RoundState::MatchOver);
+        } else {
+            next_state_round_state.set(RoundState::RoundOver);
+        }
+        next_state_game_state.set(GameState::EndGame);
+    }
+}
+
+pub fn advance_round_on_restart(
+    mut restart_game_events: EventReader<RestartGame>,
+    game_state: Res<State<GameState>>,
+    round_state: Res<State<RoundState>>,
+    mut versus_rounds: ResMut<VersusRounds>,
+    mut next_state_round_state: ResMut<NextState<RoundState>>
+) {
+    if restart_game_events.read().last().is_some() {
+        match (game_state.get(), round_state.get()) {
+            (GameState::InGame, _) | (_, RoundState::MatchOver) => { *versus_rounds = VersusRounds::default(); },
+            _ => { versus_rounds.round += 1; }
+        }
+        next_state_round_state.set(RoundState::Playing);
+    }
+}
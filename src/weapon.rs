@@ -0,0 +1,510 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use serde::Deserialize;
+
+use bevy::audio::{
+    PlaybackMode::Despawn,
+    Volume
+};
+
+use crate::GameplaySet;
+use crate::projectile::{self, Projectile};
+use crate::power_up::ActivePowerUps;
+use crate::shop::ShipUpgrades;
+use crate::asteroid::Asteroid;
+use crate::config::GameConfig;
+use crate::statistics::RunStatistics;
+
+use crate::collision::{
+    self,
+    AsteroidHit
+};
+
+use crate::hero_ship::{
+    HeroShip,
+    HeroShipHull,
+    HeroShipInput,
+    PlayerId
+};
+
+use crate::constants::image_handles::{
+    CHARGED_SHOT_HANDLE_IMAGE,
+    HOMING_MISSILE_HANDLE_IMAGE,
+    LASER_BEAM_HANDLE_IMAGE
+};
+
+use crate::constants::weapons::{
+    CHARGED_SHOT_SOUND_VOLUME,
+    LASER_BEAM_SOUND_VOLUME,
+    HOMING_MISSILE_SOUND_VOLUME
+};
+
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(
+        &self,
+        app: &mut App
+    ) {
+        app.add_systems(FixedUpdate, (
+            cycle_hero_ship_weapon,
+            fire_charged_shot.after(cycle_hero_ship_weapon),
+            fire_laser_beam.after(cycle_hero_ship_weapon).before(collision::apply_asteroid_hits),
+            steer_homing_missiles.before(projectile::set_projectile_movement)
+        ).in_set(GameplaySet));
+    }
+}
+
+#[derive(Component, Reflect, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum Weapon {
+    #[default]
+    Blaster,
+    ChargedShot,
+    LaserBeam,
+    HomingMissile
+}
+
+impl Weapon {
+    pub fn next(
+        &self,
+        weapon_loadout: &[Weapon]
+    ) -> Self {
+        let Some(weapon_index) = weapon_loadout.iter().position(|weapon| weapon == self) else {
+            return weapon_loadout.first().copied().unwrap_or(*self);
+        };
+
+        return weapon_loadout[(weapon_index + 1) % weapon_loadout.len()];
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weapon::Blaster => return "Blaster",
+            Weapon::ChargedShot => return "Charged Shot",
+            Weapon::LaserBeam => return "Laser Beam",
+            Weapon::HomingMissile => return "Homing Missile"
+        }
+    }
+
+    pub fn cooldown_seconds(
+        &self,
+        game_config: &GameConfig
+    ) -> f32 {
+        match self {
+            Weapon::Blaster => return game_config.projectile.spawn_seconds,
+            Weapon::ChargedShot => return game_config.weapons.charged_shot.cooldown_seconds,
+            Weapon::LaserBeam => return game_config.weapons.laser_beam.damage_seconds,
+            Weapon::HomingMissile => return game_config.weapons.homing_missile.cooldown_seconds
+        }
+    }
+
+    pub fn projectile_movement_speed(
+        &self,
+        game_config: &GameConfig
+    ) -> Option<f32> {
+        match self {
+            Weapon::Blaster => return Some(game_config.projectile.movement_speed),
+            Weapon::ChargedShot => return Some(game_config.weapons.charged_shot.movement_speed),
+            Weapon::LaserBeam => return None,
+            Weapon::HomingMissile => return Some(game_config.weapons.homing_missile.movement_speed)
+        }
+    }
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct WeaponState {
+    pub charge_seconds: f32,
+    pub charged_shot_cooldown_seconds: f32,
+    pub was_cycle_weapon_pressed: bool,
+    pub is_laser_beam_firing: bool,
+    pub has_laser_beam_hit: bool
+}
+
+impl WeaponState {
+    pub fn get_charge_ratio(
+        &self,
+        game_config: &GameConfig
+    ) -> f32 {
+        return (self.charge_seconds / game_config.weapons.charged_shot.maximum_charge_seconds).min(1.);
+    }
+}
+
+#[derive(Component)]
+pub struct HomingMissile {
+    pub turn_speed: f32
+}
+
+#[derive(Component)]
+pub struct LaserBeam;
+
+#[derive(Resource, Default)]
+pub struct WeaponSounds {
+    pub charged_shot: Handle<AudioSource>,
+    pub laser_beam: Handle<AudioSource>,
+    pub homing_missile: Handle<AudioSource>
+}
+
+pub fn spawn_laser_beam(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    player_id: PlayerId
+) {
+    parent.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: player_id.color(),
+                custom_size: Some(Vec2::ZERO),
+                ..default()
+            },
+            texture: asset_server.load(LASER_BEAM_HANDLE_IMAGE),
+            transform: Transform::from_xyz(0., 0., -1.),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        LaserBeam
+    ));
+}
+
+pub fn cycle_hero_ship_weapon(
+    mut hero_ship_query: Query<(
+        &HeroShipInput,
+        &HeroShipHull,
+        &mut Weapon,
+        &mut WeaponState,
+        &mut projectile::ProjectileSpawnTimer
+    ), With<HeroShip>>
+) {
+    for (hero_ship_input, hero_ship_hull, mut weapon, mut weapon_state, mut projectile_spawn_timer) in &mut hero_ship_query {
+        if hero_ship_input.cycle_weapon && !weapon_state.was_cycle_weapon_pressed {
+            *weapon = weapon.next(&hero_ship_hull.weapons);
+            weapon_state.charge_seconds = 0.;
+            projectile_spawn_timer.0.reset();
+        }
+        weapon_state.was_cycle_weapon_pressed = hero_ship_input.cycle_weapon;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn fire_charged_shot(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    game_config: Res<GameConfig>,
+    weapon_sounds: Res<WeaponSounds>,
+    mut run_statistics: ResMut<RunStatistics>,
+    mut hero_ship_query: Query<(
+        &PlayerId,
+        &HeroShipInput,
+        &Weapon,
+        &mut WeaponState,
+        &ActivePowerUps,
+        &ShipUpgrades,
+        &Transform,
+        &Visibility
+    ), With<HeroShip>>
+) {
+    let charged_shot_config = &game_config.weapons.charged_shot;
+
+    for (
+        player_id,
+        hero_ship_input,
+        weapon,
+        mut weapon_state,
+        active_power_ups,
+        ship_upgrades,
+        hero_ship_transform,
+        hero_ship_visibility
+    ) in &mut hero_ship_query {
+        weapon_state.charged_shot_cooldown_seconds = (weapon_state.charged_shot_cooldown_seconds - time.delta_seconds()).max(0.);
+
+        if *weapon != Weapon::ChargedShot || *hero_ship_visibility != Visibility::Visible {
+            weapon_state.charge_seconds = 0.;
+            continue;
+        }
+
+        if hero_ship_input.shoot {
+            if weapon_state.charged_shot_cooldown_seconds == 0. {
+                weapon_state.charge_seconds =
+                    (weapon_state.charge_seconds + time.delta_seconds()).min(charged_shot_config.maximum_charge_seconds);
+            }
+            continue;
+        }
+
+        if weapon_state.charge_seconds >= charged_shot_config.minimum_charge_seconds {
+            let charge_ratio: f32 = weapon_state.get_charge_ratio(&game_config);
+
+            Projectile {
+                translation: hero_ship_transform.translation,
+                direction: hero_ship_transform.rotation * Vec3::Y,
+                movement_speed: charged_shot_config.movement_speed,
+                collider_ball_size: charged_shot_config.minimum_collider_ball_size +
+                    (charged_shot_config.maximum_collider_ball_size - charged_shot_config.minimum_collider_ball_size) * charge_ratio,
+                handle_image_path: CHARGED_SHOT_HANDLE_IMAGE,
+                pierce_count: charged_shot_config.pierce_count,
+                ricochet_count: 0,
+                owner: *player_id,
+                has_hit_asteroid: false
+            }.spawn_projectile(commands.reborrow(), &asset_server);
+            run_statistics.shots_fired += 1;
+
+            commands.spawn(AudioBundle {
+                source: weapon_sounds.charged_shot.clone(),
+                settings: PlaybackSettings {
+                    mode: Despawn,
+                    volume: Volume::new(CHARGED_SHOT_SOUND_VOLUME),
+                    ..default()
+                }
+            });
+            weapon_state.charged_shot_cooldown_seconds = charged_shot_config.cooldown_seconds *
+                active_power_ups.get_cooldown_factor(&game_config.power_ups) *
+                ship_upgrades.get_fire_rate_factor(&game_config.shop);
+        }
+        weapon_state.charge_seconds = 0.;
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn fire_laser_beam(
+    mut commands: Commands,
+    time: Res<Time>,
+    rapier_context: Res<RapierContext>,
+    game_config: Res<GameConfig>,
+    weapon_sounds: Res<WeaponSounds>,
+    mut run_statistics: ResMut<RunStatistics>,
+    mut asteroid_hit_events: EventWriter<AsteroidHit>,
+    mut hero_ship_query: Query<(
+        &PlayerId,
+        &HeroShipInput,
+        &Weapon,
+        &mut WeaponState,
+        &mut projectile::ProjectileSpawnTimer,
+        &Transform,
+        &Visibility,
+        &Children
+    ), With<HeroShip>>,
+    mut laser_beam_query: Query<(&mut Sprite, &mut Transform, &mut Visibility), (With<LaserBeam>, Without<HeroShip>)>,
+    asteroid_query: Query<Entity, With<Asteroid>>
+) {
+    let laser_beam_config = &game_config.weapons.laser_beam;
+    let asteroid_filter = |entity: Entity| asteroid_query.contains(entity);
+
+    for (
+        player_id,
+        hero_ship_input,
+        weapon,
+        mut weapon_state,
+        mut projectile_spawn_timer,
+        hero_ship_transform,
+        hero_ship_visibility,
+        hero_ship_children
+    ) in &mut hero_ship_query {
+        let Some(laser_beam_entity) = hero_ship_children.iter().copied().find(|child_entity| laser_beam_query.contains(*child_entity)) else {
+            continue;
+        };
+        let Ok((mut laser_beam_sprite, mut laser_beam_transform, mut laser_beam_visibility)) =
+            laser_beam_query.get_mut(laser_beam_entity)
+        else {
+            continue;
+        };
+
+        if *weapon != Weapon::LaserBeam || !hero_ship_input.shoot || *hero_ship_visibility != Visibility::Visible {
+            *laser_beam_visibility = Visibility::Hidden;
+            weapon_state.is_laser_beam_firing = false;
+            continue;
+        }
+
+        if !weapon_state.is_laser_beam_firing {
+            weapon_state.is_laser_beam_firing = true;
+            weapon_state.has_laser_beam_hit = false;
+            run_statistics.shots_fired += 1;
+
+            commands.spawn(AudioBundle {
+                source: weapon_sounds.laser_beam.clone(),
+                settings: PlaybackSettings {
+                    mode: Despawn,
+                    volume: Volume::new(LASER_BEAM_SOUND_VOLUME),
+                    ..default()
+                }
+            });
+        }
+
+        let laser_beam_direction: Vec3 = hero_ship_transform.rotation * Vec3::Y;
+        let laser_beam_hit: Option<(Entity, f32)> = rapier_context.cast_ray(
+            hero_ship_transform.translation.truncate(),
+            laser_beam_direction.truncate(),
+            laser_beam_config.length,
+            true,
+            QueryFilter::new().predicate(&asteroid_filter)
+        );
+        let laser_beam_length: f32 = laser_beam_hit.map_or(laser_beam_config.length, |(_, hit_distance)| hit_distance);
+
+        laser_beam_sprite.custom_size = Some(Vec2::new(laser_beam_config.width, laser_beam_length));
+        laser_beam_transform.translation = Vec3::new(0., laser_beam_length / 2., -1.);
+        *laser_beam_visibility = Visibility::Visible;
+
+        projectile_spawn_timer.0.tick(time.delta());
+
+        if projectile_spawn_timer.0.just_finished() {
+            if let Some((asteroid_entity, _)) = laser_beam_hit {
+                if !weapon_state.has_laser_beam_hit {
+                    weapon_state.has_laser_beam_hit = true;
+                    run_statistics.shots_hit += 1;
+                }
+                asteroid_hit_events.send(AsteroidHit {
+                    asteroid_entity,
+                    player_id: *player_id,
+                    direction: laser_beam_direction
+                });
+            }
+        }
+    }
+}
+
+pub fn spawn_homing_missile(
+    mut commands: Commands,
+    asset_server: &Res<AssetServer>,
+    game_config: &GameConfig,
+    weapon_sounds: &Res<WeaponSounds>,
+    player_id: PlayerId,
+    translation: Vec3,
+    direction: Vec3
+) {
+    let homing_missile_config = &game_config.weapons.homing_missile;
+    let homing_missile_entity: Entity = Projectile {
+        translation,
+        direction,
+        movement_speed: homing_missile_config.movement_speed,
+        collider_ball_size: homing_missile_config.collider_ball_size,
+        handle_image_path: HOMING_MISSILE_HANDLE_IMAGE,
+        pierce_count: 0,
+        ricochet_count: 0,
+        owner: player_id,
+        has_hit_asteroid: false
+    }.spawn_projectile(commands.reborrow(), asset_server);
+
+    commands.entity(homing_missile_entity).insert(HomingMissile { turn_speed: homing_missile_config.turn_speed });
+    commands.spawn(AudioBundle {
+        source: weapon_sounds.homing_missile.clone(),
+        settings: PlaybackSettings {
+            mode: Despawn,
+            volume: Volume::new(HOMING_MISSILE_SOUND_VOLUME),
+            ..default()
+        }
+    });
+}
+
+pub fn steer_homing_missiles(
+    time: Res<Time>,
+    mut homing_missile_query: Query<(&HomingMissile, &mut Projectile, &mut Transform), Without<Asteroid>>,
+    asteroid_query: Query<&Transform, With<Asteroid>>
+) {
+    for (homing_missile, mut projectile_component, mut homing_missile_transform) in &mut homing_missile_query {
+        let homing_missile_position: Vec3 = homing_missile_transform.translation;
+        let nearest_asteroid_position: Option<Vec3> = asteroid_query.iter()
+            .map(|asteroid_transform| asteroid_transform.translation)
+            .min_by(|first_position, second_position| {
+                first_position.distance_squared(homing_missile_position)
+                    .total_cmp(&second_position.distance_squared(homing_missile_position))
+            });
+
+        let Some(nearest_asteroid_position) = nearest_asteroid_position else {
+            continue;
+        };
+        let target_direction: Vec2 = (nearest_asteroid_position - homing_missile_position).truncate();
+        let current_direction: Vec2 = projectile_component.direction.truncate();
+
+        if target_direction == Vec2::ZERO || current_direction == Vec2::ZERO {
+            continue;
+        }
+
+        let maximum_turn_angle: f32 = f32::to_radians(homing_missile.turn_speed) * time.delta_seconds();
+        let turn_angle: f32 = current_direction.angle_between(target_direction).clamp(-maximum_turn_angle, maximum_turn_angle);
+
+        projectile_component.direction = Quat::from_rotation_z(turn_angle) * projectile_component.direction;
+        homing_missile_transform.rotation =
+            Quat::from_rotation_arc(Vec3::Y, projectile_component.direction.normalize_or_zero());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn next_weapon_cycles_through_the_loadout() {
+        let weapon_loadout: Vec<Weapon> = vec![Weapon::Blaster, Weapon::ChargedShot, Weapon::HomingMissile];
+
+        assert_eq!(Weapon::Blaster.next(&weapon_loadout), Weapon::ChargedShot);
+        assert_eq!(Weapon::ChargedShot.next(&weapon_loadout), Weapon::HomingMissile);
+        assert_eq!(Weapon::HomingMissile.next(&weapon_loadout), Weapon::Blaster);
+    }
+
+    #[test]
+    fn next_weapon_outside_the_loadout_falls_back_to_its_first_weapon() {
+        assert_eq!(Weapon::LaserBeam.next(&[Weapon::Blaster, Weapon::HomingMissile]), Weapon::Blaster);
+        assert_eq!(Weapon::LaserBeam.next(&[]), Weapon::LaserBeam);
+    }
+
+    #[test]
+    fn cooldowns_come_from_the_weapon_config() {
+        let game_config: GameConfig = GameConfig::default();
+
+        assert_eq!(Weapon::Blaster.cooldown_seconds(&game_config), game_config.projectile.spawn_seconds);
+        assert_eq!(Weapon::ChargedShot.cooldown_seconds(&game_config), game_config.weapons.charged_shot.cooldown_seconds);
+        assert_eq!(Weapon::LaserBeam.cooldown_seconds(&game_config), game_config.weapons.laser_beam.damage_seconds);
+        assert_eq!(Weapon::HomingMissile.cooldown_seconds(&game_config), game_config.weapons.homing_missile.cooldown_seconds);
+    }
+
+    #[test]
+    fn charge_ratio_grows_with_the_charge_and_stops_at_the_maximum() {
+        let game_config: GameConfig = GameConfig::default();
+        let maximum_charge_seconds: f32 = game_config.weapons.charged_shot.maximum_charge_seconds;
+        let mut weapon_state: WeaponState = WeaponState::default();
+
+        assert_eq!(weapon_state.get_charge_ratio(&game_config), 0.);
+
+        weapon_state.charge_seconds = maximum_charge_seconds / 2.;
+        assert_eq!(weapon_state.get_charge_ratio(&game_config), 0.5);
+
+        weapon_state.charge_seconds = maximum_charge_seconds * 2.;
+        assert_eq!(weapon_state.get_charge_ratio(&game_config), 1.);
+    }
+
+    #[test]
+    fn homing_missile_turns_towards_the_nearest_asteroid_within_its_turn_speed() {
+        let mut app: App = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(1. / 64.)));
+        app.add_systems(Update, steer_homing_missiles);
+
+        let homing_missile_entity: Entity = app.world.spawn((
+            HomingMissile { turn_speed: 180. },
+            Projectile { direction: Vec3::Y, ..default() },
+            Transform::default()
+        )).id();
+        app.world.spawn((Asteroid::default(), Transform::from_xyz(100., 0., 0.)));
+        app.world.spawn((Asteroid::default(), Transform::from_xyz(-300., 0., 0.)));
+
+        let maximum_turn_angle: f32 = f32::to_radians(180.) / 64.;
+        let mut previous_direction: Vec3 = Vec3::Y;
+
+        for _ in 0..64 {
+            app.update();
+
+            let direction: Vec3 = app.world.get::<Projectile>(homing_missile_entity).unwrap().direction;
+            let turn_angle: f32 = previous_direction.truncate().angle_between(direction.truncate());
+
+            assert!(turn_angle <= 0.);
+            assert!(turn_angle.abs() <= maximum_turn_angle + 1e-4);
+            previous_direction = direction;
+        }
+
+        let homing_missile_transform: &Transform = app.world.get::<Transform>(homing_missile_entity).unwrap();
+
+        assert!(previous_direction.abs_diff_eq(Vec3::X, 1e-3));
+        assert!((homing_missile_transform.rotation * Vec3::Y).abs_diff_eq(Vec3::X, 1e-3));
+    }
+}